@@ -2,6 +2,9 @@ use bevy::prelude::*;
 use bevy::ui::FocusPolicy;
 
 use super::components::*;
+use crate::components::direction_provider::MirrorInRtl;
+use crate::components::disabled::Disabled;
+use crate::components::transition::UiTransition;
 use crate::theme::UiTheme;
 
 /// Builder for a simple accordion item consisting of a header and body.
@@ -10,6 +13,7 @@ pub struct AccordionBuilder {
     title: String,
     open: bool,
     disabled: bool,
+    chevron: Option<Handle<Image>>,
     content:
         Option<Box<dyn FnOnce(&mut ChildSpawnerCommands, &UiTheme, &Handle<Font>) + Send + Sync>>,
     markers: Vec<Box<dyn FnOnce(&mut EntityCommands) + Send + Sync>>,
@@ -22,6 +26,7 @@ impl AccordionBuilder {
             title: title.into(),
             open: false,
             disabled: false,
+            chevron: None,
             content: None,
             markers: Vec::new(),
         }
@@ -39,6 +44,13 @@ impl AccordionBuilder {
         self
     }
 
+    /// Show a chevron icon at the end of the header.
+    /// The icon is mirrored automatically below an RTL `DirectionProvider`.
+    pub fn chevron(mut self, icon: Handle<Image>) -> Self {
+        self.chevron = Some(icon);
+        self
+    }
+
     /// Provide custom body content.
     pub fn content<F>(mut self, func: F) -> Self
     where
@@ -92,6 +104,8 @@ impl AccordionBuilder {
                 Node {
                     width: Val::Percent(100.0),
                     padding: UiRect::all(Val::Px(4.0)),
+                    justify_content: JustifyContent::SpaceBetween,
+                    align_items: AlignItems::Center,
                     ..default()
                 },
                 BackgroundColor(theme.color.red.step10),
@@ -112,6 +126,18 @@ impl AccordionBuilder {
                     },
                     TextColor(theme.color.gray.step12),
                 ));
+                if let Some(icon) = self.chevron {
+                    h.spawn((
+                        AccordionChevronMarker,
+                        MirrorInRtl,
+                        ImageNode::new(icon),
+                        Node {
+                            width: Val::Px(theme.font.size.base),
+                            height: Val::Px(theme.font.size.base),
+                            ..default()
+                        },
+                    ));
+                }
             });
 
             // Body
@@ -129,6 +155,7 @@ impl AccordionBuilder {
                 },
                 BackgroundColor(theme.color.gray.step01),
                 body_visibility,
+                UiTransition::default().expand(),
             ));
 
             if let Some(content_fn) = self.content {
@@ -139,26 +166,9 @@ impl AccordionBuilder {
         });
 
         if self.disabled {
-            spawn_disabled_overlay(&mut cmd, theme);
+            cmd.insert(Disabled);
         }
 
         cmd
     }
 }
-
-fn spawn_disabled_overlay<'w>(cmd: &mut EntityCommands<'w>, theme: &UiTheme) {
-    cmd.with_children(|parent| {
-        parent.spawn((
-            Node {
-                position_type: PositionType::Absolute,
-                left: Val::Px(0.0),
-                top: Val::Px(0.0),
-                width: Val::Percent(100.0),
-                height: Val::Percent(100.0),
-                ..default()
-            },
-            BackgroundColor(theme.color.black.step08),
-            FocusPolicy::Block,
-        ));
-    });
-}
@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 
+use crate::components::disabled::DisabledFlag;
+
 /// Marker for the root entity of an accordion item.
 #[derive(Component, Default, Debug, Clone, Copy)]
 pub struct AccordionMarker;
@@ -12,6 +14,10 @@ pub struct AccordionHeaderMarker;
 #[derive(Component, Default, Debug, Clone, Copy)]
 pub struct AccordionBodyMarker;
 
+/// Marker for the optional chevron icon inside the header.
+#[derive(Component, Default, Debug, Clone, Copy)]
+pub struct AccordionChevronMarker;
+
 /// Runtime state of an accordion item.
 #[derive(Component, Debug, Clone, Copy)]
 pub struct AccordionState {
@@ -27,3 +33,13 @@ impl Default for AccordionState {
         }
     }
 }
+
+impl DisabledFlag for AccordionState {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+}
@@ -1,9 +1,11 @@
 use bevy::prelude::*;
 
 use super::{
+    components::AccordionState,
     events::AccordionToggledEvent,
     systems::{handle_accordion_clicks, update_body_visibility},
 };
+use crate::components::disabled::sync_disabled_flag;
 use crate::plugin::UiState;
 
 /// Plugin for accordion functionality.
@@ -16,6 +18,7 @@ impl Plugin for AccordionPlugin {
             (
                 handle_accordion_clicks.run_if(in_state(UiState::Ready)),
                 update_body_visibility.run_if(in_state(UiState::Ready)),
+                sync_disabled_flag::<AccordionState>.run_if(in_state(UiState::Ready)),
             ),
         );
     }
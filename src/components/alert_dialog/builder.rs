@@ -62,7 +62,6 @@ impl AlertDialogBuilder {
         commands: &mut Commands,
         theme: &UiTheme,
         font: &Handle<Font>,
-        portal_root: Option<Res<crate::components::portal::ForgeUiPortalRoot>>,
     ) -> Entity {
         let header = DialogHeaderBuilder::new().title(self.title);
 
@@ -111,7 +110,7 @@ impl AlertDialogBuilder {
         DialogBuilder::new(self.id.into())
             .initially_open(self.initially_open)
             .content(content)
-            .spawn(commands, theme, font, portal_root)
+            .spawn(commands, theme, font)
     }
 
     /// Spawns a trigger button that opens the alert dialog.
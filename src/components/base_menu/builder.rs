@@ -1,13 +1,24 @@
 use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
 
-use crate::components::helper::UiBuilder;
+use crate::components::dismissable_layer::DismissableLayer;
+use crate::components::floating::{Floating, FloatingAlign, FloatingSide};
+use crate::components::helper::{NoAction, UiBuilder};
+use crate::components::layer::{Layer, UiLayer};
+use crate::components::portal::Portal;
+use crate::components::shortcut::{Accelerator, Shortcut, ShortcutHintMarker};
 use crate::theme::UiTheme;
 
-use super::{BaseMenuContentMarker, BaseMenuContentStyle};
+use super::{
+    BaseMenuContentMarker, BaseMenuContentStyle, MenuContentState, MenuGroupLabelMarker, MenuItem,
+    MenuItemIndicatorMarker, MenuItemIndicatorStyle, MenuItemKind, MenuItemStyle,
+    MenuItemTextMarker, MenuSeparatorMarker, MenuSeparatorStyle,
+};
 
 /// Builder for a [`BaseMenuContentMarker`] container.
 pub struct BaseMenuBuilder {
-    content: Option<Box<dyn FnOnce(&mut ChildSpawnerCommands, &UiTheme, &Handle<Font>) + Send + Sync>>,
+    content:
+        Option<Box<dyn FnOnce(&mut ChildSpawnerCommands, &UiTheme, &Handle<Font>) + Send + Sync>>,
 }
 
 impl BaseMenuBuilder {
@@ -29,7 +40,12 @@ impl BaseMenuBuilder {
 impl<'w, 's> UiBuilder<'w, 's> for BaseMenuBuilder {
     type Output = Entity;
 
-    fn spawn(self, parent: &'s mut ChildSpawnerCommands<'w>, theme: &UiTheme, font: &Handle<Font>) -> Self::Output {
+    fn spawn(
+        self,
+        parent: &'s mut ChildSpawnerCommands<'w>,
+        theme: &UiTheme,
+        font: &Handle<Font>,
+    ) -> Self::Output {
         let mut cmd = parent.spawn((BaseMenuContentMarker, BaseMenuContentStyle::default()));
         if let Some(content_fn) = self.content {
             cmd.with_children(|cb| {
@@ -39,3 +55,312 @@ impl<'w, 's> UiBuilder<'w, 's> for BaseMenuBuilder {
         cmd.id()
     }
 }
+
+enum MenuEntry<A: Component + Clone> {
+    Item {
+        label: String,
+        kind: MenuItemKind,
+        disabled: bool,
+        action: Option<A>,
+        shortcut: Option<Accelerator>,
+    },
+    Submenu {
+        label: String,
+        disabled: bool,
+        items: MenuItems<A>,
+    },
+    Separator,
+    Label(String),
+}
+
+/// Entries of a menu panel, shared by context menus, dropdown menus and
+/// menubars.
+///
+/// `action` and `disabled` apply to the entry added last:
+///
+/// ```ignore
+/// MenuItems::new()
+///     .item("Copy").action(EditAction::Copy)
+///     .item("Paste").action(EditAction::Paste).disabled()
+///     .separator()
+///     .checkbox("Show grid", true).action(EditAction::ToggleGrid)
+///     .submenu("Zoom", MenuItems::new().radio("zoom", "100 %", true).radio("zoom", "200 %", false))
+/// ```
+pub struct MenuItems<A: Component + Clone = NoAction> {
+    entries: Vec<MenuEntry<A>>,
+}
+
+impl<A: Component + Clone> Default for MenuItems<A> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<A: Component + Clone> MenuItems<A> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn push_item(mut self, label: impl Into<String>, kind: MenuItemKind) -> Self {
+        self.entries.push(MenuEntry::Item {
+            label: label.into(),
+            kind,
+            disabled: false,
+            action: None,
+            shortcut: None,
+        });
+        self
+    }
+
+    /// Adds a plain item.
+    pub fn item(self, label: impl Into<String>) -> Self {
+        self.push_item(label, MenuItemKind::Action)
+    }
+
+    /// Adds an item that toggles a check mark when chosen.
+    pub fn checkbox(self, label: impl Into<String>, checked: bool) -> Self {
+        self.push_item(label, MenuItemKind::Checkbox { checked })
+    }
+
+    /// Adds an item of a radio group; choosing it unchecks the other items
+    /// of `group` in the same panel.
+    pub fn radio(self, group: impl Into<String>, label: impl Into<String>, checked: bool) -> Self {
+        self.push_item(
+            label,
+            MenuItemKind::Radio {
+                group: group.into(),
+                checked,
+            },
+        )
+    }
+
+    /// Adds an item that opens `items` in a nested panel.
+    pub fn submenu(mut self, label: impl Into<String>, items: MenuItems<A>) -> Self {
+        self.entries.push(MenuEntry::Submenu {
+            label: label.into(),
+            disabled: false,
+            items,
+        });
+        self
+    }
+
+    pub fn separator(mut self) -> Self {
+        self.entries.push(MenuEntry::Separator);
+        self
+    }
+
+    /// Adds a non-interactive group heading.
+    pub fn label(mut self, text: impl Into<String>) -> Self {
+        self.entries.push(MenuEntry::Label(text.into()));
+        self
+    }
+
+    /// Sets the action reported when the last item is chosen.
+    pub fn action(mut self, action: A) -> Self {
+        if let Some(MenuEntry::Item { action: slot, .. }) = self.entries.last_mut() {
+            *slot = Some(action);
+        }
+        self
+    }
+
+    /// Lets `accelerator` choose the last item even while the menu is
+    /// closed. It is shown as a right-aligned hint.
+    pub fn shortcut(mut self, accelerator: Accelerator) -> Self {
+        if let Some(MenuEntry::Item { shortcut, .. }) = self.entries.last_mut() {
+            *shortcut = Some(accelerator);
+        }
+        self
+    }
+
+    /// Disables the last item or submenu.
+    pub fn disabled(mut self) -> Self {
+        match self.entries.last_mut() {
+            Some(MenuEntry::Item { disabled, .. }) | Some(MenuEntry::Submenu { disabled, .. }) => {
+                *disabled = true;
+            }
+            _ => {}
+        }
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Spawns `items` into the menu panel `panel` is spawning into. Submenus get
+/// their own floating panel that is portaled next to their trigger item.
+pub(crate) fn spawn_menu_items<A: Component + Clone>(
+    panel: &mut ChildSpawnerCommands,
+    items: MenuItems<A>,
+    theme: &UiTheme,
+    font: &Handle<Font>,
+) {
+    let panel_entity = panel.target_entity();
+    for entry in items.entries {
+        match entry {
+            MenuEntry::Item {
+                label,
+                kind,
+                disabled,
+                action,
+                shortcut,
+            } => {
+                let indicator = match &kind {
+                    MenuItemKind::Checkbox { .. } => Some(false),
+                    MenuItemKind::Radio { .. } => Some(true),
+                    _ => None,
+                };
+                let mut item = spawn_item_row(panel, &label, kind, disabled, theme);
+                if let Some(action) = action {
+                    item.insert(action);
+                }
+                if let Some(accelerator) = shortcut {
+                    item.insert(Shortcut(accelerator));
+                }
+                item.with_children(|ic| {
+                    if let Some(round) = indicator {
+                        ic.spawn((
+                            MenuItemIndicatorMarker,
+                            MenuItemIndicatorStyle::new(theme, round),
+                        ));
+                    }
+                    spawn_item_text(ic, label, disabled, theme, font);
+                    if let Some(accelerator) = shortcut {
+                        ic.spawn((
+                            ShortcutHintMarker,
+                            Text::new(accelerator.to_string()),
+                            TextFont {
+                                font: font.clone(),
+                                font_size: theme.font.size.xs,
+                                ..default()
+                            },
+                            TextColor(theme.color.gray.step10),
+                            Node {
+                                margin: UiRect::left(Val::Auto),
+                                ..default()
+                            },
+                        ));
+                    }
+                });
+            }
+            MenuEntry::Submenu {
+                label,
+                disabled,
+                items,
+            } => {
+                let kind = MenuItemKind::SubTrigger {
+                    submenu: Entity::PLACEHOLDER,
+                };
+                let mut item = spawn_item_row(panel, &label, kind, disabled, theme);
+                let trigger = item.id();
+                let mut submenu = Entity::PLACEHOLDER;
+                item.with_children(|ic| {
+                    spawn_item_text(ic, label.clone(), disabled, theme, font);
+                    ic.spawn((
+                        Text::new("›"),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: theme.font.size.sm,
+                            ..default()
+                        },
+                        TextColor(theme.color.gray.step10),
+                        Node {
+                            margin: UiRect::left(Val::Auto),
+                            ..default()
+                        },
+                    ));
+
+                    // Eigenes Panel, das im Portal neben dem Eintrag schwebt
+                    let mut sub = ic.spawn((
+                        BaseMenuContentMarker,
+                        BaseMenuContentStyle::new(theme),
+                        MenuContentState::submenu(panel_entity),
+                        Floating::new(trigger)
+                            .side(FloatingSide::Right)
+                            .align(FloatingAlign::Start)
+                            .side_offset(theme.layout.padding.xs)
+                            .align_offset(-theme.layout.padding.xs),
+                        Portal::global(),
+                        Layer(UiLayer::Dropdown),
+                        DismissableLayer::new().branch(panel_entity),
+                        Visibility::Hidden,
+                        Name::new(format!("Submenu {label}")),
+                    ));
+                    submenu = sub.id();
+                    sub.with_children(|sc| spawn_menu_items(sc, items, theme, font));
+                });
+                item.insert(MenuItem {
+                    label,
+                    kind: MenuItemKind::SubTrigger { submenu },
+                    disabled,
+                });
+            }
+            MenuEntry::Separator => {
+                panel.spawn((MenuSeparatorMarker, MenuSeparatorStyle::new(theme)));
+            }
+            MenuEntry::Label(text) => {
+                panel
+                    .spawn((
+                        MenuGroupLabelMarker,
+                        Node {
+                            padding: UiRect::all(Val::Px(theme.layout.padding.xs)),
+                            ..default()
+                        },
+                    ))
+                    .with_children(|lc| {
+                        lc.spawn((
+                            Text::new(text),
+                            TextFont {
+                                font: font.clone(),
+                                font_size: theme.font.size.xs,
+                                ..default()
+                            },
+                            TextColor(theme.color.gray.step10),
+                        ));
+                    });
+            }
+        }
+    }
+}
+
+fn spawn_item_row<'a>(
+    panel: &'a mut ChildSpawnerCommands,
+    label: &str,
+    kind: MenuItemKind,
+    disabled: bool,
+    theme: &UiTheme,
+) -> EntityCommands<'a> {
+    panel.spawn((
+        MenuItem {
+            label: label.to_string(),
+            kind,
+            disabled,
+        },
+        MenuItemStyle::new(theme),
+        Interaction::default(),
+        FocusPolicy::Block,
+        Name::new(label.to_string()),
+    ))
+}
+
+fn spawn_item_text(
+    item: &mut ChildSpawnerCommands,
+    label: String,
+    disabled: bool,
+    theme: &UiTheme,
+    font: &Handle<Font>,
+) {
+    item.spawn((
+        MenuItemTextMarker,
+        Text::new(label),
+        TextFont {
+            font: font.clone(),
+            font_size: theme.font.size.sm,
+            ..default()
+        },
+        TextColor(MenuItemStyle::text_color(theme, disabled)),
+    ));
+}
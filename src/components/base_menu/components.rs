@@ -1,5 +1,8 @@
 use bevy::{prelude::*, ui::FocusPolicy};
 
+use crate::components::disabled::DisabledFlag;
+use crate::theme::UiTheme;
+
 /// Marker component for a base menu content container.
 #[derive(Component, Debug, Clone, Copy, Default)]
 pub struct BaseMenuContentMarker;
@@ -28,3 +31,134 @@ impl Default for BaseMenuContentStyle {
         }
     }
 }
+
+impl BaseMenuContentStyle {
+    /// Themed panel used for menus and their submenus.
+    pub fn new(theme: &UiTheme) -> Self {
+        Self {
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                min_width: Val::Px(160.0),
+                padding: UiRect::all(Val::Px(theme.layout.padding.xs)),
+                overflow: Overflow::clip_y(),
+                ..default()
+            },
+            background_color: BackgroundColor(theme.color.gray.step02),
+            border_radius: BorderRadius::all(Val::Px(theme.layout.radius.sm)),
+            focus_policy: FocusPolicy::Block,
+        }
+    }
+}
+
+/// State of one menu panel: the top-level content of a menu or one of its
+/// submenus.
+///
+/// Panels with `parent == None` are the root of a menu tree. Closing a panel
+/// closes all submenus below it.
+#[derive(Component, Debug, Clone)]
+pub struct MenuContentState {
+    pub open: bool,
+    /// Item under the pointer or selected with the arrow keys.
+    pub highlighted: Option<Entity>,
+    /// Panel the submenu was opened from, `None` for the root panel.
+    pub parent: Option<Entity>,
+    /// Entity that gets the focus back when the root panel closes.
+    pub return_focus: Option<Entity>,
+    /// Whether choosing an item closes the menu (root panel only).
+    pub close_on_select: bool,
+    /// Item chosen by pointer or keyboard, applied by the menu systems.
+    pub(crate) chosen: Option<Entity>,
+    /// Characters typed for typeahead and the time of the last one.
+    pub(crate) typeahead: String,
+    pub(crate) typeahead_at: f32,
+}
+
+impl Default for MenuContentState {
+    fn default() -> Self {
+        Self {
+            open: false,
+            highlighted: None,
+            parent: None,
+            return_focus: None,
+            close_on_select: true,
+            chosen: None,
+            typeahead: String::new(),
+            typeahead_at: 0.0,
+        }
+    }
+}
+
+impl MenuContentState {
+    pub fn new(open: bool) -> Self {
+        Self { open, ..default() }
+    }
+
+    /// State of a submenu panel opened from `parent`.
+    pub fn submenu(parent: Entity) -> Self {
+        Self {
+            parent: Some(parent),
+            ..default()
+        }
+    }
+}
+
+/// What happens when a [`MenuItem`] is chosen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MenuItemKind {
+    /// Plain item that only reports the selection.
+    Action,
+    /// Toggles `checked` on every selection.
+    Checkbox { checked: bool },
+    /// Checks this item and unchecks the other items of `group` in the
+    /// same panel.
+    Radio { group: String, checked: bool },
+    /// Opens the `submenu` panel instead of closing the menu.
+    SubTrigger { submenu: Entity },
+}
+
+/// A selectable row of a menu panel.
+#[derive(Component, Debug, Clone)]
+pub struct MenuItem {
+    pub label: String,
+    pub kind: MenuItemKind,
+    pub disabled: bool,
+}
+
+impl DisabledFlag for MenuItem {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+}
+
+impl MenuItem {
+    /// Checked state of checkbox and radio items, `None` for the others.
+    pub fn checked(&self) -> Option<bool> {
+        match self.kind {
+            MenuItemKind::Checkbox { checked } | MenuItemKind::Radio { checked, .. } => {
+                Some(checked)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Text of a [`MenuItem`], dimmed while the item is disabled.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct MenuItemTextMarker;
+
+/// Box in front of checkbox and radio items that is filled while checked.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct MenuItemIndicatorMarker;
+
+/// Horizontal line between groups of items.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct MenuSeparatorMarker;
+
+/// Non-interactive heading inside a menu panel.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct MenuGroupLabelMarker;
@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+
+/// Sent when a [`MenuItem`](super::MenuItem) other than a submenu trigger is
+/// chosen. Menu widgets turn it into their typed selection events.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct MenuItemActivatedEvent {
+    /// Root panel of the menu the item belongs to.
+    pub menu: Entity,
+    pub item: Entity,
+}
@@ -1,5 +1,16 @@
 mod builder;
 mod components;
+mod events;
+mod plugin;
+mod style;
+mod systems;
 
 pub use builder::*;
 pub use components::*;
+pub use events::*;
+pub use plugin::BaseMenuPlugin;
+pub use style::*;
+pub(crate) use systems::{
+    apply_menu_activation, enabled_items, handle_menu_dismiss, handle_menu_keyboard,
+    sync_menu_panels,
+};
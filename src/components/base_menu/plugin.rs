@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+use super::systems::*;
+use super::{MenuItem, MenuItemActivatedEvent};
+use crate::components::disabled::sync_disabled_flag;
+use crate::components::shortcut::ShortcutTriggeredEvent;
+use crate::plugin::UiState;
+
+/// Shared behaviour of menu panels: highlighting, keyboard navigation,
+/// submenus, checkbox/radio items and closing on select or dismiss.
+pub struct BaseMenuPlugin;
+
+impl Plugin for BaseMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<MenuItemActivatedEvent>()
+            .add_event::<ShortcutTriggeredEvent>()
+            .add_systems(
+                Update,
+                (
+                    // `Disabled` auf Einträgen wirkt wie `MenuItems::disabled()`
+                    sync_disabled_flag::<MenuItem>,
+                    handle_menu_dismiss,
+                    handle_menu_item_pointer,
+                    handle_menu_item_shortcuts,
+                    handle_menu_keyboard,
+                    apply_menu_activation,
+                    sync_menu_panels,
+                    update_menu_item_visuals,
+                )
+                    .chain()
+                    .run_if(in_state(UiState::Ready)),
+            );
+    }
+}
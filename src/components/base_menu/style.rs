@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+
+use crate::theme::UiTheme;
+
+/// Style bundle for a menu item row.
+#[derive(Bundle, Clone, Debug)]
+pub struct MenuItemStyle {
+    pub node: Node,
+    pub background_color: BackgroundColor,
+    pub border_radius: BorderRadius,
+}
+
+impl MenuItemStyle {
+    pub fn new(theme: &UiTheme) -> Self {
+        MenuItemStyle {
+            node: Node {
+                display: Display::Flex,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(theme.layout.gap.sm),
+                padding: UiRect::all(Val::Px(theme.layout.padding.xs)),
+                ..default()
+            },
+            background_color: BackgroundColor(Color::NONE),
+            border_radius: BorderRadius::all(Val::Px(theme.layout.radius.xs)),
+        }
+    }
+
+    pub fn background(theme: &UiTheme, highlighted: bool) -> BackgroundColor {
+        if highlighted {
+            BackgroundColor(theme.color.gray.step04)
+        } else {
+            BackgroundColor(Color::NONE)
+        }
+    }
+
+    pub fn text_color(theme: &UiTheme, disabled: bool) -> Color {
+        if disabled {
+            theme.color.gray.step08
+        } else {
+            theme.color.gray.step12
+        }
+    }
+}
+
+/// Style bundle for the check box / radio dot of an item.
+#[derive(Bundle, Clone, Debug)]
+pub struct MenuItemIndicatorStyle {
+    pub node: Node,
+    pub background_color: BackgroundColor,
+    pub border_color: BorderColor,
+    pub border_radius: BorderRadius,
+}
+
+impl MenuItemIndicatorStyle {
+    pub fn new(theme: &UiTheme, round: bool) -> Self {
+        let size = theme.font.size.sm * 0.75;
+        MenuItemIndicatorStyle {
+            node: Node {
+                width: Val::Px(size),
+                height: Val::Px(size),
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            background_color: BackgroundColor(Color::NONE),
+            border_color: BorderColor(theme.color.gray.step08),
+            border_radius: if round {
+                BorderRadius::MAX
+            } else {
+                BorderRadius::all(Val::Px(2.0))
+            },
+        }
+    }
+
+    pub fn background(theme: &UiTheme, checked: bool) -> BackgroundColor {
+        if checked {
+            BackgroundColor(theme.accent.step09)
+        } else {
+            BackgroundColor(Color::NONE)
+        }
+    }
+}
+
+/// Style bundle for a separator line.
+#[derive(Bundle, Clone, Debug)]
+pub struct MenuSeparatorStyle {
+    pub node: Node,
+    pub background_color: BackgroundColor,
+}
+
+impl MenuSeparatorStyle {
+    pub fn new(theme: &UiTheme) -> Self {
+        MenuSeparatorStyle {
+            node: Node {
+                height: Val::Px(1.0),
+                margin: UiRect::vertical(Val::Px(theme.layout.margin.xs)),
+                ..default()
+            },
+            background_color: BackgroundColor(theme.color.gray.step06),
+        }
+    }
+}
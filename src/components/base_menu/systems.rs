@@ -0,0 +1,426 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::input_focus::InputFocus;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+use super::*;
+use crate::components::dismissable_layer::LayerDismissedEvent;
+use crate::components::shortcut::ShortcutTriggeredEvent;
+use crate::theme::UiTheme;
+
+/// Time after which typed characters start a new typeahead search, in
+/// seconds.
+const TYPEAHEAD_TIMEOUT: f32 = 1.0;
+
+/// Enabled items of `panel` in display order.
+pub(crate) fn enabled_items(
+    panel: Entity,
+    children: &Query<&Children>,
+    items: &Query<&MenuItem>,
+) -> Vec<Entity> {
+    children
+        .get(panel)
+        .map(|c| {
+            c.iter()
+                .filter(|e| items.get(*e).is_ok_and(|item| !item.disabled))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Submenu panel opened by `item`, if it is an enabled submenu trigger.
+fn submenu_of(item: Entity, items: &Query<&MenuItem>) -> Option<Entity> {
+    match items.get(item) {
+        Ok(MenuItem {
+            kind: MenuItemKind::SubTrigger { submenu },
+            disabled: false,
+            ..
+        }) => Some(*submenu),
+        _ => None,
+    }
+}
+
+/// Next item whose label starts with `search`. Repeating one letter cycles
+/// through the items starting with it, longer searches keep the current
+/// item while it still matches.
+fn typeahead_match(
+    list: &[Entity],
+    current: Option<usize>,
+    search: &str,
+    items: &Query<&MenuItem>,
+) -> Option<Entity> {
+    let mut chars = search.chars();
+    let first = chars.next()?;
+    let repeated = chars.all(|c| c == first);
+    let (search, start) = if repeated {
+        (first.to_string(), current.map_or(0, |i| i + 1))
+    } else {
+        (search.to_string(), current.unwrap_or(0))
+    };
+    (0..list.len())
+        .map(|offset| list[(start + offset) % list.len()])
+        .find(|e| {
+            items
+                .get(*e)
+                .is_ok_and(|item| item.label.to_lowercase().starts_with(&search))
+        })
+}
+
+/// Root panel of the menu tree `panel` belongs to.
+fn root_of(panel: Entity, parents: impl Fn(Entity) -> Option<Entity>) -> Entity {
+    let mut current = panel;
+    while let Some(parent) = parents(current) {
+        current = parent;
+    }
+    current
+}
+
+/// Closes the whole menu when one of its panels was dismissed by Escape, a
+/// click outside or the focus leaving it.
+pub fn handle_menu_dismiss(
+    mut dismissed: EventReader<LayerDismissedEvent>,
+    mut panels: Query<&mut MenuContentState>,
+) {
+    for event in dismissed.read() {
+        if !panels.contains(event.layer) {
+            continue;
+        }
+        let root = root_of(event.layer, |e| panels.get(e).ok().and_then(|s| s.parent));
+        if let Ok(mut state) = panels.get_mut(root) {
+            state.open = false;
+        }
+    }
+}
+
+/// Hovering highlights an item and opens submenus, pressing chooses it.
+pub fn handle_menu_item_pointer(
+    changed: Query<(Entity, &Interaction, &ChildOf), (With<MenuItem>, Changed<Interaction>)>,
+    items: Query<&MenuItem>,
+    mut panels: Query<&mut MenuContentState>,
+) {
+    for (entity, interaction, child_of) in changed.iter() {
+        let panel = child_of.parent();
+        if !panels.get(panel).is_ok_and(|s| s.open) {
+            continue;
+        }
+        let Ok(item) = items.get(entity) else {
+            continue;
+        };
+        let submenu = submenu_of(entity, &items);
+        let submenu_open = submenu.is_some_and(|s| panels.get(s).is_ok_and(|s| s.open));
+        let Ok(mut state) = panels.get_mut(panel) else {
+            continue;
+        };
+
+        match interaction {
+            Interaction::None => {
+                // Ein offenes Untermenü behält seinen Eintrag hervorgehoben
+                if state.highlighted == Some(entity) && !submenu_open {
+                    state.highlighted = None;
+                }
+            }
+            Interaction::Hovered | Interaction::Pressed => {
+                if item.disabled {
+                    if state.highlighted.is_some() {
+                        state.highlighted = None;
+                    }
+                    continue;
+                }
+                if state.highlighted != Some(entity) {
+                    state.highlighted = Some(entity);
+                }
+                if *interaction == Interaction::Pressed {
+                    state.chosen = Some(entity);
+                } else if let Some(mut sub) = submenu.and_then(|s| panels.get_mut(s).ok()) {
+                    if !sub.open {
+                        sub.open = true;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Chooses items whose [`Shortcut`](crate::components::shortcut::Shortcut)
+/// was pressed, also while their menu is closed.
+pub fn handle_menu_item_shortcuts(
+    mut shortcuts: EventReader<ShortcutTriggeredEvent>,
+    items: Query<(&MenuItem, &ChildOf)>,
+    mut panels: Query<&mut MenuContentState>,
+) {
+    for event in shortcuts.read() {
+        let Ok((item, child_of)) = items.get(event.entity) else {
+            continue;
+        };
+        if item.disabled || matches!(item.kind, MenuItemKind::SubTrigger { .. }) {
+            continue;
+        }
+        if let Ok(mut state) = panels.get_mut(child_of.parent()) {
+            state.chosen = Some(event.entity);
+        }
+    }
+}
+
+/// Arrow keys move the highlight in the innermost open panel of the focused
+/// menu, Right/Left open and close submenus and Enter/Space choose the
+/// highlighted item. Typing letters jumps to the next matching item.
+pub fn handle_menu_keyboard(
+    time: Res<Time>,
+    focus: Res<InputFocus>,
+    mut key_events: EventReader<KeyboardInput>,
+    mut panels: Query<(Entity, &mut MenuContentState)>,
+    items: Query<&MenuItem>,
+    children: Query<&Children>,
+) {
+    let Some(root) = focus.get().filter(|e| {
+        panels
+            .get(*e)
+            .is_ok_and(|(_, s)| s.open && s.parent.is_none())
+    }) else {
+        key_events.clear();
+        return;
+    };
+
+    // Innerstes offenes Untermenü
+    let mut active = root;
+    while let Some(open_child) = panels
+        .iter()
+        .find(|(_, s)| s.open && s.parent == Some(active))
+        .map(|(e, _)| e)
+    {
+        active = open_child;
+    }
+
+    for event in key_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        let list = enabled_items(active, &children, &items);
+        let Ok((_, mut state)) = panels.get_mut(active) else {
+            return;
+        };
+        let current = state
+            .highlighted
+            .and_then(|h| list.iter().position(|e| *e == h));
+        match &event.logical_key {
+            Key::ArrowDown | Key::ArrowUp if !list.is_empty() => {
+                let len = list.len();
+                let next = match (current, event.logical_key == Key::ArrowDown) {
+                    (Some(i), true) => (i + 1) % len,
+                    (Some(i), false) => (i + len - 1) % len,
+                    (None, true) => 0,
+                    (None, false) => len - 1,
+                };
+                state.highlighted = Some(list[next]);
+            }
+            Key::Home if !list.is_empty() => state.highlighted = list.first().copied(),
+            Key::End if !list.is_empty() => state.highlighted = list.last().copied(),
+            Key::ArrowRight | Key::Enter | Key::Space => {
+                let Some(highlighted) = state.highlighted else {
+                    continue;
+                };
+                match submenu_of(highlighted, &items) {
+                    Some(submenu) => {
+                        let first = enabled_items(submenu, &children, &items).first().copied();
+                        if let Ok((_, mut sub)) = panels.get_mut(submenu) {
+                            sub.open = true;
+                            sub.highlighted = first;
+                            active = submenu;
+                        }
+                    }
+                    None if event.logical_key != Key::ArrowRight => {
+                        state.chosen = Some(highlighted);
+                    }
+                    None => {}
+                }
+            }
+            Key::ArrowLeft => {
+                if let Some(parent) = state.parent {
+                    state.open = false;
+                    active = parent;
+                }
+            }
+            Key::Character(text) if !list.is_empty() => {
+                let now = time.elapsed_secs();
+                if now - state.typeahead_at > TYPEAHEAD_TIMEOUT {
+                    state.typeahead.clear();
+                }
+                state.typeahead.push_str(&text.to_lowercase());
+                state.typeahead_at = now;
+                if let Some(found) = typeahead_match(&list, current, &state.typeahead, &items) {
+                    state.highlighted = Some(found);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Applies the items chosen by pointer or keyboard: toggles checkbox and
+/// radio items, reports the selection and closes the menu unless its root
+/// panel has `close_on_select` turned off.
+pub fn apply_menu_activation(
+    mut panels: Query<(Entity, &mut MenuContentState)>,
+    mut items: Query<(Entity, &mut MenuItem, &ChildOf)>,
+    mut activated: EventWriter<MenuItemActivatedEvent>,
+) {
+    let mut chosen = Vec::new();
+    for (panel, mut state) in panels.iter_mut() {
+        if state.chosen.is_some() {
+            chosen.extend(state.chosen.take().map(|item| (panel, item)));
+        }
+    }
+
+    for (panel, entity) in chosen {
+        let Ok((_, item, _)) = items.get(entity) else {
+            continue;
+        };
+        if item.disabled {
+            continue;
+        }
+        match item.kind.clone() {
+            MenuItemKind::SubTrigger { submenu } => {
+                if let Ok((_, mut sub)) = panels.get_mut(submenu) {
+                    sub.open = true;
+                }
+                continue;
+            }
+            MenuItemKind::Checkbox { checked } => {
+                if let Ok((_, mut item, _)) = items.get_mut(entity) {
+                    item.kind = MenuItemKind::Checkbox { checked: !checked };
+                }
+            }
+            MenuItemKind::Radio { group, .. } => {
+                for (other, mut item, child_of) in items.iter_mut() {
+                    if child_of.parent() != panel {
+                        continue;
+                    }
+                    if let MenuItemKind::Radio {
+                        group: other_group,
+                        checked,
+                    } = &mut item.kind
+                    {
+                        if *other_group == group && *checked != (other == entity) {
+                            *checked = other == entity;
+                        }
+                    }
+                }
+            }
+            MenuItemKind::Action => {}
+        }
+
+        let root = root_of(panel, |e| panels.get(e).ok().and_then(|(_, s)| s.parent));
+        activated.write(MenuItemActivatedEvent {
+            menu: root,
+            item: entity,
+        });
+        if let Ok((_, mut state)) = panels.get_mut(root) {
+            if state.close_on_select {
+                state.open = false;
+            }
+        }
+    }
+}
+
+/// Closes submenus whose panel closed or whose trigger lost the highlight,
+/// shows and hides the panels and moves the focus into an opened menu and
+/// back out of a closed one.
+pub fn sync_menu_panels(
+    mut focus: ResMut<InputFocus>,
+    mut panels: Query<(Entity, &mut MenuContentState, &mut Visibility)>,
+    items: Query<&MenuItem>,
+) {
+    // 1) Untermenüs schließen, bis sich nichts mehr ändert
+    loop {
+        let snapshot: HashMap<Entity, (bool, Option<Entity>)> = panels
+            .iter()
+            .map(|(e, s, _)| (e, (s.open, s.highlighted)))
+            .collect();
+        let mut changed = false;
+        for (entity, mut state, _) in panels.iter_mut() {
+            let Some(parent) = state.parent.filter(|_| state.open) else {
+                continue;
+            };
+            let keep = snapshot.get(&parent).is_some_and(|(open, highlighted)| {
+                *open && highlighted.and_then(|h| submenu_of(h, &items)) == Some(entity)
+            });
+            if !keep {
+                state.open = false;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    // 2) Sichtbarkeit und Fokus nur bei Zustandswechseln anpassen
+    for (entity, mut state, mut visibility) in panels.iter_mut() {
+        if !state.is_changed() {
+            continue;
+        }
+        let wanted = if state.open {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        if *visibility != wanted {
+            *visibility = wanted;
+            if state.parent.is_none() {
+                if state.open {
+                    focus.set(entity);
+                } else if focus.get() == Some(entity) {
+                    match state.return_focus {
+                        Some(target) => focus.set(target),
+                        None => focus.clear(),
+                    }
+                }
+            }
+        }
+        if !state.open && state.highlighted.is_some() {
+            state.highlighted = None;
+        }
+    }
+}
+
+/// Colors highlighted items, dims disabled ones and fills the indicators of
+/// checked checkbox and radio items.
+pub fn update_menu_item_visuals(
+    theme: Option<Res<UiTheme>>,
+    panels: Query<&MenuContentState>,
+    mut items: Query<
+        (Entity, &MenuItem, &ChildOf, &mut BackgroundColor, &Children),
+        Without<MenuItemIndicatorMarker>,
+    >,
+    mut indicators: Query<&mut BackgroundColor, With<MenuItemIndicatorMarker>>,
+    mut texts: Query<&mut TextColor, With<MenuItemTextMarker>>,
+) {
+    let Some(theme) = theme else {
+        return;
+    };
+    for (entity, item, child_of, mut background, children) in items.iter_mut() {
+        let highlighted = panels
+            .get(child_of.parent())
+            .is_ok_and(|s| s.open && s.highlighted == Some(entity));
+        let wanted = MenuItemStyle::background(&theme, highlighted);
+        if background.0 != wanted.0 {
+            *background = wanted;
+        }
+
+        let color = MenuItemStyle::text_color(&theme, item.disabled);
+        let indicator = MenuItemIndicatorStyle::background(&theme, item.checked().unwrap_or(false));
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                if text.0 != color {
+                    text.0 = color;
+                }
+            }
+            if let Ok(mut fill) = indicators.get_mut(child) {
+                if fill.0 != indicator.0 {
+                    *fill = indicator;
+                }
+            }
+        }
+    }
+}
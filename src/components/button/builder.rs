@@ -2,7 +2,7 @@
 use super::{
     enums::{ButtonChild, ButtonSize, ButtonVariant},
     style::ButtonStyle,
-    {ButtonDisabledOverlayMarker, ButtonMarker, ButtonState},
+    {ButtonMarker, ButtonState},
 };
 
 use crate::components::helper::NoAction;
@@ -211,14 +211,10 @@ impl<A: Component + Clone + Send + Sync> ButtonBuilder<A> {
         };
 
         // --- Werte für die 'static Closure klonen ---
-        let cloned_name = self.name.clone();
         let cloned_children = self.children_defs;
-        let cloned_disabled = self.disabled;
-        let overlay_bg_color: Color = theme.color.black.step08.into();
         let text_color = ButtonStyle::text_color(&color_palette, self.variant);
         let font_handle = font_family.clone();
         let font_size = button_style.text_style.font_size;
-        let cloned_border_radius = border_radius;
         // Wichtig: Die Aktion wird hier nicht behandelt, da sie nicht Teil des
         // visuellen Bundles ist. Sie muss nach dem Spawnen separat eingefügt werden.
 
@@ -262,26 +258,8 @@ impl<A: Component + Clone + Send + Sync> ButtonBuilder<A> {
                     }
                 }
 
-                // 2. Spawne das Overlay darüber, wenn der Button deaktiviert ist
-                if cloned_disabled {
-                    let _ = parent.spawn((
-                        Name::new(format!("{}_Overlay", cloned_name)),
-                        ButtonDisabledOverlayMarker,
-                        Node {
-                            position_type: PositionType::Absolute,
-                            width: Val::Percent(100.0),
-                            height: Val::Percent(100.0),
-                            left: Val::Px(0.0),
-                            top: Val::Px(0.0),
-                            ..default()
-                        },
-                        BackgroundColor(overlay_bg_color),
-                        cloned_border_radius,
-                        FocusPolicy::Block, // Blockiert keine Klicks
-                    ));
-                } else {
-                    let _ = parent.spawn(());
-                }
+                // Das Disabled-Overlay kommt aus `Disabled`, das für
+                // `ButtonState.disabled` automatisch eingefügt wird.
             })),
             self.action,
         )
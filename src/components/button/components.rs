@@ -5,6 +5,7 @@
 //! the button systems to determine visual appearance and behavior.
 
 use super::enums::{ButtonSize, ButtonVariant};
+use crate::components::disabled::DisabledFlag;
 use crate::theme::UiColorPalette;
 use bevy::prelude::*;
 // PhantomData is no longer needed for OnClick
@@ -17,10 +18,6 @@ use bevy::prelude::*;
 #[derive(Component, Default, Debug)]
 pub struct ButtonMarker;
 
-/// Marker component for the disabled overlay of a button.
-#[derive(Component, Default, Debug)]
-pub struct ButtonDisabledOverlayMarker;
-
 /// Stores the configured state of a button, such as variant, size, and disabled status.
 ///
 /// This component holds all the configurable properties that determine a button's
@@ -44,3 +41,13 @@ pub struct ButtonState {
     /// Whether the button is currently disabled
     pub disabled: bool,
 }
+
+impl DisabledFlag for ButtonState {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+}
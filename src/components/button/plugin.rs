@@ -4,9 +4,12 @@
 //! and visual updates. It registers the necessary systems and events for basic
 //! button functionality with the default `NoAction` type.
 use crate::components::button::{
-    handle_button_release, update_button_visuals, ButtonClickedEvent,
+    handle_button_release, handle_button_shortcuts, update_button_visuals, ButtonClickedEvent,
+    ButtonState,
 };
+use crate::components::disabled::sync_disabled_flag;
 use crate::components::helper::NoAction;
+use crate::components::shortcut::ShortcutTriggeredEvent;
 use crate::plugin::UiState;
 use bevy::prelude::*;
 use std::marker::PhantomData;
@@ -28,17 +31,34 @@ impl<A: Component + Clone + Send + Sync> Default for ButtonPlugin<A> {
 
 impl<A: Component + Clone + Send + Sync + std::fmt::Debug> Plugin for ButtonPlugin<A> {
     fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<ButtonStatePlugin>() {
+            app.add_plugins(ButtonStatePlugin);
+        }
         app.add_event::<ButtonClickedEvent<A>>().add_systems(
             Update,
             (
                 // handle_button_press::<A>,
                 handle_button_release::<A>,
-                update_button_visuals,
+                handle_button_shortcuts::<A>,
             )
                 .run_if(in_state(UiState::Ready)),
         );
     }
 }
 
+/// Systeme, die nicht vom Aktionstyp abhängen. Wird vom ersten
+/// [`ButtonPlugin`] hinzugefügt, damit sie nur einmal laufen.
+pub struct ButtonStatePlugin;
+
+impl Plugin for ButtonStatePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ShortcutTriggeredEvent>().add_systems(
+            Update,
+            (update_button_visuals, sync_disabled_flag::<ButtonState>)
+                .run_if(in_state(UiState::Ready)),
+        );
+    }
+}
+
 /// Bequemer Alias für Buttons ohne Aktionstyp
 pub type ButtonNoActionPlugin = ButtonPlugin<NoAction>;
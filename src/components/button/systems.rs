@@ -7,6 +7,7 @@
 use super::components::{ButtonMarker, ButtonState}; // NoAction nicht unbedingt hier nötig
 use super::events::ButtonClickedEvent; // Generisches Event importieren
 use super::style::ButtonStyle;
+use crate::components::shortcut::ShortcutTriggeredEvent;
 use bevy::prelude::*;
 use std::collections::HashMap;
 
@@ -104,3 +105,27 @@ pub fn handle_button_release<A: Component + Clone + std::fmt::Debug>(
         prev.insert(entity, *interaction);
     }
 }
+
+/// Löst einen Button über seinen [`Shortcut`](crate::components::shortcut::Shortcut) aus.
+///
+/// Sendet dasselbe [`ButtonClickedEvent`] wie ein Mausklick, deaktivierte
+/// Buttons werden ignoriert.
+pub fn handle_button_shortcuts<A: Component + Clone + std::fmt::Debug>(
+    mut shortcuts: EventReader<ShortcutTriggeredEvent>,
+    mut writer: EventWriter<ButtonClickedEvent<A>>,
+    query: Query<(&A, &ButtonState), With<ButtonMarker>>,
+) {
+    for ev in shortcuts.read() {
+        let Ok((action, state)) = query.get(ev.entity) else {
+            continue;
+        };
+        if state.disabled {
+            continue;
+        }
+        writer.write(ButtonClickedEvent {
+            source_entity: ev.entity,
+            action_id: Some(action.clone()),
+        });
+        info!("Button triggered via shortcut {}: {:?}", ev.accelerator, action);
+    }
+}
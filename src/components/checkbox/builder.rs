@@ -3,7 +3,8 @@ use crate::assets::IconAssets;
 use crate::components::checkbox::components::{
     CheckboxMarker, CheckboxState, CheckmarkIconEntity,
 };
-use crate::components::checkbox::style::{CheckboxStyle, spawn_disabled_overlay};
+use crate::components::checkbox::style::CheckboxStyle;
+use crate::components::disabled::Disabled;
 use crate::theme::UiTheme;
 use bevy::{ecs::system::EntityCommands, prelude::*, ui::FocusPolicy};
 
@@ -170,7 +171,7 @@ impl CheckboxBuilder {
         checkbox_cmd.insert(CheckmarkIconEntity(checkmark_entity));
 
         if self.disabled {
-            spawn_disabled_overlay(&mut checkbox_cmd, theme, style.border_radius);
+            checkbox_cmd.insert(Disabled);
         }
 
         for marker_fn in self.markers {
@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 
+use crate::components::disabled::DisabledFlag;
+
 /// Marker-Komponente für die Checkbox-Entity.
 ///
 /// Dient zur Identifikation in Queries und für Systemspezifisches Styling.
@@ -25,12 +27,18 @@ impl Default for CheckboxState {
     }
 }
 
+impl DisabledFlag for CheckboxState {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+}
+
 /// Enthält die Entity des internal gerenderten Checkmark-Icons.
 ///
 /// Wird genutzt, um Sichtbarkeit und Styling des Icons zu steuern.
 #[derive(Component, Debug, Clone, Copy)]
 pub struct CheckmarkIconEntity(pub Entity);
-
-/// Marker for the overlay entity shown when a checkbox is disabled.
-#[derive(Component, Default, Debug, Clone, Copy)]
-pub struct CheckboxDisabledOverlayMarker;
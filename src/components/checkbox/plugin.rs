@@ -7,6 +7,8 @@ use super::{
         update_checkmark_visibility_on_state_change,
     },
 };
+use super::CheckboxState;
+use crate::components::disabled::sync_disabled_flag;
 use crate::plugin::UiState;
 
 /// Plugin registering systems and events for [`CheckboxBuilder`].
@@ -22,6 +24,7 @@ impl Plugin for CheckboxPlugin {
                     update_checkbox_visuals,
                     handle_checkbox_clicks,
                     update_checkmark_visibility_on_state_change,
+                    sync_disabled_flag::<CheckboxState>,
                 )
                     .run_if(in_state(UiState::Ready)),
             );
@@ -1,5 +1,4 @@
 use bevy::prelude::*;
-use bevy::ui::FocusPolicy;
 use crate::theme::UiTheme;
 
 /// Bundles style components for a checkbox.
@@ -31,23 +30,3 @@ impl CheckboxStyle {
         }
     }
 }
-
-/// Spawns a translucent overlay to indicate disabled state.
-pub fn spawn_disabled_overlay(cmd: &mut EntityCommands, theme: &UiTheme, radius: BorderRadius) {
-    cmd.with_children(|parent| {
-        parent.spawn((
-            Node {
-                position_type: PositionType::Absolute,
-                left: Val::Px(0.0),
-                top: Val::Px(0.0),
-                width: Val::Percent(100.0),
-                height: Val::Percent(100.0),
-                ..default()
-            },
-            BackgroundColor(theme.color.black.step08),
-            FocusPolicy::Block,
-            Visibility::Visible,
-            radius,
-        ));
-    });
-}
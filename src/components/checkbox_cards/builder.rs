@@ -1,12 +1,12 @@
 use bevy::prelude::*;
 
 use crate::assets::IconAssets;
-use crate::components::{checkbox::CheckboxBuilder, label::LabelBuilder};
+use crate::components::{checkbox::CheckboxBuilder, disabled::Disabled, label::LabelBuilder};
 use crate::theme::UiTheme;
 
 use super::{
     components::CheckboxCardMarker,
-    style::{spawn_disabled_overlay, CheckboxCardStyle},
+    style::CheckboxCardStyle,
 };
 
 /// Fluent builder to create a simple checkbox card with a label.
@@ -67,9 +67,9 @@ impl CheckboxCardBuilder {
         }
 
         cmd.with_children(|p| {
+            // Der Disabled-Zustand der Checkbox wird von der Karte geerbt
             let _ = CheckboxBuilder::new()
                 .checked(self.checked)
-                .disabled(self.disabled)
                 .spawn(p, theme, icons);
 
             let _ = LabelBuilder::new(self.label)
@@ -78,7 +78,7 @@ impl CheckboxCardBuilder {
         });
 
         if self.disabled {
-            spawn_disabled_overlay(&mut cmd, theme, style.border_radius);
+            cmd.insert(Disabled);
         }
 
         cmd
@@ -2,7 +2,11 @@ use bevy::prelude::*;
 use std::collections::HashSet;
 
 use crate::assets::IconAssets;
-use crate::components::{checkbox::CheckboxBuilder, label::LabelBuilder};
+use crate::components::{
+    checkbox::CheckboxBuilder,
+    label::LabelBuilder,
+    roving_focus::{RovingFocusGroup, RovingFocusItem, RovingFocusOrientation},
+};
 use crate::theme::UiTheme;
 
 use super::components::{
@@ -15,6 +19,7 @@ pub struct CheckboxGroupBuilder {
     disabled: bool,
     options: Vec<(String, String)>,
     checked: HashSet<String>,
+    loop_navigation: bool,
 }
 
 impl CheckboxGroupBuilder {
@@ -25,6 +30,7 @@ impl CheckboxGroupBuilder {
             disabled: false,
             options: Vec::new(),
             checked: HashSet::new(),
+            loop_navigation: true,
         }
     }
 
@@ -40,6 +46,12 @@ impl CheckboxGroupBuilder {
         self
     }
 
+    /// Whether arrow-key navigation wraps around at the ends (default: `true`).
+    pub fn loop_navigation(mut self, flag: bool) -> Self {
+        self.loop_navigation = flag;
+        self
+    }
+
     /// Add an option with value and label text.
     pub fn option(mut self, value: impl Into<String>, label: impl Into<String>) -> Self {
         self.options.push((value.into(), label.into()));
@@ -80,6 +92,13 @@ impl CheckboxGroupBuilder {
                 disabled: self.disabled,
                 checked_values: self.checked.clone(),
             },
+            RovingFocusGroup::new(
+                match self.orientation {
+                    CheckboxGroupOrientation::Horizontal => RovingFocusOrientation::Horizontal,
+                    CheckboxGroupOrientation::Vertical => RovingFocusOrientation::Vertical,
+                },
+                self.loop_navigation,
+            ),
         ));
 
         let options = self.options;
@@ -105,7 +124,10 @@ impl CheckboxGroupBuilder {
                             .add_marker({
                                 let v = value.clone();
                                 move |ec| {
-                                    ec.insert(CheckboxGroupItem { value: v.clone() });
+                                    ec.insert((
+                                        CheckboxGroupItem { value: v.clone() },
+                                        RovingFocusItem,
+                                    ));
                                 }
                             })
                             .spawn(cb, theme, icons);
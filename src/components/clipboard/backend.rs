@@ -0,0 +1,138 @@
+use bevy::prelude::*;
+
+/// Storage behind the [`Clipboard`] resource.
+///
+/// On desktop the `system_clipboard` feature (on by default) provides the
+/// [`SystemClipboard`]. Without it, or when no system clipboard is reachable,
+/// the [`MemoryClipboard`] is used, which only shares text within the app and
+/// keeps headless tests deterministic. Implement the trait for other
+/// platforms and install it with [`Clipboard::new`].
+pub trait ClipboardBackend: Send + Sync + 'static {
+    /// Current clipboard text, `None` if empty or not text.
+    fn get_text(&mut self) -> Option<String>;
+
+    /// Replaces the clipboard content.
+    fn set_text(&mut self, text: &str);
+}
+
+/// Clipboard that lives in memory only.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryClipboard {
+    text: Option<String>,
+}
+
+impl ClipboardBackend for MemoryClipboard {
+    fn get_text(&mut self) -> Option<String> {
+        self.text.clone()
+    }
+
+    fn set_text(&mut self, text: &str) {
+        self.text = Some(text.to_string());
+    }
+}
+
+/// System clipboard of the operating system via `arboard`.
+///
+/// Falls back to a [`MemoryClipboard`] if the system clipboard cannot be
+/// opened (e.g. headless on Linux without a display server).
+#[cfg(all(
+    feature = "system_clipboard",
+    not(any(target_arch = "wasm32", target_os = "android", target_os = "ios"))
+))]
+pub struct SystemClipboard {
+    system: Option<arboard::Clipboard>,
+    fallback: MemoryClipboard,
+}
+
+#[cfg(all(
+    feature = "system_clipboard",
+    not(any(target_arch = "wasm32", target_os = "android", target_os = "ios"))
+))]
+impl Default for SystemClipboard {
+    fn default() -> Self {
+        let system = arboard::Clipboard::new()
+            .inspect_err(|e| warn!("System clipboard unavailable, using memory: {e}"))
+            .ok();
+        Self {
+            system,
+            fallback: MemoryClipboard::default(),
+        }
+    }
+}
+
+#[cfg(all(
+    feature = "system_clipboard",
+    not(any(target_arch = "wasm32", target_os = "android", target_os = "ios"))
+))]
+impl ClipboardBackend for SystemClipboard {
+    fn get_text(&mut self) -> Option<String> {
+        match self.system.as_mut() {
+            Some(system) => system.get_text().ok(),
+            None => self.fallback.get_text(),
+        }
+    }
+
+    fn set_text(&mut self, text: &str) {
+        match self.system.as_mut() {
+            Some(system) => {
+                if let Err(e) = system.set_text(text) {
+                    warn!("Could not write to the system clipboard: {e}");
+                }
+            }
+            None => self.fallback.set_text(text),
+        }
+    }
+}
+
+/// Clipboard used by text inputs, selectable text and copy buttons.
+///
+/// Defaults to the [`SystemClipboard`] where available, otherwise to the
+/// [`MemoryClipboard`]. Tests can swap in a deterministic backend:
+///
+/// ```ignore
+/// app.insert_resource(Clipboard::new(MemoryClipboard::default()));
+/// ```
+#[derive(Resource)]
+pub struct Clipboard {
+    backend: Box<dyn ClipboardBackend>,
+}
+
+impl Clipboard {
+    pub fn new(backend: impl ClipboardBackend) -> Self {
+        Self {
+            backend: Box::new(backend),
+        }
+    }
+
+    pub fn get_text(&mut self) -> Option<String> {
+        self.backend.get_text()
+    }
+
+    pub fn set_text(&mut self, text: impl AsRef<str>) {
+        self.backend.set_text(text.as_ref());
+    }
+}
+
+impl Default for Clipboard {
+    #[cfg(all(
+        feature = "system_clipboard",
+        not(any(target_arch = "wasm32", target_os = "android", target_os = "ios"))
+    ))]
+    fn default() -> Self {
+        Self::new(SystemClipboard::default())
+    }
+
+    #[cfg(not(all(
+        feature = "system_clipboard",
+        not(any(target_arch = "wasm32", target_os = "android", target_os = "ios"))
+    )))]
+    fn default() -> Self {
+        Self::new(MemoryClipboard::default())
+    }
+}
+
+impl std::fmt::Debug for Clipboard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Clipboard").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,39 @@
+use std::ops::Range;
+
+use bevy::prelude::*;
+
+/// Makes the text of a label or code element selectable with the mouse.
+///
+/// Sits on the container whose child carries the `Text`. Once focused by a
+/// click, Ctrl+A selects everything and Ctrl+C copies the selection to the
+/// [`Clipboard`](super::Clipboard). Indices are byte offsets into the text.
+#[derive(Component, Debug, Clone, Default)]
+pub struct SelectableText {
+    pub(crate) anchor: usize,
+    pub(crate) caret: usize,
+    pub(crate) dragging: bool,
+}
+
+impl SelectableText {
+    /// Selected byte range, ordered.
+    pub fn selection(&self) -> Range<usize> {
+        self.anchor.min(self.caret)..self.anchor.max(self.caret)
+    }
+
+    pub fn has_selection(&self) -> bool {
+        self.anchor != self.caret
+    }
+
+    pub fn select(&mut self, range: Range<usize>) {
+        self.anchor = range.start;
+        self.caret = range.end;
+    }
+
+    pub fn clear(&mut self) {
+        self.anchor = self.caret;
+    }
+}
+
+/// One highlight rectangle behind selected text.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct SelectableTextHighlight;
@@ -0,0 +1,17 @@
+mod backend;
+mod components;
+mod plugin;
+mod systems;
+
+#[cfg(all(
+    feature = "system_clipboard",
+    not(any(target_arch = "wasm32", target_os = "android", target_os = "ios"))
+))]
+pub use backend::SystemClipboard;
+pub use backend::{Clipboard, ClipboardBackend, MemoryClipboard};
+pub use components::{SelectableText, SelectableTextHighlight};
+pub use plugin::ClipboardPlugin;
+pub use systems::{
+    handle_selectable_text_keyboard, handle_selectable_text_pointer,
+    update_selectable_text_highlights,
+};
@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+
+use super::backend::Clipboard;
+use super::systems::*;
+use crate::plugin::UiState;
+
+/// Provides the [`Clipboard`] resource and selectable text.
+///
+/// Added before the text field plugins, which copy and paste through the
+/// same resource. Insert an own [`Clipboard`] beforehand to use another
+/// backend than the default one.
+pub struct ClipboardPlugin;
+
+impl Plugin for ClipboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Clipboard>().add_systems(
+            Update,
+            (
+                handle_selectable_text_pointer,
+                handle_selectable_text_keyboard,
+                update_selectable_text_highlights,
+            )
+                .chain()
+                .run_if(in_state(UiState::Ready)),
+        );
+    }
+}
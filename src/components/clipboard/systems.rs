@@ -0,0 +1,197 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::input_focus::InputFocus;
+use bevy::prelude::*;
+use bevy::text::ComputedTextBlock;
+use bevy::ui::RelativeCursorPosition;
+
+use super::{Clipboard, SelectableText, SelectableTextHighlight};
+use crate::components::shortcut::Modifiers;
+use crate::components::text_field::{caret_stops, index_at, selection_rects, CaretStop};
+use crate::theme::UiTheme;
+
+type TextParts<'a> = (
+    &'a Text,
+    &'a ComputedNode,
+    &'a GlobalTransform,
+    Option<&'a ComputedTextBlock>,
+    Option<&'a RelativeCursorPosition>,
+);
+
+/// First child of `container` that carries the selectable `Text`.
+fn text_child(children: Option<&Children>, texts: &Query<TextParts>) -> Option<Entity> {
+    children?.iter().find(|child| texts.contains(*child))
+}
+
+fn stops_of(text: &Text, node: &ComputedNode, block: Option<&ComputedTextBlock>) -> Vec<CaretStop> {
+    match block {
+        Some(block) if !text.0.is_empty() => {
+            caret_stops(&text.0, block, node.inverse_scale_factor())
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Selects text by clicking and dragging; Shift+click extends the selection.
+/// A click also focuses the text so the keyboard shortcuts reach it.
+pub fn handle_selectable_text_pointer(
+    mouse: Res<ButtonInput<MouseButton>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut focus: ResMut<InputFocus>,
+    mut selectables: Query<(Entity, &Interaction, &mut SelectableText, Option<&Children>)>,
+    texts: Query<TextParts>,
+) {
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    for (entity, interaction, mut selectable, children) in selectables.iter_mut() {
+        if !mouse.pressed(MouseButton::Left) {
+            if selectable.dragging {
+                selectable.dragging = false;
+            }
+            continue;
+        }
+        let pressed = mouse.just_pressed(MouseButton::Left) && *interaction == Interaction::Pressed;
+        if !pressed && !selectable.dragging {
+            continue;
+        }
+        let Some((text, node, _, block, Some(cursor))) =
+            text_child(children, &texts).and_then(|e| texts.get(e).ok())
+        else {
+            continue;
+        };
+        let Some(normalized) = cursor.normalized else {
+            continue;
+        };
+
+        let point = normalized * node.size() * node.inverse_scale_factor();
+        let index = index_at(&stops_of(text, node, block), point);
+        if pressed {
+            if focus.get() != Some(entity) {
+                focus.set(entity);
+            }
+            if !shift {
+                selectable.anchor = index;
+            }
+        }
+        selectable.caret = index;
+        selectable.dragging = true;
+    }
+}
+
+/// Ctrl+A selects all, Ctrl+C copies the selection of the focused text.
+pub fn handle_selectable_text_keyboard(
+    keys: Res<ButtonInput<KeyCode>>,
+    focus: Res<InputFocus>,
+    mut clipboard: ResMut<Clipboard>,
+    mut key_events: EventReader<KeyboardInput>,
+    mut selectables: Query<(&mut SelectableText, Option<&Children>)>,
+    texts: Query<TextParts>,
+) {
+    let Some((mut selectable, children)) = focus.get().and_then(|e| selectables.get_mut(e).ok())
+    else {
+        key_events.clear();
+        return;
+    };
+    let Some((text, ..)) = text_child(children, &texts).and_then(|e| texts.get(e).ok()) else {
+        key_events.clear();
+        return;
+    };
+
+    let modifiers = Modifiers::pressed(&keys);
+    if !(modifiers.ctrl || modifiers.super_key) {
+        key_events.clear();
+        return;
+    }
+    for event in key_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        let Key::Character(c) = &event.logical_key else {
+            continue;
+        };
+        match c.to_lowercase().as_str() {
+            "a" => selectable.select(0..text.0.len()),
+            "c" => {
+                if let Some(selected) = text.0.get(selectable.selection()) {
+                    if !selected.is_empty() {
+                        clipboard.set_text(selected);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Draws the selection of focused selectable texts behind the glyphs.
+pub fn update_selectable_text_highlights(
+    mut commands: Commands,
+    theme: Option<Res<UiTheme>>,
+    focus: Res<InputFocus>,
+    selectables: Query<(
+        Entity,
+        &SelectableText,
+        &ComputedNode,
+        &GlobalTransform,
+        Option<&Children>,
+    )>,
+    texts: Query<TextParts>,
+    mut highlights: Query<&mut Node, With<SelectableTextHighlight>>,
+) {
+    let Some(theme) = theme else {
+        return;
+    };
+
+    for (entity, selectable, node, transform, children) in selectables.iter() {
+        let mut rects = Vec::new();
+        let mut offset = Vec2::ZERO;
+        if focus.get() == Some(entity) && selectable.has_selection() {
+            if let Some((text, text_node, text_transform, block, _)) =
+                text_child(children, &texts).and_then(|e| texts.get(e).ok())
+            {
+                // Textposition relativ zum Container, in logischen Pixeln
+                let text_min = text_transform.translation().truncate() - text_node.size() / 2.0;
+                let min = transform.translation().truncate() - node.size() / 2.0;
+                offset = (text_min - min) * node.inverse_scale_factor();
+                let len = text.0.len();
+                let range = selectable.selection();
+                let range = range.start.min(len)..range.end.min(len);
+                rects = selection_rects(&stops_of(text, text_node, block), range);
+            }
+        }
+
+        let existing: Vec<Entity> = children
+            .map(|c| c.iter().filter(|e| highlights.contains(*e)).collect())
+            .unwrap_or_default();
+        for (i, rect) in rects.iter().enumerate() {
+            let wanted = Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(offset.x + rect.min.x),
+                top: Val::Px(offset.y + rect.min.y),
+                width: Val::Px(rect.width()),
+                height: Val::Px(rect.height()),
+                ..default()
+            };
+            match existing.get(i).and_then(|e| highlights.get_mut(*e).ok()) {
+                Some(mut current) => {
+                    if *current != wanted {
+                        *current = wanted;
+                    }
+                }
+                None => {
+                    // Vor dem Text einfügen, damit die Markierung dahinter liegt
+                    let highlight = commands
+                        .spawn((
+                            SelectableTextHighlight,
+                            wanted,
+                            BackgroundColor(theme.accent.step05),
+                        ))
+                        .id();
+                    commands.entity(entity).insert_children(0, &[highlight]);
+                }
+            }
+        }
+        for extra in existing.iter().skip(rects.len()) {
+            commands.entity(*extra).despawn();
+        }
+    }
+}
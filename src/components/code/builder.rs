@@ -1,30 +1,67 @@
 use bevy::prelude::*;
+use bevy::ui::{FocusPolicy, RelativeCursorPosition};
 
-use crate::components::helper::UiBuilder;
+use crate::components::button::{ButtonBuilder, ButtonSize, ButtonVariant};
+use crate::components::clipboard::SelectableText;
+use crate::components::helper::{NoAction, UiBuilder};
 use crate::theme::UiTheme;
 
-use super::{CodeMarker, CodeStyle};
+use super::{CodeCopyButton, CodeMarker, CodeStyle, CodeTextMarker, COPY_LABEL};
 
 /// Builder for an inline code element.
 pub struct CodeBuilder {
     text: String,
+    selectable: bool,
+    copy_button: bool,
 }
 
 impl CodeBuilder {
     /// Creates a new code snippet with the given text.
     pub fn new(text: impl Into<String>) -> Self {
-        Self { text: text.into() }
+        Self {
+            text: text.into(),
+            selectable: false,
+            copy_button: false,
+        }
+    }
+
+    /// Lets the user select the code with the mouse and copy it with Ctrl+C.
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
+    /// Adds a button that copies the whole snippet.
+    pub fn copy_button(mut self, copy_button: bool) -> Self {
+        self.copy_button = copy_button;
+        self
     }
 }
 
 impl<'w, 's> UiBuilder<'w, 's> for CodeBuilder {
     type Output = Entity;
 
-    fn spawn(self, parent: &'s mut ChildSpawnerCommands<'w>, theme: &UiTheme, _font: &Handle<Font>) -> Self::Output {
-        let style = CodeStyle::new(theme);
+    fn spawn(
+        self,
+        parent: &'s mut ChildSpawnerCommands<'w>,
+        theme: &UiTheme,
+        font: &Handle<Font>,
+    ) -> Self::Output {
+        let mut style = CodeStyle::new(theme);
+        if self.copy_button {
+            style.node.column_gap = Val::Px(theme.layout.gap.sm);
+        }
         let mut cmd = parent.spawn((CodeMarker, style));
+        if self.selectable {
+            cmd.insert((
+                SelectableText::default(),
+                Interaction::default(),
+                FocusPolicy::Block,
+            ));
+        }
         cmd.with_children(|cb| {
-            cb.spawn((
+            let mut text = cb.spawn((
+                CodeTextMarker,
                 Text::new(self.text),
                 TextFont {
                     font: theme.font.family.mono.regular.clone(),
@@ -33,7 +70,26 @@ impl<'w, 's> UiBuilder<'w, 's> for CodeBuilder {
                 },
                 TextLayout::default(),
                 TextColor(theme.color.slate.step12),
+                FocusPolicy::Pass,
             ));
+            if self.selectable {
+                text.insert(RelativeCursorPosition::default());
+            }
+            let code = text.id();
+
+            if self.copy_button {
+                cb.spawn((
+                    ButtonBuilder::<NoAction>::new("Copy code")
+                        .text(COPY_LABEL)
+                        .variant(ButtonVariant::Ghost)
+                        .size(ButtonSize::Small)
+                        .build(theme, font),
+                    CodeCopyButton {
+                        code,
+                        copied_at: None,
+                    },
+                ));
+            }
         });
         cmd.id()
     }
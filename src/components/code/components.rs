@@ -3,3 +3,16 @@ use bevy::prelude::*;
 /// Marker component for inline code snippets.
 #[derive(Component, Default, Debug, Clone, Copy)]
 pub struct CodeMarker;
+
+/// Marker for the `Text` entity holding the code.
+#[derive(Component, Default, Debug, Clone, Copy)]
+pub struct CodeTextMarker;
+
+/// Copy button of a code element; copies the text of `code` to the
+/// [`Clipboard`](crate::components::clipboard::Clipboard).
+#[derive(Component, Debug, Clone, Copy)]
+pub struct CodeCopyButton {
+    pub code: Entity,
+    /// Time of the last copy, while the button shows the confirmation.
+    pub(crate) copied_at: Option<f32>,
+}
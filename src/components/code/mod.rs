@@ -1,7 +1,11 @@
 mod builder;
 mod components;
+mod plugin;
 mod style;
+mod systems;
 
 pub use builder::CodeBuilder;
-pub use components::CodeMarker;
+pub use components::{CodeCopyButton, CodeMarker, CodeTextMarker};
+pub use plugin::CodePlugin;
 pub use style::CodeStyle;
+pub use systems::{handle_code_copy_buttons, update_code_copy_labels, COPIED_LABEL, COPY_LABEL};
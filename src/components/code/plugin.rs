@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+
+use super::systems::*;
+use crate::components::button::handle_button_release;
+use crate::components::helper::NoAction;
+use crate::plugin::UiState;
+
+/// Plugin for the copy button of [`CodeBuilder`](super::CodeBuilder).
+pub struct CodePlugin;
+
+impl Plugin for CodePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                handle_code_copy_buttons.after(handle_button_release::<NoAction>),
+                update_code_copy_labels,
+            )
+                .chain()
+                .run_if(in_state(UiState::Ready)),
+        );
+    }
+}
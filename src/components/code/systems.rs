@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+
+use super::CodeCopyButton;
+use crate::components::button::ButtonClickedEvent;
+use crate::components::clipboard::Clipboard;
+use crate::components::helper::NoAction;
+
+pub const COPY_LABEL: &str = "Copy";
+pub const COPIED_LABEL: &str = "Copied";
+
+/// How long the button shows [`COPIED_LABEL`] after a click, in seconds.
+const COPIED_DURATION: f32 = 1.5;
+
+/// Copies the code to the clipboard when its copy button is clicked.
+pub fn handle_code_copy_buttons(
+    time: Res<Time>,
+    mut clicks: EventReader<ButtonClickedEvent<NoAction>>,
+    mut clipboard: ResMut<Clipboard>,
+    mut buttons: Query<&mut CodeCopyButton>,
+    texts: Query<&Text>,
+) {
+    for event in clicks.read() {
+        let Ok(mut button) = buttons.get_mut(event.source_entity) else {
+            continue;
+        };
+        let Ok(text) = texts.get(button.code) else {
+            continue;
+        };
+        clipboard.set_text(&text.0);
+        button.copied_at = Some(time.elapsed_secs());
+    }
+}
+
+/// Switches the button label between "Copy" and the short confirmation.
+pub fn update_code_copy_labels(
+    time: Res<Time>,
+    mut buttons: Query<(&mut CodeCopyButton, &Children)>,
+    mut texts: Query<&mut Text>,
+) {
+    let now = time.elapsed_secs();
+    for (mut button, children) in buttons.iter_mut() {
+        if button
+            .copied_at
+            .is_some_and(|at| now - at >= COPIED_DURATION)
+        {
+            button.copied_at = None;
+        }
+        let label = if button.copied_at.is_some() {
+            COPIED_LABEL
+        } else {
+            COPY_LABEL
+        };
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                if text.0 != label {
+                    text.0 = label.to_string();
+                }
+            }
+        }
+    }
+}
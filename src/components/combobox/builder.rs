@@ -0,0 +1,227 @@
+use bevy::input_focus::tab_navigation::TabIndex;
+use bevy::prelude::*;
+use bevy::text::LineBreak;
+use bevy::ui::FocusPolicy;
+
+use super::*;
+use crate::components::disabled::Disabled;
+use crate::components::dismissable_layer::DismissableLayer;
+use crate::components::floating::{Floating, FloatingAlign};
+use crate::components::helper::UiBuilder;
+use crate::components::label::LabelBuilder;
+use crate::components::layer::{Layer, UiLayer};
+use crate::components::portal::Portal;
+use crate::components::text_field::{spawn_text_field_viewport, TextFieldState, TextFieldStyle};
+use crate::theme::UiTheme;
+
+/// Builder for a select with a text input that filters its options.
+///
+/// Typing opens the list and filters it ([`ComboboxFilter`]), Arrow Up and
+/// Down move the highlight, Enter or a click chooses the option and Escape
+/// closes the list. With [`allow_create`](Self::allow_create) text that
+/// matches no option can be added as a new one. Choices are reported
+/// through [`ComboboxSelectedEvent`].
+///
+/// ```ignore
+/// ComboboxBuilder::new()
+///     .option("de", "Germany")
+///     .option("fr", "France")
+///     .placeholder("Country")
+///     .spawn(parent, &theme, &font);
+/// ```
+pub struct ComboboxBuilder {
+    options: Vec<(String, String)>,
+    selected: Option<String>,
+    placeholder: String,
+    empty_text: String,
+    filter: ComboboxFilter,
+    allow_create: bool,
+    width: Option<Val>,
+    disabled: bool,
+}
+
+impl ComboboxBuilder {
+    pub fn new() -> Self {
+        Self {
+            options: Vec::new(),
+            selected: None,
+            placeholder: "Search...".to_string(),
+            empty_text: "No results".to_string(),
+            filter: ComboboxFilter::Fuzzy,
+            allow_create: false,
+            width: None,
+            disabled: false,
+        }
+    }
+
+    pub fn option(mut self, value: impl Into<String>, label: impl Into<String>) -> Self {
+        self.options.push((value.into(), label.into()));
+        self
+    }
+
+    /// Value of the initially chosen option.
+    pub fn selected(mut self, value: impl Into<String>) -> Self {
+        self.selected = Some(value.into());
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Text shown when no option matches.
+    pub fn empty_text(mut self, text: impl Into<String>) -> Self {
+        self.empty_text = text.into();
+        self
+    }
+
+    pub fn filter(mut self, filter: ComboboxFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Offers to create an option from text that matches no label; the new
+    /// option uses the text as value and label.
+    pub fn allow_create(mut self, allow: bool) -> Self {
+        self.allow_create = allow;
+        self
+    }
+
+    pub fn width(mut self, width: Val) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl Default for ComboboxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'w, 's> UiBuilder<'w, 's> for ComboboxBuilder {
+    type Output = Entity;
+
+    fn spawn(
+        self,
+        parent: &'s mut ChildSpawnerCommands<'w>,
+        theme: &UiTheme,
+        font: &Handle<Font>,
+    ) -> Self::Output {
+        let mut root_style = ComboboxRootStyle::new();
+        if let Some(width) = self.width {
+            root_style.node.width = width;
+        }
+        let selected_label = self
+            .selected
+            .as_ref()
+            .and_then(|v| self.options.iter().find(|(value, _)| value == v))
+            .map(|(_, label)| label.clone())
+            .unwrap_or_default();
+        let mut input_state = TextFieldState::new(selected_label);
+        input_state.placeholder = self.placeholder;
+
+        let mut state = ComboboxState {
+            selected: self.selected,
+            filter: self.filter,
+            allow_create: self.allow_create,
+            ..default()
+        };
+
+        let mut root = parent.spawn((ComboboxMarker, root_style, Name::new("Combobox")));
+        root.with_children(|rc| {
+            let input = rc
+                .spawn((
+                    ComboboxInputMarker,
+                    TextFieldStyle::new(theme),
+                    input_state,
+                    Interaction::default(),
+                    FocusPolicy::Block,
+                    TabIndex(0),
+                ))
+                .with_children(|ic| {
+                    spawn_text_field_viewport(ic, theme, font, LineBreak::NoWrap);
+                })
+                .id();
+
+            let floating = Floating::new(input)
+                .align(FloatingAlign::Start)
+                .side_offset(theme.layout.gap.xs)
+                .match_anchor_width(true);
+            rc.spawn((
+                ComboboxContentMarker,
+                ComboboxContentStyle::new(theme),
+                floating,
+                Portal::global(),
+                Layer(UiLayer::Dropdown),
+                DismissableLayer::new().branch(input),
+            ))
+            .with_children(|cc| {
+                for (value, label) in self.options {
+                    let option = spawn_combobox_option(cc, theme, font, value, label);
+                    state.options.push(option);
+                }
+
+                if self.allow_create {
+                    let mut style = ComboboxOptionStyle::new(theme);
+                    style.node.display = Display::None;
+                    cc.spawn((
+                        ComboboxCreateOptionMarker,
+                        style,
+                        Interaction::default(),
+                        FocusPolicy::Block,
+                    ))
+                    .with_children(|oc| {
+                        let _ = LabelBuilder::new("")
+                            .color(theme.color.gray.step11)
+                            .spawn(oc, theme, font);
+                    });
+                }
+
+                let empty = LabelBuilder::new(self.empty_text)
+                    .font_size(theme.font.size.sm)
+                    .color(theme.color.gray.step10)
+                    .margin(UiRect::all(Val::Px(theme.layout.padding.sm)))
+                    .spawn(cc, theme, font);
+                cc.commands().entity(empty).insert(ComboboxEmptyMarker);
+            });
+        });
+
+        root.insert(state);
+        if self.disabled {
+            root.insert(Disabled);
+        }
+        root.id()
+    }
+}
+
+/// Spawns one option row; also used for options created at runtime.
+pub(crate) fn spawn_combobox_option(
+    parent: &mut ChildSpawnerCommands,
+    theme: &UiTheme,
+    font: &Handle<Font>,
+    value: String,
+    label: String,
+) -> Entity {
+    let mut option = parent.spawn((
+        ComboboxOptionMarker {
+            value,
+            label: label.clone(),
+        },
+        ComboboxOptionStyle::new(theme),
+        Interaction::default(),
+        FocusPolicy::Block,
+    ));
+    option.with_children(|oc| {
+        let _ = LabelBuilder::new(label)
+            .color(theme.color.gray.step12)
+            .spawn(oc, theme, font);
+    });
+    option.id()
+}
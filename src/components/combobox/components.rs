@@ -0,0 +1,90 @@
+use bevy::prelude::*;
+
+/// Marker for the root node of a combobox.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct ComboboxMarker;
+
+/// Text input used to filter the options.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct ComboboxInputMarker;
+
+/// List below the input holding the options.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct ComboboxContentMarker;
+
+/// One option of a combobox, same shape as
+/// [`SelectOptionMarker`](crate::components::select::SelectOptionMarker).
+#[derive(Component, Debug, Clone)]
+pub struct ComboboxOptionMarker {
+    pub value: String,
+    pub label: String,
+}
+
+/// Entry that creates a new option from the typed text.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct ComboboxCreateOptionMarker;
+
+/// Text shown when nothing matches.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct ComboboxEmptyMarker;
+
+/// How typed text is matched against the option labels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ComboboxFilter {
+    /// Label or one of its words starts with the text.
+    Prefix,
+    /// All typed characters appear in order; prefix matches rank first.
+    #[default]
+    Fuzzy,
+}
+
+/// State of a combobox.
+///
+/// `selected` holds the value of the chosen option, like
+/// [`SelectState::selected`](crate::components::select::SelectState::selected).
+#[derive(Component, Debug, Clone, Default)]
+pub struct ComboboxState {
+    pub open: bool,
+    pub selected: Option<String>,
+    pub filter: ComboboxFilter,
+    /// Offers to create an option from text that matches no label.
+    pub allow_create: bool,
+    pub(crate) query: String,
+    /// Options in their original order, also after filtering.
+    pub(crate) options: Vec<Entity>,
+    /// Visible entries in display order, including the create entry.
+    pub(crate) matches: Vec<Entity>,
+    pub(crate) highlighted: Option<Entity>,
+    /// Entry chosen this frame, applied by `apply_combobox_choice`.
+    pub(crate) chosen: Option<Entity>,
+}
+
+impl ComboboxState {
+    /// Text currently used to filter the options.
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Entry that Enter would choose.
+    pub fn highlighted(&self) -> Option<Entity> {
+        self.highlighted
+    }
+
+    /// Moves the highlight by `delta` entries, wrapping around.
+    pub(crate) fn move_highlight(&mut self, delta: isize) {
+        if self.matches.is_empty() {
+            self.highlighted = None;
+            return;
+        }
+        let len = self.matches.len() as isize;
+        let next = match self
+            .highlighted
+            .and_then(|h| self.matches.iter().position(|e| *e == h))
+        {
+            Some(current) => (current as isize + delta).rem_euclid(len),
+            None if delta < 0 => len - 1,
+            None => 0,
+        };
+        self.highlighted = Some(self.matches[next as usize]);
+    }
+}
@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+
+/// Sent when an option of a combobox is chosen. `selected` carries the new
+/// value of [`ComboboxState::selected`](super::ComboboxState::selected).
+#[derive(Event, Debug, Clone)]
+pub struct ComboboxSelectedEvent {
+    pub combobox: Entity,
+    pub selected: Option<String>,
+}
+
+/// Sent when the user creates a new option from the typed text; followed
+/// by a [`ComboboxSelectedEvent`] for the new value.
+#[derive(Event, Debug, Clone)]
+pub struct ComboboxCreatedEvent {
+    pub combobox: Entity,
+    pub value: String,
+}
@@ -0,0 +1,41 @@
+use super::ComboboxFilter;
+
+/// Rank of `label` for the typed `query`, lower is better; `None` if the
+/// label does not match.
+///
+/// Matching ignores case. A label starting with the query ranks 0, a word
+/// inside the label starting with it ranks 1. With
+/// [`ComboboxFilter::Fuzzy`] any label containing the query characters in
+/// order matches as well, ranked by how far apart they are.
+pub fn match_score(filter: ComboboxFilter, query: &str, label: &str) -> Option<u32> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Some(0);
+    }
+    let label = label.to_lowercase();
+    if label.starts_with(&query) {
+        return Some(0);
+    }
+    let word_prefix = label
+        .split(|c: char| c.is_whitespace() || c == '-' || c == '_')
+        .any(|word| word.starts_with(&query));
+    if word_prefix {
+        return Some(1);
+    }
+    if filter == ComboboxFilter::Prefix {
+        return None;
+    }
+
+    // Teilfolge: jedes Zeichen der Eingabe in Reihenfolge, Lücken kosten
+    let mut gaps = 0;
+    let mut last = None;
+    let mut chars = label.chars().enumerate();
+    for wanted in query.chars() {
+        let (index, _) = chars.by_ref().find(|(_, c)| *c == wanted)?;
+        if let Some(last) = last {
+            gaps += (index - last - 1) as u32;
+        }
+        last = Some(index);
+    }
+    Some(2 + gaps)
+}
@@ -0,0 +1,15 @@
+mod builder;
+mod components;
+mod events;
+mod matching;
+mod plugin;
+mod style;
+mod systems;
+
+pub use builder::ComboboxBuilder;
+pub use components::*;
+pub use events::*;
+pub use matching::match_score;
+pub use plugin::ComboboxPlugin;
+pub use style::*;
+pub use systems::*;
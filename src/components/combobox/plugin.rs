@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+
+use super::events::{ComboboxCreatedEvent, ComboboxSelectedEvent};
+use super::systems::*;
+use crate::components::text_field::{handle_text_field_keyboard, update_text_field_visuals};
+use crate::plugin::UiState;
+
+/// Plugin for [`ComboboxBuilder`](super::ComboboxBuilder).
+pub struct ComboboxPlugin;
+
+impl Plugin for ComboboxPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ComboboxSelectedEvent>()
+            .add_event::<ComboboxCreatedEvent>()
+            .add_systems(
+                Update,
+                (
+                    handle_combobox_input.after(handle_text_field_keyboard),
+                    handle_combobox_dismiss,
+                    handle_combobox_keyboard,
+                    handle_combobox_option_pointer,
+                    apply_combobox_choice,
+                    update_combobox_options,
+                    update_combobox_visuals,
+                )
+                    .chain()
+                    .before(update_text_field_visuals)
+                    .run_if(in_state(UiState::Ready)),
+            );
+    }
+}
@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+
+use crate::theme::UiTheme;
+
+#[derive(Bundle, Clone, Debug)]
+pub struct ComboboxRootStyle {
+    pub node: Node,
+}
+
+impl ComboboxRootStyle {
+    pub fn new() -> Self {
+        Self {
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                position_type: PositionType::Relative,
+                ..default()
+            },
+        }
+    }
+}
+
+impl Default for ComboboxRootStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// List below the input, positioned by a
+/// [`Floating`](crate::components::floating::Floating).
+#[derive(Bundle, Clone, Debug)]
+pub struct ComboboxContentStyle {
+    pub node: Node,
+    pub background_color: BackgroundColor,
+    pub border_color: BorderColor,
+    pub border_radius: BorderRadius,
+}
+
+impl ComboboxContentStyle {
+    pub fn new(theme: &UiTheme) -> Self {
+        Self {
+            node: Node {
+                display: Display::None,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Stretch,
+                position_type: PositionType::Absolute,
+                padding: UiRect::all(Val::Px(theme.layout.padding.xs)),
+                max_height: Val::Px(240.0),
+                overflow: Overflow::clip_y(),
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            background_color: BackgroundColor(theme.color.gray.step02),
+            border_color: BorderColor(theme.color.gray.step06),
+            border_radius: BorderRadius::all(Val::Px(theme.layout.radius.sm)),
+        }
+    }
+}
+
+#[derive(Bundle, Clone, Debug)]
+pub struct ComboboxOptionStyle {
+    pub node: Node,
+    pub background_color: BackgroundColor,
+    pub border_radius: BorderRadius,
+}
+
+impl ComboboxOptionStyle {
+    pub fn new(theme: &UiTheme) -> Self {
+        Self {
+            node: Node {
+                display: Display::Flex,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(theme.layout.padding.sm)),
+                ..default()
+            },
+            background_color: BackgroundColor(Color::NONE),
+            border_radius: BorderRadius::all(Val::Px(theme.layout.radius.xs)),
+        }
+    }
+
+    /// Background of an option depending on highlight and selection.
+    pub fn background(theme: &UiTheme, highlighted: bool, selected: bool) -> BackgroundColor {
+        if highlighted {
+            BackgroundColor(theme.color.gray.step04)
+        } else if selected {
+            BackgroundColor(theme.accent.step03)
+        } else {
+            BackgroundColor(Color::NONE)
+        }
+    }
+}
@@ -0,0 +1,372 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::input_focus::InputFocus;
+use bevy::prelude::*;
+
+use super::builder::spawn_combobox_option;
+use super::*;
+use crate::components::disabled::InheritedDisabled;
+use crate::components::dismissable_layer::LayerDismissedEvent;
+use crate::components::portal::PortalHierarchy;
+use crate::components::text_field::{TextChangedEvent, TextFieldState};
+use crate::theme::UiTheme;
+
+/// Entries that can be highlighted and chosen.
+type EntryFilter = Or<(With<ComboboxOptionMarker>, With<ComboboxCreateOptionMarker>)>;
+
+/// Opens the list while typing or when the input is clicked.
+pub fn handle_combobox_input(
+    mut text_changes: EventReader<TextChangedEvent>,
+    pressed_inputs: Query<
+        (&Interaction, &ChildOf),
+        (With<ComboboxInputMarker>, Changed<Interaction>),
+    >,
+    inputs: Query<&ChildOf, With<ComboboxInputMarker>>,
+    mut comboboxes: Query<&mut ComboboxState, Without<InheritedDisabled>>,
+) {
+    for event in text_changes.read() {
+        let Ok(child_of) = inputs.get(event.entity) else {
+            continue;
+        };
+        if let Ok(mut state) = comboboxes.get_mut(child_of.parent()) {
+            state.query = event.value.clone();
+            state.open = true;
+            state.highlighted = None;
+        }
+    }
+
+    for (interaction, child_of) in pressed_inputs.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Ok(mut state) = comboboxes.get_mut(child_of.parent()) {
+            if !state.open {
+                state.open = true;
+            }
+        }
+    }
+}
+
+/// Closes the list when its [`DismissableLayer`] was dismissed by Escape, a
+/// click outside or the focus leaving the combobox.
+///
+/// [`DismissableLayer`]: crate::components::dismissable_layer::DismissableLayer
+pub fn handle_combobox_dismiss(
+    mut dismissed: EventReader<LayerDismissedEvent>,
+    contents: Query<(), With<ComboboxContentMarker>>,
+    hierarchy: PortalHierarchy,
+    mut comboboxes: Query<&mut ComboboxState>,
+) {
+    for event in dismissed.read() {
+        if !contents.contains(event.layer) {
+            continue;
+        }
+        if let Some(mut state) = hierarchy
+            .parent(event.layer)
+            .and_then(|root| comboboxes.get_mut(root).ok())
+        {
+            state.open = false;
+        }
+    }
+}
+
+/// Arrow Up/Down move the highlight (opening the list), Enter chooses the
+/// highlighted entry and Escape closes the list.
+pub fn handle_combobox_keyboard(
+    focus: Res<InputFocus>,
+    mut key_events: EventReader<KeyboardInput>,
+    inputs: Query<&ChildOf, With<ComboboxInputMarker>>,
+    mut comboboxes: Query<&mut ComboboxState, Without<InheritedDisabled>>,
+) {
+    let Some(mut state) = focus
+        .get()
+        .and_then(|e| inputs.get(e).ok())
+        .and_then(|child_of| comboboxes.get_mut(child_of.parent()).ok())
+    else {
+        key_events.clear();
+        return;
+    };
+
+    for event in key_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        match &event.logical_key {
+            Key::ArrowDown | Key::ArrowUp if !state.open => state.open = true,
+            Key::ArrowDown => state.move_highlight(1),
+            Key::ArrowUp => state.move_highlight(-1),
+            Key::Enter if state.open => {
+                if let Some(highlighted) = state.highlighted {
+                    state.chosen = Some(highlighted);
+                }
+            }
+            Key::Escape if state.open => state.open = false,
+            _ => {}
+        }
+    }
+}
+
+/// Hovering highlights an entry, clicking chooses it.
+pub fn handle_combobox_option_pointer(
+    entries: Query<(Entity, &Interaction), (EntryFilter, Changed<Interaction>)>,
+    hierarchy: PortalHierarchy,
+    mut comboboxes: Query<&mut ComboboxState, Without<InheritedDisabled>>,
+) {
+    for (entry, interaction) in entries.iter() {
+        if *interaction == Interaction::None {
+            continue;
+        }
+        let Some(root) = hierarchy.ancestors(entry).find(|e| comboboxes.contains(*e)) else {
+            continue;
+        };
+        let Ok(mut state) = comboboxes.get_mut(root) else {
+            continue;
+        };
+        state.highlighted = Some(entry);
+        if *interaction == Interaction::Pressed {
+            state.chosen = Some(entry);
+        }
+    }
+}
+
+/// Applies the entry chosen by keyboard or pointer: creates the option if
+/// needed, stores the value and sends the events.
+pub fn apply_combobox_choice(
+    mut commands: Commands,
+    theme: Option<Res<UiTheme>>,
+    mut comboboxes: Query<(Entity, &mut ComboboxState)>,
+    options: Query<&ComboboxOptionMarker>,
+    creates: Query<(), With<ComboboxCreateOptionMarker>>,
+    contents: Query<(), With<ComboboxContentMarker>>,
+    children: Query<&Children>,
+    hierarchy: PortalHierarchy,
+    fonts: Query<&TextFont>,
+    mut selected_events: EventWriter<ComboboxSelectedEvent>,
+    mut created_events: EventWriter<ComboboxCreatedEvent>,
+) {
+    for (entity, mut state) in comboboxes.iter_mut() {
+        let Some(chosen) = state.chosen else {
+            continue;
+        };
+        state.chosen = None;
+
+        let value = if let Ok(option) = options.get(chosen) {
+            option.value.clone()
+        } else if creates.contains(chosen) {
+            let value = state.query.trim().to_string();
+            let content = hierarchy.children(entity).find(|e| contents.contains(*e));
+            let font = children
+                .iter_descendants(chosen)
+                .find_map(|e| fonts.get(e).ok())
+                .map(|f| f.font.clone());
+            let (Some(theme), Some(content), Some(font), false) =
+                (theme.as_deref(), content, font, value.is_empty())
+            else {
+                continue;
+            };
+            let mut option = Entity::PLACEHOLDER;
+            commands.entity(content).with_children(|cc| {
+                option = spawn_combobox_option(cc, theme, &font, value.clone(), value.clone());
+            });
+            state.options.push(option);
+            created_events.write(ComboboxCreatedEvent {
+                combobox: entity,
+                value: value.clone(),
+            });
+            value
+        } else {
+            continue;
+        };
+
+        state.selected = Some(value);
+        state.open = false;
+        state.highlighted = None;
+        selected_events.write(ComboboxSelectedEvent {
+            combobox: entity,
+            selected: state.selected.clone(),
+        });
+    }
+}
+
+/// Filters and sorts the options whenever the state changes and shows the
+/// chosen label in the input once the list closes.
+pub fn update_combobox_options(
+    mut comboboxes: Query<(Entity, &mut ComboboxState)>,
+    mut inputs: Query<&mut TextFieldState, With<ComboboxInputMarker>>,
+    options: Query<&ComboboxOptionMarker>,
+    creates: Query<(), With<ComboboxCreateOptionMarker>>,
+    empties: Query<(), With<ComboboxEmptyMarker>>,
+    contents: Query<(), With<ComboboxContentMarker>>,
+    mut nodes: Query<
+        &mut Node,
+        Or<(
+            EntryFilter,
+            With<ComboboxEmptyMarker>,
+            With<ComboboxContentMarker>,
+        )>,
+    >,
+    children: Query<&Children>,
+    hierarchy: PortalHierarchy,
+    mut texts: Query<&mut Text>,
+    mut commands: Commands,
+) {
+    let mut set_display = |entity: Entity, visible: bool| {
+        let display = if visible {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        if let Ok(mut node) = nodes.get_mut(entity) {
+            if node.display != display {
+                node.display = display;
+            }
+        }
+    };
+
+    for (entity, mut state) in comboboxes.iter_mut() {
+        if !state.is_changed() {
+            continue;
+        }
+        let root_children: Vec<Entity> = hierarchy.children(entity).collect();
+        // Abgeleitete Felder ändern, ohne das nächste Update auszulösen
+        let state = state.bypass_change_detection();
+        let Some(content) = root_children
+            .iter()
+            .copied()
+            .find(|e| contents.contains(*e))
+        else {
+            continue;
+        };
+        set_display(content, state.open);
+
+        if !state.open {
+            state.query.clear();
+            state.matches.clear();
+            state.highlighted = None;
+            let label = state
+                .selected
+                .as_ref()
+                .and_then(|value| {
+                    state
+                        .options
+                        .iter()
+                        .filter_map(|e| options.get(*e).ok())
+                        .find(|o| &o.value == value)
+                })
+                .map(|o| o.label.clone())
+                .unwrap_or_default();
+            for input in root_children.iter().copied() {
+                if let Ok(mut input) = inputs.get_mut(input) {
+                    if input.value != label {
+                        input.set_value(label.clone());
+                    }
+                }
+            }
+            continue;
+        }
+
+        // 1) Treffer nach Rang, bei Gleichstand in ursprünglicher Reihenfolge
+        let mut ranked = Vec::new();
+        let mut rest = Vec::new();
+        for (index, entity) in state.options.iter().enumerate() {
+            let Ok(option) = options.get(*entity) else {
+                continue;
+            };
+            match match_score(state.filter, &state.query, &option.label) {
+                Some(score) => ranked.push((score, index, *entity)),
+                None => rest.push(*entity),
+            }
+        }
+        ranked.sort();
+        let mut matches: Vec<Entity> = ranked.iter().map(|(_, _, e)| *e).collect();
+        for entity in state.options.iter() {
+            set_display(*entity, matches.contains(entity));
+        }
+
+        // 2) Eintrag zum Anlegen, wenn kein Label genau passt
+        let query = state.query.trim();
+        let exact = state
+            .options
+            .iter()
+            .filter_map(|e| options.get(*e).ok())
+            .any(|o| o.label.to_lowercase() == query.to_lowercase());
+        let content_children: Vec<Entity> = children
+            .get(content)
+            .map(|c| c.iter().collect())
+            .unwrap_or_default();
+        let create = content_children
+            .iter()
+            .copied()
+            .find(|e| creates.contains(*e));
+        if let Some(create) = create {
+            let visible = state.allow_create && !query.is_empty() && !exact;
+            set_display(create, visible);
+            if visible {
+                matches.push(create);
+                let label = format!("Create \"{query}\"");
+                if let Some(mut text) = children
+                    .iter_descendants(create)
+                    .find(|e| texts.contains(*e))
+                    .and_then(|e| texts.get_mut(e).ok())
+                {
+                    if text.0 != label {
+                        text.0 = label;
+                    }
+                }
+            }
+        }
+        let empty = content_children
+            .iter()
+            .copied()
+            .find(|e| empties.contains(*e));
+        if let Some(empty) = empty {
+            set_display(empty, matches.is_empty());
+        }
+
+        // 3) Reihenfolge der Kinder an die Trefferliste anpassen
+        let mut order: Vec<Entity> = ranked.iter().map(|(_, _, e)| *e).collect();
+        order.extend(rest);
+        order.extend(create);
+        order.extend(empty);
+        if order != content_children {
+            commands.entity(content).replace_children(&order);
+        }
+
+        // 4) Hervorhebung: bisheriger Eintrag, sonst der gewählte, sonst der erste
+        if !state.highlighted.is_some_and(|h| matches.contains(&h)) {
+            let selected = matches.iter().copied().find(|e| {
+                options
+                    .get(*e)
+                    .is_ok_and(|o| state.selected.as_ref() == Some(&o.value))
+            });
+            state.highlighted = selected.or(matches.first().copied());
+        }
+        state.matches = matches;
+    }
+}
+
+/// Colors highlighted and selected entries.
+pub fn update_combobox_visuals(
+    theme: Option<Res<UiTheme>>,
+    comboboxes: Query<&ComboboxState>,
+    mut entries: Query<(Entity, &mut BackgroundColor, Option<&ComboboxOptionMarker>), EntryFilter>,
+    hierarchy: PortalHierarchy,
+) {
+    let Some(theme) = theme else {
+        return;
+    };
+    for (entity, mut background, option) in entries.iter_mut() {
+        let Some(state) = hierarchy
+            .ancestors(entity)
+            .find_map(|e| comboboxes.get(e).ok())
+        else {
+            continue;
+        };
+        let highlighted = state.open && state.highlighted == Some(entity);
+        let selected = option.is_some_and(|o| state.selected.as_ref() == Some(&o.value));
+        let wanted = ComboboxOptionStyle::background(&theme, highlighted, selected);
+        if background.0 != wanted.0 {
+            *background = wanted;
+        }
+    }
+}
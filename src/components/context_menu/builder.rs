@@ -1,30 +1,115 @@
 use bevy::prelude::*;
 
-use crate::components::helper::UiBuilder;
-use crate::components::label::LabelBuilder;
+use crate::components::base_menu::{
+    spawn_menu_items, BaseMenuContentMarker, MenuContentState, MenuItems,
+};
+use crate::components::dismissable_layer::DismissableLayer;
+use crate::components::floating::{Floating, FloatingAlign};
+use crate::components::helper::{NoAction, UiBuilder};
+use crate::components::layer::{Layer, UiLayer};
+use crate::components::portal::Portal;
 use crate::theme::UiTheme;
 
-use super::{ContextMenuItemMarker, ContextMenuItemStyle, ContextMenuMarker, ContextMenuStyle};
+use super::{ContextMenuMarker, ContextMenuState, ContextMenuStyle, ContextMenuTrigger};
 
-/// Builder for a simple context menu.
-pub struct ContextMenuBuilder {
-    items: Vec<String>,
+/// Builder for a context menu.
+///
+/// The menu stays hidden until it is opened by right-click or long-press on
+/// one of its triggers, and then appears at the pointer. Choosing an item
+/// sends a [`ContextMenuItemSelected<A>`](super::ContextMenuItemSelected).
+///
+/// ```ignore
+/// let canvas = parent.spawn((Node::default(), Interaction::default())).id();
+/// ContextMenuBuilder::<EditAction>::new()
+///     .item("Copy").action(EditAction::Copy)
+///     .item("Paste").action(EditAction::Paste).disabled()
+///     .separator()
+///     .checkbox("Snap to grid", true).action(EditAction::Snap)
+///     .trigger(canvas)
+///     .spawn(parent, &theme, &font);
+/// ```
+pub struct ContextMenuBuilder<A: Component + Clone = NoAction> {
+    items: MenuItems<A>,
+    triggers: Vec<Entity>,
 }
 
-impl ContextMenuBuilder {
+impl<A: Component + Clone> ContextMenuBuilder<A> {
     /// Create a new empty context menu.
     pub fn new() -> Self {
-        Self { items: Vec::new() }
+        Self {
+            items: MenuItems::new(),
+            triggers: Vec::new(),
+        }
+    }
+
+    /// Replaces all entries of the menu.
+    pub fn items(mut self, items: MenuItems<A>) -> Self {
+        self.items = items;
+        self
     }
 
     /// Add a text item to the context menu.
     pub fn item(mut self, text: impl Into<String>) -> Self {
-        self.items.push(text.into());
+        self.items = self.items.item(text);
+        self
+    }
+
+    /// Add an item with a check mark that toggles when chosen.
+    pub fn checkbox(mut self, text: impl Into<String>, checked: bool) -> Self {
+        self.items = self.items.checkbox(text, checked);
+        self
+    }
+
+    /// Add an item of the radio group `group`.
+    pub fn radio(
+        mut self,
+        group: impl Into<String>,
+        text: impl Into<String>,
+        checked: bool,
+    ) -> Self {
+        self.items = self.items.radio(group, text, checked);
+        self
+    }
+
+    /// Add an item that opens a nested menu.
+    pub fn submenu(mut self, text: impl Into<String>, items: MenuItems<A>) -> Self {
+        self.items = self.items.submenu(text, items);
+        self
+    }
+
+    pub fn separator(mut self) -> Self {
+        self.items = self.items.separator();
+        self
+    }
+
+    /// Add a non-interactive group heading.
+    pub fn label(mut self, text: impl Into<String>) -> Self {
+        self.items = self.items.label(text);
+        self
+    }
+
+    /// Action of the item added last.
+    pub fn action(mut self, action: A) -> Self {
+        self.items = self.items.action(action);
+        self
+    }
+
+    /// Disables the item added last.
+    pub fn disabled(mut self) -> Self {
+        self.items = self.items.disabled();
+        self
+    }
+
+    /// Opens the menu on right-click or long-press on `entity`. The entity
+    /// needs an [`Interaction`]. [`ContextMenuTrigger`] can also be inserted
+    /// later with the returned menu entity.
+    pub fn trigger(mut self, entity: Entity) -> Self {
+        self.triggers.push(entity);
         self
     }
 }
 
-impl<'w, 's> UiBuilder<'w, 's> for ContextMenuBuilder {
+impl<'w, 's, A: Component + Clone> UiBuilder<'w, 's> for ContextMenuBuilder<A> {
     type Output = Entity;
 
     fn spawn(
@@ -33,17 +118,32 @@ impl<'w, 's> UiBuilder<'w, 's> for ContextMenuBuilder {
         theme: &UiTheme,
         font: &Handle<Font>,
     ) -> Self::Output {
-        let style = ContextMenuStyle::new(theme);
-        let mut cmd = parent.spawn((ContextMenuMarker, style));
-        cmd.with_children(|cb| {
-            for item in self.items {
-                let item_style = ContextMenuItemStyle::new(theme);
-                let mut item_cmd = cb.spawn((ContextMenuItemMarker, item_style));
-                item_cmd.with_children(|icb| {
-                    let _ = LabelBuilder::new(item).spawn(icb, theme, font);
-                });
-            }
-        });
-        cmd.id()
+        // Anker und Punkt werden beim Öffnen auf Auslöser und Zeiger gesetzt
+        let anchor = parent.target_entity();
+        let mut cmd = parent.spawn((
+            ContextMenuMarker,
+            ContextMenuState::default(),
+            BaseMenuContentMarker,
+            MenuContentState::new(false),
+            ContextMenuStyle::new(theme),
+            Floating::new(anchor)
+                .align(FloatingAlign::Start)
+                .side_offset(0.0),
+            Portal::global(),
+            Layer(UiLayer::Dropdown),
+            DismissableLayer::new(),
+            Visibility::Hidden,
+            Name::new("ContextMenu"),
+        ));
+        cmd.with_children(|cb| spawn_menu_items(cb, self.items, theme, font));
+        let menu = cmd.id();
+
+        for trigger in self.triggers {
+            parent
+                .commands()
+                .entity(trigger)
+                .insert(ContextMenuTrigger::new(menu));
+        }
+        menu
     }
 }
@@ -4,6 +4,22 @@ use bevy::prelude::*;
 #[derive(Component, Debug, Clone, Copy, Default)]
 pub struct ContextMenuMarker;
 
-/// Marker for individual context menu items.
+/// Entity the context menu was last opened on.
 #[derive(Component, Debug, Clone, Copy, Default)]
-pub struct ContextMenuItemMarker;
+pub struct ContextMenuState {
+    pub trigger: Option<Entity>,
+}
+
+/// Opens the context menu `menu` at the pointer on right-click or
+/// long-press. Any entity with an [`Interaction`] can be a trigger, and
+/// several triggers can share one menu.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ContextMenuTrigger {
+    pub menu: Entity,
+}
+
+impl ContextMenuTrigger {
+    pub fn new(menu: Entity) -> Self {
+        Self { menu }
+    }
+}
@@ -0,0 +1,35 @@
+use bevy::prelude::{Component, Entity, Event};
+use std::fmt::Debug;
+
+/// Sent when an item of a context menu is chosen.
+///
+/// Like [`ButtonClickedEvent`](crate::components::button::ButtonClickedEvent)
+/// it is generic over the action type `A` set with
+/// [`ContextMenuBuilder::action`](super::ContextMenuBuilder::action); each
+/// type needs its own [`ContextMenuActionPlugin`](super::ContextMenuActionPlugin).
+#[derive(Event, Clone)]
+pub struct ContextMenuItemSelected<A: Component + Clone + Send + Sync> {
+    /// The chosen item.
+    pub source_entity: Entity,
+    /// The context menu the item belongs to.
+    pub menu: Entity,
+    /// The entity the menu was opened on.
+    pub trigger: Option<Entity>,
+    /// Clone of the item's action, `None` if it has none.
+    pub action_id: Option<A>,
+    /// New checked state of checkbox and radio items.
+    pub checked: Option<bool>,
+}
+
+// Wie beim ButtonClickedEvent: A muss kein Debug implementieren
+impl<A: Component + Clone + Send + Sync> Debug for ContextMenuItemSelected<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ContextMenuItemSelected")
+            .field("source_entity", &self.source_entity)
+            .field("menu", &self.menu)
+            .field("trigger", &self.trigger)
+            .field("action_id", &self.action_id.as_ref().map(|_| "Some<A>"))
+            .field("checked", &self.checked)
+            .finish()
+    }
+}
@@ -1,7 +1,12 @@
 mod builder;
 mod components;
+mod events;
+mod plugin;
 mod style;
+mod systems;
 
 pub use builder::ContextMenuBuilder;
-pub use components::{ContextMenuMarker, ContextMenuItemMarker};
-pub use style::{ContextMenuStyle, ContextMenuItemStyle};
+pub use components::{ContextMenuMarker, ContextMenuState, ContextMenuTrigger};
+pub use events::ContextMenuItemSelected;
+pub use plugin::{ContextMenuActionPlugin, ContextMenuPlugin};
+pub use style::ContextMenuStyle;
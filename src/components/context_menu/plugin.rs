@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+use std::marker::PhantomData;
+
+use super::events::ContextMenuItemSelected;
+use super::systems::{handle_context_menu_triggers, send_context_menu_selection};
+use crate::components::base_menu::{apply_menu_activation, handle_menu_dismiss, sync_menu_panels};
+use crate::components::helper::NoAction;
+use crate::plugin::UiState;
+
+/// Opens context menus from their triggers. The menu items themselves are
+/// driven by the [`BaseMenuPlugin`](crate::components::base_menu::BaseMenuPlugin).
+pub struct ContextMenuPlugin;
+
+impl Plugin for ContextMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            handle_context_menu_triggers
+                .after(handle_menu_dismiss)
+                .before(sync_menu_panels)
+                .run_if(in_state(UiState::Ready)),
+        );
+    }
+}
+
+/// Registers [`ContextMenuItemSelected<A>`] for the action type `A`.
+/// Add one per action type used with
+/// [`ContextMenuBuilder`](super::ContextMenuBuilder).
+pub struct ContextMenuActionPlugin<A: Component + Clone + Send + Sync = NoAction>(PhantomData<A>);
+
+impl<A: Component + Clone + Send + Sync> Default for ContextMenuActionPlugin<A> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A: Component + Clone + Send + Sync> Plugin for ContextMenuActionPlugin<A> {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ContextMenuItemSelected<A>>().add_systems(
+            Update,
+            send_context_menu_selection::<A>
+                .after(apply_menu_activation)
+                .run_if(in_state(UiState::Ready)),
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
 
 use crate::theme::UiTheme;
 
@@ -9,6 +10,7 @@ pub struct ContextMenuStyle {
     pub background_color: BackgroundColor,
     pub border_color: BorderColor,
     pub border_radius: BorderRadius,
+    pub focus_policy: FocusPolicy,
 }
 
 impl ContextMenuStyle {
@@ -17,34 +19,15 @@ impl ContextMenuStyle {
             node: Node {
                 display: Display::Flex,
                 flex_direction: FlexDirection::Column,
+                min_width: Val::Px(180.0),
+                padding: UiRect::all(Val::Px(theme.layout.padding.xs)),
+                border: UiRect::all(Val::Px(1.0)),
                 ..default()
             },
             background_color: BackgroundColor(theme.color.gray.step02),
             border_color: BorderColor(theme.color.gray.step06),
             border_radius: BorderRadius::all(Val::Px(theme.layout.radius.sm)),
-        }
-    }
-}
-
-/// Style bundle for menu items.
-#[derive(Bundle, Clone, Debug)]
-pub struct ContextMenuItemStyle {
-    pub node: Node,
-    pub background_color: BackgroundColor,
-    pub border_radius: BorderRadius,
-}
-
-impl ContextMenuItemStyle {
-    pub fn new(theme: &UiTheme) -> Self {
-        ContextMenuItemStyle {
-            node: Node {
-                display: Display::Flex,
-                align_items: AlignItems::Center,
-                padding: UiRect::all(Val::Px(theme.layout.padding.xs)),
-                ..default()
-            },
-            background_color: BackgroundColor(theme.color.gray.step03),
-            border_radius: BorderRadius::all(Val::Px(theme.layout.radius.xs)),
+            focus_policy: FocusPolicy::Block,
         }
     }
 }
@@ -0,0 +1,122 @@
+use bevy::input::touch::Touches;
+use bevy::input_focus::InputFocus;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use super::*;
+use crate::components::base_menu::{MenuContentState, MenuItem, MenuItemActivatedEvent};
+use crate::components::disabled::InheritedDisabled;
+use crate::components::floating::Floating;
+
+/// Hold time after which a press opens the menu, in seconds.
+const LONG_PRESS_DURATION: f32 = 0.5;
+/// Pointer movement in logical pixels that cancels a long-press.
+const LONG_PRESS_TOLERANCE: f32 = 10.0;
+
+/// Press on a trigger that may become a long-press.
+pub struct LongPress {
+    trigger: Entity,
+    origin: Vec2,
+    elapsed: f32,
+}
+
+/// Opens the menu of a [`ContextMenuTrigger`] on right-click or long-press,
+/// anchored at the pointer. The floating placement keeps it inside the
+/// window.
+pub fn handle_context_menu_triggers(
+    time: Res<Time>,
+    focus: Res<InputFocus>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    triggers: Query<(Entity, &Interaction, &ContextMenuTrigger), Without<InheritedDisabled>>,
+    mut menus: Query<
+        (&mut MenuContentState, &mut Floating, &mut ContextMenuState),
+        With<ContextMenuMarker>,
+    >,
+    mut long_press: Local<Option<LongPress>>,
+) {
+    let pointer = windows
+        .single()
+        .ok()
+        .and_then(|w| w.cursor_position())
+        .or_else(|| touches.iter().next().map(|t| t.position()));
+    let Some(pointer) = pointer else {
+        *long_press = None;
+        return;
+    };
+
+    let mut opened = None;
+    if mouse.just_pressed(MouseButton::Right) {
+        opened = triggers
+            .iter()
+            .find(|(_, interaction, _)| **interaction != Interaction::None)
+            .map(|(entity, _, trigger)| (entity, trigger.menu));
+    }
+
+    // Langes Drücken mit Maus oder Finger
+    if mouse.just_pressed(MouseButton::Left) || touches.any_just_pressed() {
+        *long_press = triggers
+            .iter()
+            .find(|(_, interaction, _)| **interaction == Interaction::Pressed)
+            .map(|(entity, ..)| LongPress {
+                trigger: entity,
+                origin: pointer,
+                elapsed: 0.0,
+            });
+    } else if !(mouse.pressed(MouseButton::Left) || touches.iter().next().is_some()) {
+        *long_press = None;
+    }
+    if let Some(press) = long_press.as_mut() {
+        press.elapsed += time.delta_secs();
+        if pointer.distance(press.origin) > LONG_PRESS_TOLERANCE {
+            *long_press = None;
+        } else if press.elapsed >= LONG_PRESS_DURATION {
+            opened = triggers
+                .get(press.trigger)
+                .ok()
+                .map(|(entity, _, trigger)| (entity, trigger.menu));
+            *long_press = None;
+        }
+    }
+
+    let Some((trigger, menu)) = opened else {
+        return;
+    };
+    let Ok((mut state, mut floating, mut context)) = menus.get_mut(menu) else {
+        return;
+    };
+    if !state.open {
+        state.return_focus = focus.get();
+    }
+    state.open = true;
+    state.highlighted = None;
+    floating.anchor = trigger;
+    floating.point = Some(pointer);
+    context.trigger = Some(trigger);
+}
+
+/// Turns chosen items of context menus into typed
+/// [`ContextMenuItemSelected<A>`] events.
+pub fn send_context_menu_selection<A: Component + Clone + Send + Sync>(
+    mut activated: EventReader<MenuItemActivatedEvent>,
+    menus: Query<&ContextMenuState, With<ContextMenuMarker>>,
+    items: Query<(&MenuItem, Option<&A>)>,
+    mut selected: EventWriter<ContextMenuItemSelected<A>>,
+) {
+    for event in activated.read() {
+        let Ok(context) = menus.get(event.menu) else {
+            continue;
+        };
+        let Ok((item, action)) = items.get(event.item) else {
+            continue;
+        };
+        selected.write(ContextMenuItemSelected {
+            source_entity: event.item,
+            menu: event.menu,
+            trigger: context.trigger,
+            action_id: action.cloned(),
+            checked: item.checked(),
+        });
+    }
+}
@@ -1,24 +1,33 @@
 // src/components/dialog/builder/builder.rs
+use bevy::input_focus::tab_navigation::TabGroup;
 use bevy::prelude::*;
 use bevy::ui::FocusPolicy;
 
 use super::super::*;
 use super::*;
+use crate::components::dismissable_layer::DismissableLayer;
+use crate::components::layer::{Layer, UiLayer};
 use crate::components::portal::*;
+use crate::components::shortcut::ShortcutScope;
+use crate::components::transition::UiTransition;
 use crate::theme::UiTheme;
 
 /// Builder für einen gesamten Dialog (Overlay + Content).
 /// Alle konkreten Abschnitts‑Details (Header/Body/Footer) werden über
 /// `DialogContentBuilder` injiziert.
+///
+/// Wird ein Dialog aus einem anderen heraus geöffnet, liegt er mit eigenem
+/// Overlay darüber; Escape schließt dann nur den obersten.
 pub struct DialogBuilder {
     // Identität
     id: DialogId,
     // Anfangszustand
     initially_open: bool,
+    modal: bool,
 
     // Wo soll der Dialog angehängt werden?
     target_container: Option<Entity>,
-    use_global_portal: bool,
+    portal: Option<Portal>,
 
     // --- Inhalt ---
     content_builder: Option<DialogContentBuilder>,
@@ -27,7 +36,6 @@ pub struct DialogBuilder {
     width: Option<Val>,
     height: Option<Val>,
     overlay_color: Option<Color>,
-    z_index_offset: i32,
 
     // --- Komfortoptionen ---
     show_default_close_button: bool,
@@ -41,13 +49,13 @@ impl DialogBuilder {
         Self {
             id,
             initially_open: false,
+            modal: true,
             target_container: None,
-            use_global_portal: true,
+            portal: Some(Portal::global()),
             content_builder: None,
             width: Some(Val::Px(500.0)),
             height: None,
             overlay_color: None,
-            z_index_offset: 0,
             show_default_close_button: true,
         }
     }
@@ -64,6 +72,14 @@ impl DialogBuilder {
         self
     }
 
+    /// Modale Dialoge (Standard) legen ein Overlay über alles darunter und
+    /// halten den Fokus. Nicht-modale Dialoge lassen die Oberfläche dahinter
+    /// bedienbar.
+    pub fn modal(mut self, flag: bool) -> Self {
+        self.modal = flag;
+        self
+    }
+
     pub fn content(mut self, builder: DialogContentBuilder) -> Self {
         self.content_builder = Some(builder);
         self
@@ -84,8 +100,10 @@ impl DialogBuilder {
         self
     }
 
-    pub fn z_index_offset(mut self, offset: i32) -> Self {
-        self.z_index_offset = offset;
+    /// Ohne Wirkung: die Stapelreihenfolge vergibt jetzt der `LayerStack`,
+    /// ein später geöffneter Dialog liegt immer über den früheren.
+    #[deprecated(note = "dialogs are stacked in opening order by the LayerStack; remove the call")]
+    pub fn z_index_offset(self, _offset: i32) -> Self {
         self
     }
 
@@ -94,6 +112,20 @@ impl DialogBuilder {
         self
     }
 
+    /// Rendert den Dialog über das angegebene Portal (Standard: globales Root).
+    pub fn portal(mut self, portal: Portal) -> Self {
+        self.portal = Some(portal);
+        self.target_container = None;
+        self
+    }
+
+    /// Hängt den Dialog direkt an `container` statt an ein Portal.
+    pub fn target_container(mut self, container: Entity) -> Self {
+        self.target_container = Some(container);
+        self.portal = None;
+        self
+    }
+
     // ---------------------------------------------------------------------
     // SPAWN – Erzeugt alle Entities des Dialogs
     // ---------------------------------------------------------------------
@@ -103,7 +135,6 @@ impl DialogBuilder {
         commands: &mut Commands,
         theme: &UiTheme,
         font_handle: &Handle<Font>,
-        global_portal_root: Option<Res<ForgeUiPortalRoot>>, // kann None sein
     ) -> Entity {
         // ---------- Farben & Sichtbarkeit ----------
         let overlay_color = self.overlay_color.unwrap_or(theme.color.black.step07);
@@ -115,10 +146,14 @@ impl DialogBuilder {
         };
 
         // ---------- Root‑Entity ----------
+        // Die Stapelreihenfolge (GlobalZIndex) vergibt der LayerStack beim Öffnen
         let root_id = commands
             .spawn((
                 DialogRootMarker,
-                DialogConfig { id: self.id },
+                DialogConfig {
+                    id: self.id,
+                    modal: self.modal,
+                },
                 Node {
                     position_type: PositionType::Absolute,
                     left: Val::Px(0.0),
@@ -131,19 +166,29 @@ impl DialogBuilder {
                     ..default()
                 },
                 visibility,
-                ZIndex(theme.layout.z_index.modal_base + self.z_index_offset),
                 DialogState {
                     open: self.initially_open,
+                    ..default()
                 },
                 Interaction::None,
+                UiTransition::default(),
+                Layer(UiLayer::Modal),
             ))
             .id();
 
+        if self.modal {
+            // Solange der Dialog sichtbar ist, greifen nur seine eigenen Shortcuts
+            // und Tab bleibt innerhalb des Dialogs
+            commands
+                .entity(root_id)
+                .insert((ShortcutScope::modal(), TabGroup::modal()));
+        } else {
+            commands.entity(root_id).insert(ShortcutScope::default());
+        }
+
         // ---------- Optionale Parent‑Zuweisung ----------
-        if self.use_global_portal {
-            if let Some(root) = global_portal_root {
-                commands.entity(root_id).insert(ChildOf(root.0));
-            }
+        if let Some(portal) = self.portal {
+            commands.entity(root_id).insert(portal);
         } else if let Some(container) = self.target_container {
             commands.entity(root_id).insert(ChildOf(container));
         }
@@ -151,20 +196,23 @@ impl DialogBuilder {
         // -----------------------------------------------------------------
         // Kinder anlegen
         // -----------------------------------------------------------------
+        let mut content_id = root_id;
         commands.entity(root_id).with_children(|root| {
-            // 1) Overlay
-            root.spawn((
-                DialogOverlay,
-                Node {
-                    position_type: PositionType::Absolute,
-                    width: Val::Percent(100.0),
-                    height: Val::Percent(100.0),
-                    ..default()
-                },
-                BackgroundColor(overlay_color.into()),
-                FocusPolicy::Block,
-                Interaction::default(),
-            ));
+            // 1) Overlay (nur modal, nicht-modale Dialoge lassen Klicks durch)
+            if self.modal {
+                root.spawn((
+                    DialogOverlay,
+                    Node {
+                        position_type: PositionType::Absolute,
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(overlay_color.into()),
+                    FocusPolicy::Block,
+                    UiTransition::default().fade().driven_by(root_id),
+                ));
+            }
 
             // 2) Content‑Wrapper
             let wrapper_style = Node {
@@ -181,20 +229,35 @@ impl DialogBuilder {
                 ..default()
             };
 
-            root.spawn((DialogContentBundle {
-                marker: DialogContent,
-                node: wrapper_style,
-                background_color: bg_color.into(),
-                border_radius: BorderRadius::all(Val::Px(theme.layout.radius.base)),
-            },))
+            content_id = root
+                .spawn((
+                    DialogContentBundle {
+                        marker: DialogContent,
+                        node: wrapper_style,
+                        background_color: bg_color.into(),
+                        border_radius: BorderRadius::all(Val::Px(theme.layout.radius.base)),
+                    },
+                    FocusPolicy::Block,
+                    UiTransition::default().scale(0.95).driven_by(root_id),
+                ))
                 .with_children(|content_parent| {
                     // ---------- Header / Body / Footer per Content‑Builder ----------
                     if let Some(content) = self.content_builder {
                         content.spawn_into(content_parent, theme, font_handle);
                     }
-                });
+                })
+                .id();
         });
 
+        // Escape und (nur modal) Klicks neben den Inhalt schließen den Dialog;
+        // den Fokus hält der Dialog-Stapel selbst
+        commands.entity(root_id).insert(
+            DismissableLayer::new()
+                .hit_area(content_id)
+                .pointer_outside(self.modal)
+                .focus_outside(false),
+        );
+
         root_id
     }
 }
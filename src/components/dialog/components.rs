@@ -1,11 +1,58 @@
 // src/components/dialog/components.rs
-use bevy::platform::collections::HashSet; // Korrekter Import für HashSet
 use bevy::prelude::*;
 use uuid::Uuid; // Import Uuid
 
+/// Offene Dialoge in Öffnungsreihenfolge, der oberste zuletzt.
+///
+/// Ersetzt das frühere Feld `modals: HashSet<Entity>`: die offenen modalen
+/// Dialoge liefert jetzt [`ActiveDialogs::modals`], alle offenen Dialoge
+/// [`ActiveDialogs::iter`].
 #[derive(Resource, Default, Debug)]
 pub struct ActiveDialogs {
-    pub modals: HashSet<Entity>, // Entity-IDs der aktuell aktiven modalen Dialoge
+    stack: Vec<(Entity, bool)>,
+}
+
+impl ActiveDialogs {
+    /// Open dialogs from bottom to top.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Entity> + '_ {
+        self.stack.iter().map(|(e, _)| *e)
+    }
+
+    /// Open modal dialogs from bottom to top.
+    pub fn modals(&self) -> impl DoubleEndedIterator<Item = Entity> + '_ {
+        self.stack
+            .iter()
+            .filter(|(_, modal)| *modal)
+            .map(|(e, _)| *e)
+    }
+
+    /// The dialog opened last.
+    pub fn topmost(&self) -> Option<Entity> {
+        self.iter().next_back()
+    }
+
+    /// The modal dialog opened last. Everything below it is inert.
+    pub fn topmost_modal(&self) -> Option<Entity> {
+        self.modals().next_back()
+    }
+
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.iter().any(|e| e == entity)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    /// Legt `entity` oben auf den Stapel.
+    pub(crate) fn push(&mut self, entity: Entity, modal: bool) {
+        self.stack.retain(|(e, _)| *e != entity);
+        self.stack.push((entity, modal));
+    }
+
+    pub(crate) fn retain(&mut self, mut keep: impl FnMut(&Entity) -> bool) {
+        self.stack.retain(|(e, _)| keep(e));
+    }
 }
 
 // --- Komponenten ---
@@ -26,6 +73,8 @@ pub struct DialogRootMarker;
 #[derive(Component, Debug, Clone)]
 pub struct DialogConfig {
     pub id: DialogId,
+    /// Modale Dialoge haben ein Overlay und machen alles darunter inaktiv.
+    pub modal: bool,
 }
 
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -46,8 +95,18 @@ impl Default for DialogId {
 #[derive(Component, Debug, Default)]
 pub struct DialogState {
     pub open: bool,
+    /// Fokus vor dem Öffnen, wird beim Schließen wiederhergestellt.
+    pub(crate) return_focus: Option<Entity>,
 }
 
+/// Marks an open dialog that lies below the topmost modal dialog. It is
+/// inserted together with
+/// [`Inert`](crate::components::disabled::Inert), so its content leaves the tab order,
+/// ignores clicks and its shortcut scopes stay inactive until the dialogs
+/// above it are closed.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct DialogInert;
+
 #[derive(Component, Default, Debug, Clone, Copy)]
 pub struct DialogOverlay;
 
@@ -8,11 +8,12 @@ pub struct OpenDialogEvent(pub DialogId);
 #[derive(Event, Debug, Clone)]
 pub struct CloseDialogEvent {
     pub id_to_close: Option<DialogId>, // Optional: Gezieltes Schließen
-                                       // Wenn None, wird oft der oberste modale Dialog geschlossen
+                                       // Wenn None, wird nur der oberste Dialog geschlossen
 }
 
 impl CloseDialogEvent {
-    /// Erstellt ein Event zum Schließen des obersten/aktuellen modalen Dialogs.
+    /// Erstellt ein Event zum Schließen des obersten Dialogs; darunter
+    /// liegende Dialoge bleiben offen.
     pub fn current_modal() -> Self {
         Self { id_to_close: None }
     }
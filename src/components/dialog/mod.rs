@@ -16,7 +16,7 @@ pub use builder::DialogHeaderBuilder;
 pub use builder::DialogTriggerBuilder;
 pub use plugin::*; // Öffentlich machen // Öffentlich machen
 
-pub use components::{DialogAction, DialogId};
+pub use components::{ActiveDialogs, DialogAction, DialogId, DialogInert};
 pub use events::{CloseDialogEvent, OpenDialogEvent};
 
 // Interne Nutzung für das dialog Modul
@@ -21,14 +21,15 @@ impl Plugin for DialogPlugin {
             .add_systems(
                 Update,
                 (
-                    open_dialog_system,
-                    handle_overlay_click_system,
                     handle_button_release::<DialogAction>,
                     handle_dialog_action_buttons,
+                    open_dialog_system,
                     close_dialog_system
                         .run_if(in_state(UiState::Ready))
-                        .run_if(|active: Res<ActiveDialogs>| !active.modals.is_empty()),
+                        .run_if(|active: Res<ActiveDialogs>| !active.is_empty()),
+                    sync_dialog_stack,
                 )
+                    .chain()
                     .run_if(in_state(UiState::Ready)), // Annahme: Systeme sollen nur im UiState::Ready laufen
             );
     }
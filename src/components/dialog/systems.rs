@@ -1,29 +1,57 @@
 // components/dialog/systems.rs
+use bevy::input_focus::InputFocus;
 use bevy::platform::collections::HashSet;
 use bevy::prelude::*;
 
 use super::*;
 use crate::components::button::*;
+use crate::components::disabled::Inert;
+use crate::components::dismissable_layer::LayerDismissedEvent;
+use crate::components::portal::PortalHierarchy;
+
+/// Fokusziel eines Dialogs: sein Inhalt, damit Tab innerhalb der `TabGroup`
+/// des Dialogs bleibt.
+fn focus_target(
+    root: Entity,
+    children: &Query<&Children>,
+    q_content: &Query<(), With<DialogContent>>,
+) -> Entity {
+    children
+        .get(root)
+        .ok()
+        .and_then(|c| c.iter().find(|e| q_content.contains(*e)))
+        .unwrap_or(root)
+}
 
 /// System zum Öffnen eines Dialogs über Event.
+///
+/// Der Dialog kommt oben auf [`ActiveDialogs`] und erhält den Fokus; der
+/// vorherige Fokus wird beim Schließen wiederhergestellt.
 pub fn open_dialog_system(
-    // mut commands: Commands, // Nicht mehr für Animationen, aber bleibt für ECS-Operationen
     mut ev_open: EventReader<OpenDialogEvent>,
-    mut q_dialogs: Query<(
-        Entity,
-        &DialogConfig,
-        &mut DialogState,
-        &mut Visibility, // Direktes Ändern der Sichtbarkeit
-    )>, // Keine Children, KeepMounted oder q_animatable_content mehr für die Kernlogik nötig
-    mut active_modals: ResMut<ActiveDialogs>,
+    mut focus: ResMut<InputFocus>,
+    mut q_dialogs: Query<
+        (
+            Entity,
+            &DialogConfig,
+            &mut DialogState,
+            &mut Visibility, // Direktes Ändern der Sichtbarkeit
+        ),
+        With<DialogRootMarker>,
+    >,
+    children: Query<&Children>,
+    q_content: Query<(), With<DialogContent>>,
+    mut active_dialogs: ResMut<ActiveDialogs>,
 ) {
     for OpenDialogEvent(id_to_open) in ev_open.read() {
         for (entity, config, mut state, mut vis) in q_dialogs.iter_mut() {
             if config.id == *id_to_open && !state.open {
                 *vis = Visibility::Inherited; // Dialog sichtbar machen
                 state.open = true;
+                state.return_focus = focus.get();
+                focus.set(focus_target(entity, &children, &q_content));
                 info!("Dialog {:?} ({:?}) geöffnet.", entity, config.id);
-                active_modals.modals.insert(entity);
+                active_dialogs.push(entity, config.modal);
                 break; // Dialog gefunden und verarbeitet
             }
         }
@@ -31,11 +59,17 @@ pub fn open_dialog_system(
 }
 
 /// System zum Schließen von Dialogen.
+///
+/// `CloseDialogEvent::current_modal()` schließt nur den obersten Dialog, die
+/// darunter liegenden bleiben offen. Escape und Klicks auf das Overlay
+/// kommen über den [`DismissableLayer`](crate::components::dismissable_layer::DismissableLayer)
+/// des Dialogs und lassen sich dort mit `prevent_default()` abfangen.
 pub fn close_dialog_system(
-    // mut commands: Commands, // Vorerst nicht genutzt, aber könnte für zukünftige, nicht-animierte ECS-Ops nützlich sein
     mut ev_close: EventReader<CloseDialogEvent>,
-    keyboard: Res<ButtonInput<KeyCode>>,
-    mut active_modals: ResMut<ActiveDialogs>,
+    mut ev_dismissed: EventReader<LayerDismissedEvent>,
+    mut focus: ResMut<InputFocus>,
+    hierarchy: PortalHierarchy,
+    mut active_dialogs: ResMut<ActiveDialogs>,
     mut q_dialogs: Query<
         (
             Entity,
@@ -45,42 +79,41 @@ pub fn close_dialog_system(
         ),
         With<DialogRootMarker>, // Stelle sicher, dass wir nur Dialog-Roots bearbeiten
     >,
-    // Keine q_animatable_content oder KeepMounted Query mehr
 ) {
     let mut ids_to_process_for_close: HashSet<DialogId> = HashSet::new();
-    let mut close_all_current_modals = false;
+    let mut close_topmost = false;
 
     // Events verarbeiten, um herauszufinden, welche Dialoge geschlossen werden sollen
     for event in ev_close.read() {
         if let Some(id) = &event.id_to_close {
             ids_to_process_for_close.insert(*id);
         } else {
-            close_all_current_modals = true;
+            close_topmost = true;
         }
     }
 
-    // ESC-Taste verarbeiten (schließt alle aktiven modalen Dialoge)
-    if keyboard.just_pressed(KeyCode::Escape) && !active_modals.modals.is_empty() {
-        close_all_current_modals = true;
-        info!("ESC gedrückt, schließe alle modalen Dialoge.");
-    }
-
-    // Wenn alle modalen geschlossen werden sollen, deren IDs sammeln
-    if close_all_current_modals {
-        for modal_entity_id in active_modals.modals.iter() {
-            if let Ok((_, config, _, _)) = q_dialogs.get(*modal_entity_id) {
+    // Escape oder Klick außerhalb, nur für die oberste Ebene gemeldet
+    for event in ev_dismissed.read() {
+        if let Ok((_, config, state, _)) = q_dialogs.get(event.layer) {
+            if state.open {
+                info!("Dialog {:?} geschlossen ({:?}).", config.id, event.reason);
                 ids_to_process_for_close.insert(config.id);
             }
         }
     }
 
+    if close_topmost {
+        if let Some((_, config, _, _)) = active_dialogs
+            .topmost()
+            .and_then(|top| q_dialogs.get(top).ok())
+        {
+            ids_to_process_for_close.insert(config.id);
+        }
+    }
+
     if ids_to_process_for_close.is_empty() {
         return; // Nichts zu tun
     }
-    info!(
-        "Versuche Dialoge zu schließen. IDs: {:?}",
-        ids_to_process_for_close
-    );
 
     let mut actually_closed_entities: HashSet<Entity> = HashSet::new();
 
@@ -90,37 +123,88 @@ pub fn close_dialog_system(
             state.open = false; // Zustand als geschlossen markieren
             *visibility = Visibility::Hidden; // DialogRootMarker sofort verstecken
 
+            // Fokus nur zurückgeben, wenn er noch im geschlossenen Dialog liegt
+            let focus_inside = focus.get().is_some_and(|f| {
+                std::iter::once(f)
+                    .chain(hierarchy.ancestors(f))
+                    .any(|e| e == entity)
+            });
+            if focus_inside {
+                match state.return_focus.take() {
+                    Some(previous) => focus.set(previous),
+                    None => focus.clear(),
+                }
+            }
+
             actually_closed_entities.insert(entity);
         }
     }
 
-    // Modale Dialoge aus der ActiveDialogs-Liste entfernen, die tatsächlich geschlossen wurden
     if !actually_closed_entities.is_empty() {
-        active_modals
-            .modals
-            .retain(|e| !actually_closed_entities.contains(e));
-        info!("ActiveDialogs aktualisiert: {:?}", active_modals.modals);
+        active_dialogs.retain(|e| !actually_closed_entities.contains(e));
     }
 }
 
-/// System, das Klicks auf das DialogOverlay abfängt (nur wenn modal).
-pub fn handle_overlay_click_system(
-    q_overlays: Query<(&Interaction, &ChildOf), (Changed<Interaction>, With<DialogOverlay>)>, // Interaction nur lesen -> iter()
-    q_dialog_root: Query<(Entity, &DialogConfig), With<DialogRootMarker>>,
-    mut ev_close: EventWriter<CloseDialogEvent>,
+/// Hält [`ActiveDialogs`] aktuell (auch für `initially_open`-Dialoge) und
+/// markiert alle Dialoge unter dem obersten modalen als [`DialogInert`] und
+/// [`Inert`], damit sie weder Fokus, Klicks noch Shortcuts erhalten.
+///
+/// Liegt der Fokus außerhalb des obersten modalen Dialogs und der darüber
+/// geöffneten, wird er in diesen zurückgeholt.
+pub fn sync_dialog_stack(
+    mut commands: Commands,
+    mut active_dialogs: ResMut<ActiveDialogs>,
+    mut focus: ResMut<InputFocus>,
+    hierarchy: PortalHierarchy,
+    children: Query<&Children>,
+    q_content: Query<(), With<DialogContent>>,
+    q_dialogs: Query<
+        (Entity, &DialogConfig, &DialogState, Has<DialogInert>),
+        With<DialogRootMarker>,
+    >,
 ) {
-    for (interaction, overlay_childof) in q_overlays.iter() {
-        if *interaction == Interaction::Pressed {
-            let root_entity = overlay_childof.parent();
-            if let Ok((_dialog_entity, dialog_config)) = q_dialog_root.get(root_entity) {
-                info!(
-                    "Overlay für Dialog {:?} (Entity {:?}) geklickt, sende CloseDialogEvent.",
-                    dialog_config.id, root_entity
-                );
-                ev_close.write(CloseDialogEvent::specific(dialog_config.id));
-            }
+    let is_open = |e: Entity| q_dialogs.get(e).is_ok_and(|(_, _, state, _)| state.open);
+    if active_dialogs.iter().any(|e| !is_open(e)) {
+        active_dialogs.retain(|e| is_open(*e));
+    }
+    for (entity, config, state, _) in q_dialogs.iter() {
+        if state.open && !active_dialogs.contains(entity) {
+            active_dialogs.push(entity, config.modal);
         }
     }
+
+    let stack: Vec<Entity> = active_dialogs.iter().collect();
+    let top_modal = active_dialogs
+        .topmost_modal()
+        .and_then(|modal| stack.iter().position(|e| *e == modal));
+
+    for (entity, _, _, inert) in q_dialogs.iter() {
+        let wanted = top_modal.is_some_and(|top| {
+            stack
+                .iter()
+                .position(|e| *e == entity)
+                .is_some_and(|i| i < top)
+        });
+        if wanted && !inert {
+            commands.entity(entity).insert((DialogInert, Inert));
+        } else if !wanted && inert {
+            commands.entity(entity).remove::<(DialogInert, Inert)>();
+        }
+    }
+
+    let Some(top) = top_modal else {
+        return;
+    };
+    let Some(focused) = focus.get() else {
+        return;
+    };
+    let reachable = &stack[top..];
+    let inside = std::iter::once(focused)
+        .chain(hierarchy.ancestors(focused))
+        .any(|e| reachable.contains(&e));
+    if !inside {
+        focus.set(focus_target(stack[top], &children, &q_content));
+    }
 }
 
 /// System, das auf Klicks von DialogTrigger-Buttons lauscht (die eine `OpenDialogActionPayload` haben)
@@ -32,7 +32,14 @@ impl<'w, 's> UiBuilder<'w, 's> for DirectionProviderBuilder {
 
     fn spawn(self, parent: &'s mut ChildSpawnerCommands<'w>, theme: &UiTheme, font: &Handle<Font>) -> Self::Output {
         let dir_copy = self.dir;
-        let mut cmd = parent.spawn(DirectionProvider { dir: self.dir });
+        let mut cmd = parent.spawn((
+            DirectionProvider { dir: self.dir },
+            Node {
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            Name::new("DirectionProvider"),
+        ));
         if let Some(func) = self.content {
             cmd.with_children(|cb| {
                 func(cb, dir_copy, theme, font);
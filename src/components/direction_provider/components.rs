@@ -26,3 +26,29 @@ impl Default for DirectionProvider {
         }
     }
 }
+
+/// Direction that the RTL layout pass has applied to a node.
+///
+/// Inserted automatically once a node was mirrored; widgets can read it to
+/// decide direction-dependent behaviour (e.g. which arrow key means "next").
+/// The layout pass mirrors a node only when its direction changes, so systems
+/// that keep rewriting horizontal `Node` fields (e.g. `flex_direction`) have
+/// to apply the mirror themselves. Systems that place a node via `left` use
+/// [`RuntimePosition`] instead.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ResolvedDirection(pub ReadingDirection);
+
+/// Opts a node (but not its children) out of the RTL layout pass.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct IgnoreDirection;
+
+/// Keeps `left`/`right` of a node out of the RTL layout pass because a
+/// system places it in physical coordinates (floating content, text field
+/// caret, ...). Padding, margin, border and flex direction are still mirrored.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct RuntimePosition;
+
+/// Marks an [`ImageNode`] whose image is directional (chevrons, arrows) and
+/// should be flipped horizontally under [`ReadingDirection::Rtl`].
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct MirrorInRtl;
@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+
+use super::{DirectionProvider, ReadingDirection};
+
+/// Returns the direction of the nearest [`DirectionProvider`] on `entity` or
+/// one of its ancestors. Falls back to [`ReadingDirection::Ltr`].
+pub fn resolve_reading_direction(
+    entity: Entity,
+    parents: &Query<&ChildOf>,
+    providers: &Query<&DirectionProvider>,
+) -> ReadingDirection {
+    let mut current = entity;
+    loop {
+        if let Ok(provider) = providers.get(current) {
+            return provider.dir;
+        }
+        match parents.get(current) {
+            Ok(parent) => current = parent.parent(),
+            Err(_) => return ReadingDirection::Ltr,
+        }
+    }
+}
@@ -1,5 +1,11 @@
 mod builder;
 mod components;
+mod helpers;
+mod plugin;
+mod systems;
 
 pub use builder::*;
 pub use components::*;
+pub use helpers::*;
+pub use plugin::*;
+pub use systems::*;
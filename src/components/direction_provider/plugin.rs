@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+
+use super::systems::apply_reading_direction;
+
+/// Registers the RTL layout pass for [`DirectionProvider`](super::DirectionProvider).
+///
+/// Runs in `PostUpdate` before the UI layout so freshly spawned nodes are
+/// already mirrored in their first rendered frame.
+pub struct DirectionProviderPlugin;
+
+impl Plugin for DirectionProviderPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, apply_reading_direction.before(UiSystem::Layout));
+    }
+}
@@ -0,0 +1,97 @@
+use bevy::prelude::*;
+
+use super::*;
+
+/// Mirrors nodes below an RTL [`DirectionProvider`].
+///
+/// Only nodes whose resolved direction can have changed are visited: newly
+/// spawned or reparented nodes and the subtrees of added, changed or removed
+/// providers. Mirroring is its own inverse, so switching a provider back to
+/// [`ReadingDirection::Ltr`] restores the original layout. `left`/`right` of
+/// [`RuntimePosition`] nodes are left to the system that writes them.
+pub fn apply_reading_direction(
+    mut commands: Commands,
+    reparented: Query<Entity, (Changed<ChildOf>, Without<DirectionProvider>)>,
+    changed_providers: Query<Entity, Changed<DirectionProvider>>,
+    mut removed_providers: RemovedComponents<DirectionProvider>,
+    parents: Query<&ChildOf>,
+    providers: Query<&DirectionProvider>,
+    children_q: Query<&Children>,
+    mut nodes: ParamSet<(
+        Query<Entity, Added<Node>>,
+        Query<
+            (
+                Option<&ResolvedDirection>,
+                Option<&mut Node>,
+                Option<&mut BorderRadius>,
+                Option<&mut ImageNode>,
+                Has<MirrorInRtl>,
+                Has<RuntimePosition>,
+            ),
+            Without<IgnoreDirection>,
+        >,
+    )>,
+) {
+    let mut pending: Vec<Entity> = nodes.p0().iter().collect();
+    for root in reparented
+        .iter()
+        .chain(changed_providers.iter())
+        .chain(removed_providers.read())
+    {
+        pending.push(root);
+        pending.extend(children_q.iter_descendants(root));
+    }
+    if pending.is_empty() {
+        return;
+    }
+    pending.sort_unstable();
+    pending.dedup();
+
+    let mut targets = nodes.p1();
+    for entity in pending {
+        let Ok((applied, node, radius, image, mirror_icon, runtime_position)) =
+            targets.get_mut(entity)
+        else {
+            continue;
+        };
+        let dir = resolve_reading_direction(entity, &parents, &providers);
+        let previous = applied.map(|a| a.0).unwrap_or_default();
+        if dir == previous {
+            continue;
+        }
+
+        if let Some(mut node) = node {
+            mirror_node(&mut node, !runtime_position);
+        }
+        if let Some(mut radius) = radius {
+            *radius = BorderRadius {
+                top_left: radius.top_right,
+                top_right: radius.top_left,
+                bottom_left: radius.bottom_right,
+                bottom_right: radius.bottom_left,
+            };
+        }
+        if let (Some(mut image), true) = (image, mirror_icon) {
+            image.flip_x = !image.flip_x;
+        }
+
+        if let Ok(mut cmd) = commands.get_entity(entity) {
+            cmd.try_insert(ResolvedDirection(dir));
+        }
+    }
+}
+
+/// Swaps everything that encodes a horizontal side of a node.
+fn mirror_node(node: &mut Node, position: bool) {
+    node.flex_direction = match node.flex_direction {
+        FlexDirection::Row => FlexDirection::RowReverse,
+        FlexDirection::RowReverse => FlexDirection::Row,
+        other => other,
+    };
+    if position {
+        std::mem::swap(&mut node.left, &mut node.right);
+    }
+    std::mem::swap(&mut node.padding.left, &mut node.padding.right);
+    std::mem::swap(&mut node.margin.left, &mut node.margin.right);
+    std::mem::swap(&mut node.border.left, &mut node.border.right);
+}
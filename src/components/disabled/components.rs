@@ -0,0 +1,50 @@
+use bevy::ecs::component::Mutable;
+use bevy::input_focus::tab_navigation::TabIndex;
+use bevy::prelude::*;
+
+/// Disables an element and all of its descendants.
+///
+/// Insert or remove it at runtime to toggle the state. While present the
+/// subtree is covered by a themed overlay that swallows hover and clicks,
+/// leaves the tab order and does not react to shortcuts.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct Disabled;
+
+/// Makes an element and its descendants non-interactive like [`Disabled`],
+/// but without the overlay and without changing how it looks.
+///
+/// Used for content that is blocked by something on top of it, e.g. dialogs
+/// below the topmost modal.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct Inert;
+
+/// Set automatically on every entity that is disabled or inert itself or has
+/// a [`Disabled`]/[`Inert`] ancestor. Use it in query filters to gate input
+/// instead of walking parents.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct InheritedDisabled;
+
+/// Set automatically on every entity that is [`Disabled`] itself or has a
+/// [`Disabled`] ancestor. Unlike [`InheritedDisabled`] it ignores [`Inert`],
+/// so it marks what should look disabled; widget `disabled` flags follow it.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct AppearsDisabled;
+
+/// Marker for the overlay node spawned below a [`Disabled`] entity.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct DisabledOverlayMarker;
+
+/// Tab index an entity had before it was disabled.
+#[derive(Component, Debug, Clone, Copy)]
+pub(crate) struct DisabledRestore(pub(crate) Option<TabIndex>);
+
+/// Widget state that mirrors the shared [`Disabled`] handling in a `disabled` flag.
+///
+/// Implemented for the widget states (e.g. `ButtonState`, `CheckboxState`).
+/// Register [`sync_disabled_flag`](super::sync_disabled_flag) for the type so
+/// that setting the flag toggles [`Disabled`] and an inherited disable updates
+/// the flag. [`Inert`] never touches the flag.
+pub trait DisabledFlag: Component<Mutability = Mutable> {
+    fn is_disabled(&self) -> bool;
+    fn set_disabled(&mut self, disabled: bool);
+}
@@ -0,0 +1,9 @@
+mod components;
+mod plugin;
+mod style;
+mod systems;
+
+pub use components::*;
+pub use plugin::*;
+pub use style::*;
+pub use systems::*;
@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+
+use super::systems::*;
+use crate::plugin::UiState;
+
+/// Plugin for the shared [`Disabled`](super::Disabled) state.
+///
+/// Widgets with their own `disabled` flag register
+/// [`sync_disabled_flag`] for their state type in their plugin.
+pub struct DisabledPlugin;
+
+impl Plugin for DisabledPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                propagate_disabled,
+                update_disabled_overlays,
+                blur_disabled_focus,
+            )
+                .chain()
+                .run_if(in_state(UiState::Ready)),
+        );
+    }
+}
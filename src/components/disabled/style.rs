@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+
+use crate::theme::UiTheme;
+
+use super::DisabledOverlayMarker;
+
+/// Style bundle for the overlay that covers a disabled element.
+#[derive(Bundle, Clone, Debug)]
+pub struct DisabledOverlayStyle {
+    pub marker: DisabledOverlayMarker,
+    pub node: Node,
+    pub background_color: BackgroundColor,
+    pub border_radius: BorderRadius,
+    pub focus_policy: FocusPolicy,
+    pub z_index: ZIndex,
+}
+
+impl DisabledOverlayStyle {
+    /// Creates the overlay style, `radius` should match the covered element.
+    pub fn new(theme: &UiTheme, radius: BorderRadius) -> Self {
+        Self {
+            marker: DisabledOverlayMarker,
+            node: Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                top: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            background_color: BackgroundColor(theme.color.black.step08),
+            border_radius: radius,
+            // Blockiert Hover und Klicks für alle darunterliegenden Nodes
+            focus_policy: FocusPolicy::Block,
+            // Über später gespawnten Geschwistern halten
+            z_index: ZIndex(i32::MAX),
+        }
+    }
+}
@@ -0,0 +1,210 @@
+use std::collections::HashSet;
+
+use bevy::input_focus::{tab_navigation::TabIndex, InputFocus};
+use bevy::prelude::*;
+
+use crate::components::portal::{PortalHierarchy, PortalOwner};
+use crate::theme::UiTheme;
+
+use super::*;
+
+/// Components that make a subtree non-interactive.
+pub type DisabledSource = Or<(With<Disabled>, With<Inert>)>;
+
+/// Whether `entity` or one of its logical ancestors carries [`Disabled`] or
+/// [`Inert`]. Portaled content inherits the state of its owner.
+pub fn is_disabled(
+    entity: Entity,
+    hierarchy: &PortalHierarchy,
+    disabled: &Query<(), DisabledSource>,
+) -> bool {
+    std::iter::once(entity)
+        .chain(hierarchy.ancestors(entity))
+        .any(|e| disabled.contains(e))
+}
+
+/// Keeps [`InheritedDisabled`] and [`AppearsDisabled`] in sync for subtrees whose disabled state may
+/// have changed (added/removed [`Disabled`] or [`Inert`], newly spawned, reparented or
+/// portaled nodes). Portaled content follows its owner, not the portal root.
+///
+/// Disabling removes the [`TabIndex`] so the element leaves the tab order; the
+/// previous index is restored once the element is enabled again.
+pub(crate) fn propagate_disabled(
+    mut commands: Commands,
+    added: Query<Entity, Or<(Added<Disabled>, Added<Inert>)>>,
+    mut removed: RemovedComponents<Disabled>,
+    mut removed_inert: RemovedComponents<Inert>,
+    reparented: Query<Entity, Changed<ChildOf>>,
+    portaled: Query<Entity, Changed<PortalOwner>>,
+    late_tab_indices: Query<(Entity, &TabIndex), (Added<TabIndex>, With<InheritedDisabled>)>,
+    disabled: Query<(), DisabledSource>,
+    own_disabled: Query<(), With<Disabled>>,
+    hierarchy: PortalHierarchy,
+    mut targets: Query<(
+        Has<InheritedDisabled>,
+        Has<AppearsDisabled>,
+        Option<&TabIndex>,
+        Option<&DisabledRestore>,
+        Option<&mut Interaction>,
+    )>,
+) {
+    // Z.B. von Roving Focus nachträglich vergebene Indizes wieder entfernen
+    for (entity, tab_index) in late_tab_indices.iter() {
+        commands
+            .entity(entity)
+            .try_insert(DisabledRestore(Some(*tab_index)))
+            .try_remove::<TabIndex>();
+    }
+
+    let mut roots: Vec<Entity> = added
+        .iter()
+        .chain(removed.read())
+        .chain(removed_inert.read())
+        .chain(reparented.iter())
+        .chain(portaled.iter())
+        .collect();
+    if roots.is_empty() {
+        return;
+    }
+    roots.sort_unstable();
+    roots.dedup();
+
+    let mut visited = HashSet::new();
+    for root in roots {
+        let parent = hierarchy.parent(root);
+        let ancestor_disabled = parent.is_some_and(|p| is_disabled(p, &hierarchy, &disabled));
+        let ancestor_appears = parent.is_some_and(|p| {
+            std::iter::once(p)
+                .chain(hierarchy.ancestors(p))
+                .any(|e| own_disabled.contains(e))
+        });
+        let mut stack = vec![(root, ancestor_disabled, ancestor_appears)];
+        while let Some((entity, inherited, inherited_appears)) = stack.pop() {
+            if !visited.insert(entity) {
+                continue;
+            }
+            let flag = inherited || disabled.contains(entity);
+            let appears = inherited_appears || own_disabled.contains(entity);
+
+            if let (Ok((has, has_appears, tab_index, restore, interaction)), Ok(mut cmd)) =
+                (targets.get_mut(entity), commands.get_entity(entity))
+            {
+                if appears && !has_appears {
+                    cmd.try_insert(AppearsDisabled);
+                } else if !appears && has_appears {
+                    cmd.try_remove::<AppearsDisabled>();
+                }
+                if flag && !has {
+                    cmd.try_insert((InheritedDisabled, DisabledRestore(tab_index.copied())));
+                    if tab_index.is_some() {
+                        cmd.try_remove::<TabIndex>();
+                    }
+                    if let Some(mut interaction) = interaction {
+                        interaction.set_if_neq(Interaction::None);
+                    }
+                } else if !flag && has {
+                    cmd.try_remove::<(InheritedDisabled, DisabledRestore)>();
+                    if let Some(DisabledRestore(Some(tab_index))) = restore {
+                        cmd.try_insert(*tab_index);
+                    }
+                }
+            }
+
+            // Die Portal-Wurzel ist nur technischer Parent, nicht logischer
+            stack.extend(
+                hierarchy
+                    .children(entity)
+                    .filter(|child| hierarchy.parent(*child) == Some(entity))
+                    .map(|child| (child, flag, appears)),
+            );
+        }
+    }
+}
+
+/// Spawns the themed overlay for newly disabled entities and removes it again
+/// when [`Disabled`] is removed.
+pub fn update_disabled_overlays(
+    mut commands: Commands,
+    theme: Option<Res<UiTheme>>,
+    added: Query<(Entity, Option<&BorderRadius>), Added<Disabled>>,
+    mut removed: RemovedComponents<Disabled>,
+    children_q: Query<&Children>,
+    overlays: Query<(), With<DisabledOverlayMarker>>,
+) {
+    for entity in removed.read() {
+        let Ok(children) = children_q.get(entity) else {
+            continue;
+        };
+        for child in children.iter().filter(|c| overlays.contains(*c)) {
+            commands.entity(child).despawn();
+        }
+    }
+
+    let Some(theme) = theme else {
+        return;
+    };
+    for (entity, radius) in added.iter() {
+        if let Ok(mut cmd) = commands.get_entity(entity) {
+            cmd.with_child(DisabledOverlayStyle::new(
+                &theme,
+                radius.copied().unwrap_or_default(),
+            ));
+        }
+    }
+}
+
+/// Moves keyboard focus away from an element that became disabled.
+pub fn blur_disabled_focus(
+    focus: Option<ResMut<InputFocus>>,
+    disabled: Query<(), With<InheritedDisabled>>,
+) {
+    let Some(mut focus) = focus else {
+        return;
+    };
+    if focus.get().is_some_and(|e| disabled.contains(e)) {
+        focus.clear();
+    }
+}
+
+/// Bridges a widget's own `disabled` flag and the shared [`Disabled`] handling.
+///
+/// - Setting the flag on the state inserts/removes [`Disabled`].
+/// - Gaining or losing [`AppearsDisabled`] (e.g. through a disabled
+///   ancestor) updates the flag, so widget logic that checks it keeps working.
+///   [`Inert`] only gates input and leaves the flag alone.
+pub fn sync_disabled_flag<T: DisabledFlag>(
+    mut commands: Commands,
+    mut states: ParamSet<(
+        Query<(Entity, &T, Has<Disabled>, Has<AppearsDisabled>), Changed<T>>,
+        Query<&mut T>,
+    )>,
+    inherited_added: Query<Entity, (Added<AppearsDisabled>, With<T>)>,
+    mut inherited_removed: RemovedComponents<AppearsDisabled>,
+    own_disabled: Query<(), With<Disabled>>,
+) {
+    for (entity, state, has_disabled, inherited) in states.p0().iter() {
+        if state.is_disabled() && !has_disabled && !inherited {
+            commands.entity(entity).try_insert(Disabled);
+        } else if !state.is_disabled() && has_disabled {
+            commands.entity(entity).try_remove::<Disabled>();
+        }
+    }
+
+    let updates: Vec<(Entity, bool)> = inherited_added
+        .iter()
+        .map(|e| (e, true))
+        .chain(
+            inherited_removed
+                .read()
+                .map(|e| (e, own_disabled.contains(e))),
+        )
+        .collect();
+    let mut targets = states.p1();
+    for (entity, flag) in updates {
+        if let Ok(mut state) = targets.get_mut(entity) {
+            if state.is_disabled() != flag {
+                state.set_disabled(flag);
+            }
+        }
+    }
+}
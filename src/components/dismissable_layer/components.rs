@@ -0,0 +1,70 @@
+use bevy::prelude::*;
+
+/// Closes an overlay on Escape, on a pointer-down outside of it and when the
+/// focus moves outside of it.
+///
+/// Only the topmost open [`Layer`](crate::components::layer::Layer) is
+/// dismissed, so the entity needs a `Layer` as well. Before dismissing, an
+/// [`EscapeKeyDown`](super::EscapeKeyDown),
+/// [`PointerDownOutside`](super::PointerDownOutside) or
+/// [`FocusOutside`](super::FocusOutside) event is triggered on the entity;
+/// observers can call `prevent_default()` to keep it open. Otherwise a
+/// [`LayerDismissedEvent`](super::LayerDismissedEvent) is sent and the owning
+/// widget closes itself.
+#[derive(Component, Debug, Clone)]
+pub struct DismissableLayer {
+    pub escape: bool,
+    pub pointer_outside: bool,
+    pub focus_outside: bool,
+    /// Entities that count as inside besides the layer itself, e.g. the
+    /// trigger that toggles the overlay.
+    pub branches: Vec<Entity>,
+    /// Part of the layer that counts as inside for pointer presses, e.g. the
+    /// panel of a full-screen dialog. `None` uses the whole layer.
+    pub hit_area: Option<Entity>,
+}
+
+impl Default for DismissableLayer {
+    fn default() -> Self {
+        Self {
+            escape: true,
+            pointer_outside: true,
+            focus_outside: true,
+            branches: Vec::new(),
+            hit_area: None,
+        }
+    }
+}
+
+impl DismissableLayer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Treats `entity` and its descendants as part of the layer.
+    pub fn branch(mut self, entity: Entity) -> Self {
+        self.branches.push(entity);
+        self
+    }
+
+    /// Only presses outside of `entity` (and the branches) dismiss the layer.
+    pub fn hit_area(mut self, entity: Entity) -> Self {
+        self.hit_area = Some(entity);
+        self
+    }
+
+    pub fn escape(mut self, enabled: bool) -> Self {
+        self.escape = enabled;
+        self
+    }
+
+    pub fn pointer_outside(mut self, enabled: bool) -> Self {
+        self.pointer_outside = enabled;
+        self
+    }
+
+    pub fn focus_outside(mut self, enabled: bool) -> Self {
+        self.focus_outside = enabled;
+        self
+    }
+}
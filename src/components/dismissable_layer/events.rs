@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+
+/// What caused a [`DismissableLayer`](super::DismissableLayer) to close.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DismissReason {
+    EscapeKey,
+    PointerDownOutside,
+    FocusOutside,
+}
+
+/// Events a [`DismissableLayer`](super::DismissableLayer) triggers before
+/// closing.
+pub(crate) trait Interceptable: Event + Default {
+    fn is_prevented(&self) -> bool;
+}
+
+macro_rules! interceptable_event {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Event, Debug, Clone, Default)]
+        pub struct $name {
+            prevented: bool,
+        }
+
+        impl $name {
+            /// Keeps the layer open.
+            pub fn prevent_default(&mut self) {
+                self.prevented = true;
+            }
+
+            pub fn is_default_prevented(&self) -> bool {
+                self.prevented
+            }
+        }
+
+        impl Interceptable for $name {
+            fn is_prevented(&self) -> bool {
+                self.prevented
+            }
+        }
+    };
+}
+
+interceptable_event!(
+    /// Triggered on the topmost layer when Escape is pressed.
+    EscapeKeyDown
+);
+interceptable_event!(
+    /// Triggered on the topmost layer when a mouse button is pressed outside.
+    PointerDownOutside
+);
+interceptable_event!(
+    /// Triggered on the topmost layer when the focus moves outside.
+    FocusOutside
+);
+
+/// Sent once a layer was dismissed and not prevented.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct LayerDismissedEvent {
+    pub layer: Entity,
+    pub reason: DismissReason,
+}
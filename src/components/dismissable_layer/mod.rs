@@ -0,0 +1,12 @@
+// src/components/dismissable_layer/mod.rs
+
+mod components;
+mod events;
+mod plugin;
+mod systems;
+
+pub use components::DismissableLayer;
+pub use events::{
+    DismissReason, EscapeKeyDown, FocusOutside, LayerDismissedEvent, PointerDownOutside,
+};
+pub use plugin::DismissableLayerPlugin;
@@ -0,0 +1,24 @@
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+
+use super::{systems::handle_dismissable_layers, LayerDismissedEvent};
+use crate::components::layer::update_layer_stack;
+use crate::plugin::UiState;
+
+/// Plugin for [`DismissableLayer`](super::DismissableLayer).
+///
+/// Runs in `PreUpdate`, after the layer stack, so widgets can react to
+/// [`LayerDismissedEvent`] in `Update` of the same frame.
+pub struct DismissableLayerPlugin;
+
+impl Plugin for DismissableLayerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<LayerDismissedEvent>().add_systems(
+            PreUpdate,
+            handle_dismissable_layers
+                .after(InputSystem)
+                .after(update_layer_stack)
+                .run_if(in_state(UiState::Ready)),
+        );
+    }
+}
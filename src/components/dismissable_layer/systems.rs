@@ -0,0 +1,112 @@
+use bevy::input_focus::InputFocus;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use super::events::Interceptable;
+use super::*;
+use crate::components::layer::LayerStack;
+use crate::components::portal::PortalHierarchy;
+
+type NodeParts<'a> = (
+    &'a ComputedNode,
+    &'a GlobalTransform,
+    &'a InheritedVisibility,
+);
+
+/// Whether the physical `point` lies on one of the visible `entities`.
+fn hits(point: Vec2, entities: &[Entity], nodes: &Query<NodeParts>) -> bool {
+    entities.iter().any(|e| {
+        nodes.get(*e).is_ok_and(|(node, transform, visibility)| {
+            visibility.get()
+                && Rect::from_center_size(transform.translation().truncate(), node.size())
+                    .contains(point)
+        })
+    })
+}
+
+/// Triggers `E` on `layer`; `true` if no observer prevented it.
+fn intercept<E: Interceptable>(world: &mut World, layer: Entity) -> bool {
+    let mut event = E::default();
+    world.trigger_targets_ref(&mut event, layer);
+    !event.is_prevented()
+}
+
+/// Dismisses the topmost [`DismissableLayer`] on Escape, on a pointer-down
+/// outside and when the focus moves outside of it.
+pub(crate) fn handle_dismissable_layers(
+    mut commands: Commands,
+    stack: Res<LayerStack>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    focus: Res<InputFocus>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    layers: Query<&DismissableLayer>,
+    nodes: Query<NodeParts>,
+    hierarchy: PortalHierarchy,
+    // Oberste Ebene und Fokus, als sie nach oben kam
+    mut seen: Local<Option<(Entity, Option<Entity>)>>,
+) {
+    let Some((entity, layer)) = stack
+        .topmost()
+        .and_then(|top| layers.get(top.entity).ok().map(|l| (top.entity, l)))
+    else {
+        *seen = None;
+        return;
+    };
+    // Fokuswechsel beim Öffnen selbst zählen nicht als "außerhalb"
+    let opened_focus = match *seen {
+        Some((seen_entity, seen_focus)) if seen_entity == entity => seen_focus,
+        _ => {
+            *seen = Some((entity, focus.get()));
+            focus.get()
+        }
+    };
+
+    let inside: Vec<Entity> = std::iter::once(entity)
+        .chain(layer.branches.iter().copied())
+        .flat_map(|root| std::iter::once(root).chain(hierarchy.descendants(root)))
+        .collect();
+    let hit_inside: Vec<Entity> = match layer.hit_area {
+        Some(area) => std::iter::once(area)
+            .chain(layer.branches.iter().copied())
+            .flat_map(|root| std::iter::once(root).chain(hierarchy.descendants(root)))
+            .collect(),
+        None => inside.clone(),
+    };
+
+    let reason = if layer.escape && keys.just_pressed(KeyCode::Escape) {
+        Some(DismissReason::EscapeKey)
+    } else if layer.pointer_outside && mouse.get_just_pressed().next().is_some() {
+        windows
+            .single()
+            .ok()
+            .and_then(Window::physical_cursor_position)
+            .filter(|point| !hits(*point, &hit_inside, &nodes))
+            .map(|_| DismissReason::PointerDownOutside)
+    } else if layer.focus_outside && focus.get() != opened_focus {
+        *seen = Some((entity, focus.get()));
+        focus
+            .get()
+            .filter(|e| !inside.contains(e))
+            .map(|_| DismissReason::FocusOutside)
+    } else {
+        None
+    };
+
+    let Some(reason) = reason else {
+        return;
+    };
+    commands.queue(move |world: &mut World| {
+        let dismiss = match reason {
+            DismissReason::EscapeKey => intercept::<EscapeKeyDown>(world, entity),
+            DismissReason::PointerDownOutside => intercept::<PointerDownOutside>(world, entity),
+            DismissReason::FocusOutside => intercept::<FocusOutside>(world, entity),
+        };
+        if dismiss {
+            world.send_event(LayerDismissedEvent {
+                layer: entity,
+                reason,
+            });
+        }
+    });
+}
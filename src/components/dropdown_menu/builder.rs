@@ -1,35 +1,144 @@
 use bevy::prelude::*;
 
 use crate::components::base_button::BaseButtonBuilder;
-use crate::components::helper::UiBuilder;
+use crate::components::base_menu::{
+    spawn_menu_items, BaseMenuContentMarker, MenuContentState, MenuItems,
+};
+use crate::components::dismissable_layer::DismissableLayer;
+use crate::components::floating::{Floating, FloatingAlign, FloatingSide};
+use crate::components::helper::{NoAction, UiBuilder};
 use crate::components::label::LabelBuilder;
+use crate::components::layer::{Layer, UiLayer};
+use crate::components::portal::Portal;
 use crate::theme::UiTheme;
 
-use super::{DropdownMenuItemMarker, DropdownMenuItemStyle, DropdownMenuMarker, DropdownMenuStyle};
+use super::{
+    DropdownMenuContentMarker, DropdownMenuMarker, DropdownMenuStyle, DropdownMenuTriggerMarker,
+    DropdownMenuTriggerStyle,
+};
 
-/// Builder for a simple dropdown menu.
-pub struct DropdownMenuBuilder {
+/// Builder for a dropdown menu: a trigger button that toggles a floating
+/// menu panel.
+///
+/// Choosing an item sends a
+/// [`DropdownMenuItemSelected<A>`](super::DropdownMenuItemSelected) and
+/// closes the menu, unless `close_on_select(false)` is set.
+///
+/// ```ignore
+/// DropdownMenuBuilder::<ViewAction>::new("View")
+///     .checkbox("Status Bar", true).action(ViewAction::StatusBar)
+///     .separator()
+///     .label("Panel Position")
+///     .radio("position", "Top", true).action(ViewAction::Top)
+///     .radio("position", "Bottom", false).action(ViewAction::Bottom)
+///     .spawn(parent, &theme, &font);
+/// ```
+pub struct DropdownMenuBuilder<A: Component + Clone = NoAction> {
     trigger: String,
-    items: Vec<String>,
+    items: MenuItems<A>,
+    open: bool,
+    close_on_select: bool,
+    side: FloatingSide,
+    align: FloatingAlign,
 }
 
-impl DropdownMenuBuilder {
+impl<A: Component + Clone> DropdownMenuBuilder<A> {
     /// Create a new dropdown menu with the given trigger label.
     pub fn new(trigger: impl Into<String>) -> Self {
         Self {
             trigger: trigger.into(),
-            items: Vec::new(),
+            items: MenuItems::new(),
+            open: false,
+            close_on_select: true,
+            side: FloatingSide::Bottom,
+            align: FloatingAlign::Start,
         }
     }
 
+    /// Replaces all entries of the menu.
+    pub fn items(mut self, items: MenuItems<A>) -> Self {
+        self.items = items;
+        self
+    }
+
     /// Add a text item to the menu.
     pub fn item(mut self, text: impl Into<String>) -> Self {
-        self.items.push(text.into());
+        self.items = self.items.item(text);
+        self
+    }
+
+    /// Add an item with a check mark that toggles when chosen.
+    pub fn checkbox(mut self, text: impl Into<String>, checked: bool) -> Self {
+        self.items = self.items.checkbox(text, checked);
+        self
+    }
+
+    /// Add an item of the radio group `group`.
+    pub fn radio(
+        mut self,
+        group: impl Into<String>,
+        text: impl Into<String>,
+        checked: bool,
+    ) -> Self {
+        self.items = self.items.radio(group, text, checked);
+        self
+    }
+
+    /// Add an item that opens a nested menu on hover or Arrow Right.
+    pub fn submenu(mut self, text: impl Into<String>, items: MenuItems<A>) -> Self {
+        self.items = self.items.submenu(text, items);
+        self
+    }
+
+    pub fn separator(mut self) -> Self {
+        self.items = self.items.separator();
+        self
+    }
+
+    /// Add a non-interactive group heading.
+    pub fn label(mut self, text: impl Into<String>) -> Self {
+        self.items = self.items.label(text);
+        self
+    }
+
+    /// Action of the item added last.
+    pub fn action(mut self, action: A) -> Self {
+        self.items = self.items.action(action);
+        self
+    }
+
+    /// Disables the item added last.
+    pub fn disabled(mut self) -> Self {
+        self.items = self.items.disabled();
+        self
+    }
+
+    /// Whether the menu starts open.
+    pub fn open(mut self, flag: bool) -> Self {
+        self.open = flag;
+        self
+    }
+
+    /// Whether choosing an item closes the menu (default: `true`). Keeping
+    /// it open is handy for menus with many checkbox items.
+    pub fn close_on_select(mut self, flag: bool) -> Self {
+        self.close_on_select = flag;
+        self
+    }
+
+    /// Side of the trigger the menu opens on.
+    pub fn side(mut self, side: FloatingSide) -> Self {
+        self.side = side;
+        self
+    }
+
+    pub fn align(mut self, align: FloatingAlign) -> Self {
+        self.align = align;
         self
     }
 }
 
-impl<'w, 's> UiBuilder<'w, 's> for DropdownMenuBuilder {
+impl<'w, 's, A: Component + Clone> UiBuilder<'w, 's> for DropdownMenuBuilder<A> {
     type Output = Entity;
 
     fn spawn(
@@ -45,28 +154,44 @@ impl<'w, 's> UiBuilder<'w, 's> for DropdownMenuBuilder {
             Node {
                 display: Display::Flex,
                 flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Start,
                 ..default()
             },
+            Name::new("DropdownMenu"),
         ));
 
         cmd.with_children(|cb| {
-            BaseButtonBuilder::new()
+            let trigger = BaseButtonBuilder::new()
                 .content(|b, theme, font| {
                     let _ = LabelBuilder::new(trigger).spawn(b, theme, font);
                 })
                 .spawn(cb, theme, font);
+            cb.commands().entity(trigger).insert((
+                DropdownMenuTriggerMarker,
+                DropdownMenuTriggerStyle::new(theme),
+            ));
 
-            let style = DropdownMenuStyle::new(theme);
-            let mut menu_cmd = cb.spawn((style,));
-            menu_cmd.with_children(|menu_cb| {
-                for item in items {
-                    let item_style = DropdownMenuItemStyle::new(theme);
-                    let mut item_cmd = menu_cb.spawn((DropdownMenuItemMarker, item_style));
-                    item_cmd.with_children(|icb| {
-                        let _ = LabelBuilder::new(item.clone()).spawn(icb, theme, font);
-                    });
-                }
-            });
+            let mut menu_cmd = cb.spawn((
+                DropdownMenuContentMarker,
+                BaseMenuContentMarker,
+                MenuContentState {
+                    open: self.open,
+                    return_focus: Some(trigger),
+                    close_on_select: self.close_on_select,
+                    ..default()
+                },
+                DropdownMenuStyle::new(theme),
+                Floating::new(trigger).side(self.side).align(self.align),
+                Portal::global(),
+                Layer(UiLayer::Dropdown),
+                DismissableLayer::new().branch(trigger),
+                if self.open {
+                    Visibility::Inherited
+                } else {
+                    Visibility::Hidden
+                },
+            ));
+            menu_cmd.with_children(|menu_cb| spawn_menu_items(menu_cb, items, theme, font));
         });
 
         cmd.id()
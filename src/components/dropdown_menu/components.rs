@@ -4,6 +4,11 @@ use bevy::prelude::*;
 #[derive(Component, Debug, Clone, Copy, Default)]
 pub struct DropdownMenuMarker;
 
-/// Marker for individual menu items.
+/// Marker for the button that opens and closes the menu.
 #[derive(Component, Debug, Clone, Copy, Default)]
-pub struct DropdownMenuItemMarker;
+pub struct DropdownMenuTriggerMarker;
+
+/// Marker for the menu panel. Its open state lives in
+/// [`MenuContentState`](crate::components::base_menu::MenuContentState).
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct DropdownMenuContentMarker;
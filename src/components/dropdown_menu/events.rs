@@ -0,0 +1,31 @@
+use bevy::prelude::{Component, Entity, Event};
+use std::fmt::Debug;
+
+/// Sent when an item of a dropdown menu is chosen.
+///
+/// Generic over the action type `A` set with
+/// [`DropdownMenuBuilder::action`](super::DropdownMenuBuilder::action); each
+/// type needs its own [`DropdownMenuActionPlugin`](super::DropdownMenuActionPlugin).
+#[derive(Event, Clone)]
+pub struct DropdownMenuItemSelected<A: Component + Clone + Send + Sync> {
+    /// The chosen item.
+    pub source_entity: Entity,
+    /// Root entity of the dropdown menu.
+    pub menu: Entity,
+    /// Clone of the item's action, `None` if it has none.
+    pub action_id: Option<A>,
+    /// New checked state of checkbox and radio items.
+    pub checked: Option<bool>,
+}
+
+// Wie beim ButtonClickedEvent: A muss kein Debug implementieren
+impl<A: Component + Clone + Send + Sync> Debug for DropdownMenuItemSelected<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DropdownMenuItemSelected")
+            .field("source_entity", &self.source_entity)
+            .field("menu", &self.menu)
+            .field("action_id", &self.action_id.as_ref().map(|_| "Some<A>"))
+            .field("checked", &self.checked)
+            .finish()
+    }
+}
@@ -1,7 +1,12 @@
 mod builder;
 mod components;
+mod events;
+mod plugin;
 mod style;
+mod systems;
 
 pub use builder::DropdownMenuBuilder;
-pub use components::{DropdownMenuItemMarker, DropdownMenuMarker};
-pub use style::{DropdownMenuItemStyle, DropdownMenuStyle};
+pub use components::{DropdownMenuContentMarker, DropdownMenuMarker, DropdownMenuTriggerMarker};
+pub use events::DropdownMenuItemSelected;
+pub use plugin::{DropdownMenuActionPlugin, DropdownMenuPlugin};
+pub use style::{DropdownMenuStyle, DropdownMenuTriggerStyle};
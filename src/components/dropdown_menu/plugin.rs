@@ -0,0 +1,50 @@
+use bevy::prelude::*;
+use std::marker::PhantomData;
+
+use super::events::DropdownMenuItemSelected;
+use super::systems::*;
+use crate::components::base_menu::{apply_menu_activation, handle_menu_dismiss, sync_menu_panels};
+use crate::components::helper::NoAction;
+use crate::plugin::UiState;
+
+/// Opens and closes dropdown menus from their triggers. The menu items
+/// themselves are driven by the
+/// [`BaseMenuPlugin`](crate::components::base_menu::BaseMenuPlugin).
+pub struct DropdownMenuPlugin;
+
+impl Plugin for DropdownMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                handle_dropdown_menu_trigger,
+                handle_dropdown_menu_trigger_keyboard,
+            )
+                .after(handle_menu_dismiss)
+                .before(sync_menu_panels)
+                .run_if(in_state(UiState::Ready)),
+        );
+    }
+}
+
+/// Registers [`DropdownMenuItemSelected<A>`] for the action type `A`.
+/// Add one per action type used with
+/// [`DropdownMenuBuilder`](super::DropdownMenuBuilder).
+pub struct DropdownMenuActionPlugin<A: Component + Clone + Send + Sync = NoAction>(PhantomData<A>);
+
+impl<A: Component + Clone + Send + Sync> Default for DropdownMenuActionPlugin<A> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A: Component + Clone + Send + Sync> Plugin for DropdownMenuActionPlugin<A> {
+    fn build(&self, app: &mut App) {
+        app.add_event::<DropdownMenuItemSelected<A>>().add_systems(
+            Update,
+            send_dropdown_menu_selection::<A>
+                .after(apply_menu_activation)
+                .run_if(in_state(UiState::Ready)),
+        );
+    }
+}
@@ -1,50 +1,63 @@
 use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
 
 use crate::theme::UiTheme;
 
-/// Style for the dropdown menu content container.
+/// Style for the dropdown menu trigger button.
 #[derive(Bundle, Clone, Debug)]
-pub struct DropdownMenuStyle {
+pub struct DropdownMenuTriggerStyle {
     pub node: Node,
     pub background_color: BackgroundColor,
     pub border_color: BorderColor,
     pub border_radius: BorderRadius,
 }
 
-impl DropdownMenuStyle {
+impl DropdownMenuTriggerStyle {
     pub fn new(theme: &UiTheme) -> Self {
-        DropdownMenuStyle {
+        DropdownMenuTriggerStyle {
             node: Node {
                 display: Display::Flex,
-                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                padding: UiRect::axes(
+                    Val::Px(theme.layout.padding.sm),
+                    Val::Px(theme.layout.padding.xs),
+                ),
+                border: UiRect::all(Val::Px(1.0)),
                 ..default()
             },
-            background_color: BackgroundColor(theme.color.gray.step02),
+            background_color: BackgroundColor(theme.color.gray.step03),
             border_color: BorderColor(theme.color.gray.step06),
             border_radius: BorderRadius::all(Val::Px(theme.layout.radius.sm)),
         }
     }
 }
 
-/// Style for dropdown menu items.
+/// Style for the dropdown menu content container.
 #[derive(Bundle, Clone, Debug)]
-pub struct DropdownMenuItemStyle {
+pub struct DropdownMenuStyle {
     pub node: Node,
     pub background_color: BackgroundColor,
+    pub border_color: BorderColor,
     pub border_radius: BorderRadius,
+    pub focus_policy: FocusPolicy,
 }
 
-impl DropdownMenuItemStyle {
+impl DropdownMenuStyle {
     pub fn new(theme: &UiTheme) -> Self {
-        DropdownMenuItemStyle {
+        DropdownMenuStyle {
             node: Node {
                 display: Display::Flex,
-                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                min_width: Val::Px(180.0),
                 padding: UiRect::all(Val::Px(theme.layout.padding.xs)),
+                border: UiRect::all(Val::Px(1.0)),
                 ..default()
             },
-            background_color: BackgroundColor(theme.color.gray.step03),
-            border_radius: BorderRadius::all(Val::Px(theme.layout.radius.xs)),
+            background_color: BackgroundColor(theme.color.gray.step02),
+            border_color: BorderColor(theme.color.gray.step06),
+            border_radius: BorderRadius::all(Val::Px(theme.layout.radius.sm)),
+            focus_policy: FocusPolicy::Block,
         }
     }
 }
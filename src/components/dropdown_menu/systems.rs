@@ -0,0 +1,114 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::input_focus::InputFocus;
+use bevy::prelude::*;
+
+use super::*;
+use crate::components::base_menu::{
+    enabled_items, MenuContentState, MenuItem, MenuItemActivatedEvent,
+};
+use crate::components::disabled::InheritedDisabled;
+use crate::components::portal::PortalHierarchy;
+
+/// Menu panel belonging to `trigger`.
+fn content_of(
+    trigger: Entity,
+    hierarchy: &PortalHierarchy,
+    contents: &Query<(), With<DropdownMenuContentMarker>>,
+) -> Option<Entity> {
+    let root = hierarchy.parent(trigger)?;
+    hierarchy.children(root).find(|e| contents.contains(*e))
+}
+
+/// Toggles the menu when its trigger is pressed.
+pub fn handle_dropdown_menu_trigger(
+    triggers: Query<
+        (Entity, &Interaction),
+        (
+            With<DropdownMenuTriggerMarker>,
+            Changed<Interaction>,
+            Without<InheritedDisabled>,
+        ),
+    >,
+    contents: Query<(), With<DropdownMenuContentMarker>>,
+    hierarchy: PortalHierarchy,
+    mut panels: Query<&mut MenuContentState>,
+) {
+    for (trigger, interaction) in triggers.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(mut state) =
+            content_of(trigger, &hierarchy, &contents).and_then(|c| panels.get_mut(c).ok())
+        else {
+            continue;
+        };
+        state.open = !state.open;
+        state.highlighted = None;
+    }
+}
+
+/// Enter, Space and Arrow Down on the focused trigger open the menu with
+/// the first item highlighted, Arrow Up with the last one.
+pub fn handle_dropdown_menu_trigger_keyboard(
+    focus: Res<InputFocus>,
+    mut key_events: EventReader<KeyboardInput>,
+    triggers: Query<(), (With<DropdownMenuTriggerMarker>, Without<InheritedDisabled>)>,
+    contents: Query<(), With<DropdownMenuContentMarker>>,
+    hierarchy: PortalHierarchy,
+    mut panels: Query<&mut MenuContentState>,
+    items: Query<&MenuItem>,
+    children: Query<&Children>,
+) {
+    let Some(content) = focus
+        .get()
+        .filter(|e| triggers.contains(*e))
+        .and_then(|trigger| content_of(trigger, &hierarchy, &contents))
+    else {
+        key_events.clear();
+        return;
+    };
+
+    for event in key_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        let list = enabled_items(content, &children, &items);
+        let highlighted = match event.logical_key {
+            Key::Enter | Key::Space | Key::ArrowDown => list.first().copied(),
+            Key::ArrowUp => list.last().copied(),
+            _ => continue,
+        };
+        if let Ok(mut state) = panels.get_mut(content) {
+            if !state.open {
+                state.open = true;
+                state.highlighted = highlighted;
+            }
+        }
+    }
+}
+
+/// Turns chosen items of dropdown menus into typed
+/// [`DropdownMenuItemSelected<A>`] events.
+pub fn send_dropdown_menu_selection<A: Component + Clone + Send + Sync>(
+    mut activated: EventReader<MenuItemActivatedEvent>,
+    contents: Query<(), With<DropdownMenuContentMarker>>,
+    hierarchy: PortalHierarchy,
+    items: Query<(&MenuItem, Option<&A>)>,
+    mut selected: EventWriter<DropdownMenuItemSelected<A>>,
+) {
+    for event in activated.read() {
+        if !contents.contains(event.menu) {
+            continue;
+        }
+        let Ok((item, action)) = items.get(event.item) else {
+            continue;
+        };
+        selected.write(DropdownMenuItemSelected {
+            source_entity: event.item,
+            menu: hierarchy.parent(event.menu).unwrap_or(event.menu),
+            action_id: action.cloned(),
+            checked: item.checked(),
+        });
+    }
+}
@@ -0,0 +1,220 @@
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+
+use super::*;
+use crate::components::helper::UiBuilder;
+use crate::components::label::LabelBuilder;
+use crate::theme::UiTheme;
+
+/// Spawns the control of a field and returns the entity carrying its state.
+pub type FieldControlFn =
+    Box<dyn FnOnce(&mut ChildSpawnerCommands, &UiTheme, &Handle<Font>) -> Entity + Send + Sync>;
+
+/// Arrangement of label and control.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FieldLayout {
+    /// Label above the control (text inputs, selects).
+    #[default]
+    Stacked,
+    /// Control followed by the label (checkboxes, switches).
+    Inline,
+}
+
+/// Builder wrapping a control with label, description and error text.
+///
+/// The label is linked to the control: clicking it toggles checkboxes and
+/// switches or focuses the control, and its text becomes the control's
+/// [`AccessibleName`]. An empty label leaves the label out. Errors are
+/// shown by setting [`FieldState::error`].
+///
+/// ```ignore
+/// FieldBuilder::new("Newsletter", |p, theme, _| SwitchBuilder::new().spawn(p, theme).id())
+///     .description("One mail per month.")
+///     .inline()
+///     .spawn(parent, &theme, &font);
+/// ```
+pub struct FieldBuilder {
+    label: String,
+    control: FieldControlFn,
+    description: Option<String>,
+    error: Option<String>,
+    required: bool,
+    layout: FieldLayout,
+}
+
+impl FieldBuilder {
+    pub fn new<F>(label: impl Into<String>, control: F) -> Self
+    where
+        F: FnOnce(&mut ChildSpawnerCommands, &UiTheme, &Handle<Font>) -> Entity
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self::from_boxed(label, Box::new(control))
+    }
+
+    /// Same as [`new`](Self::new) for an already boxed control.
+    pub fn from_boxed(label: impl Into<String>, control: FieldControlFn) -> Self {
+        Self {
+            label: label.into(),
+            control,
+            description: None,
+            error: None,
+            required: false,
+            layout: FieldLayout::Stacked,
+        }
+    }
+
+    /// Help text below the control.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Initial error message.
+    pub fn error(mut self, error: impl Into<String>) -> Self {
+        self.error = Some(error.into());
+        self
+    }
+
+    /// Shows the required indicator behind the label.
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    pub fn layout(mut self, layout: FieldLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Shorthand for [`FieldLayout::Inline`].
+    pub fn inline(self) -> Self {
+        self.layout(FieldLayout::Inline)
+    }
+}
+
+impl<'w, 's> UiBuilder<'w, 's> for FieldBuilder {
+    type Output = Entity;
+
+    fn spawn(
+        self,
+        parent: &'s mut ChildSpawnerCommands<'w>,
+        theme: &UiTheme,
+        font: &Handle<Font>,
+    ) -> Self::Output {
+        let label_text = self.label;
+        let required = self.required;
+        let mut control = Entity::PLACEHOLDER;
+        let mut label = Entity::PLACEHOLDER;
+
+        let mut root = parent.spawn((FieldMarker, FieldStyle::new(theme), Name::new("Field")));
+        root.with_children(|fc| match self.layout {
+            FieldLayout::Stacked => {
+                if !label_text.is_empty() {
+                    label = spawn_field_label(fc, theme, font, &label_text, required);
+                }
+                control = (self.control)(fc, theme, font);
+            }
+            FieldLayout::Inline => {
+                fc.spawn(FieldStyle::row(theme)).with_children(|row| {
+                    control = (self.control)(row, theme, font);
+                    if !label_text.is_empty() {
+                        label = spawn_field_label(row, theme, font, &label_text, required);
+                    }
+                });
+            }
+        });
+
+        root.with_children(|fc| {
+            if let Some(description) = &self.description {
+                let entity = LabelBuilder::new(description.clone())
+                    .font_size(theme.font.size.sm)
+                    .color(theme.color.gray.step11)
+                    .spawn(fc, theme, font);
+                fc.commands().entity(entity).insert(FieldDescriptionMarker);
+            }
+
+            let display = if self.error.is_some() {
+                Display::Flex
+            } else {
+                Display::None
+            };
+            fc.spawn((
+                FieldErrorMarker,
+                Node {
+                    display,
+                    ..default()
+                },
+            ))
+            .with_children(|ec| {
+                let _ = LabelBuilder::new(self.error.clone().unwrap_or_default())
+                    .font_size(theme.font.size.sm)
+                    .color(theme.color.tomato.step11)
+                    .spawn(ec, theme, font);
+            });
+        });
+
+        root.insert(FieldState {
+            control,
+            required,
+            error: self.error,
+        });
+        let id = root.id();
+        if label != Entity::PLACEHOLDER {
+            let mut commands = root.commands();
+            commands.entity(label).insert(FieldLabelFor(control));
+            commands
+                .entity(control)
+                .insert(AccessibleName(label_text_for(&label_text, required)));
+        }
+        id
+    }
+}
+
+/// Clickable label with optional required indicator.
+fn spawn_field_label(
+    parent: &mut ChildSpawnerCommands,
+    theme: &UiTheme,
+    font: &Handle<Font>,
+    text: &str,
+    required: bool,
+) -> Entity {
+    let mut group = parent.spawn((
+        Node {
+            display: Display::Flex,
+            column_gap: Val::Px(2.0),
+            ..default()
+        },
+        Interaction::default(),
+        FocusPolicy::Block,
+    ));
+    group.with_children(|gc| {
+        let _ = LabelBuilder::new(text)
+            .font_size(theme.font.size.sm)
+            .color(theme.color.gray.step12)
+            .spawn(gc, theme, font);
+        if required {
+            gc.spawn((
+                FieldRequiredMarker,
+                Text::new("*"),
+                TextFont {
+                    font: font.clone(),
+                    font_size: theme.font.size.sm,
+                    ..default()
+                },
+                TextColor(theme.color.tomato.step11),
+                FocusPolicy::Pass,
+            ));
+        }
+    });
+    group.id()
+}
+
+fn label_text_for(label: &str, required: bool) -> String {
+    if required {
+        format!("{label} (required)")
+    } else {
+        label.to_string()
+    }
+}
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct FieldMarker;
+
+/// Control, requirement and current error of a field.
+///
+/// Set `error` to show a message in the error slot; text inputs are marked
+/// with [`TextFieldInvalid`](crate::components::text_field::TextFieldInvalid)
+/// at the same time.
+#[derive(Component, Debug, Clone)]
+pub struct FieldState {
+    pub control: Entity,
+    pub required: bool,
+    pub error: Option<String>,
+}
+
+/// Label that forwards its clicks to the field's control: checkboxes and
+/// switches toggle, everything else receives focus.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct FieldLabelFor(pub Entity);
+
+/// Asterisk behind the label of a required field.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct FieldRequiredMarker;
+
+/// Help text below the control.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct FieldDescriptionMarker;
+
+/// Error slot below the control, hidden while [`FieldState::error`] is empty.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct FieldErrorMarker;
+
+/// Name announced by screen readers for an entity that has no text of its
+/// own, e.g. the control of a field labelled by a separate text.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct AccessibleName(pub String);
@@ -0,0 +1,14 @@
+mod builder;
+mod components;
+mod plugin;
+mod style;
+mod systems;
+
+pub use builder::{FieldBuilder, FieldControlFn, FieldLayout};
+pub use components::{
+    AccessibleName, FieldDescriptionMarker, FieldErrorMarker, FieldLabelFor, FieldMarker,
+    FieldRequiredMarker, FieldState,
+};
+pub use plugin::FieldPlugin;
+pub use style::FieldStyle;
+pub use systems::{forward_field_label_clicks, sync_accessible_names, update_field_errors};
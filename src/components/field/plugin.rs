@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+
+use super::systems::*;
+use crate::components::roving_focus::focus_roving_item_on_press;
+use crate::plugin::UiState;
+
+/// Plugin for [`FieldBuilder`](super::FieldBuilder).
+pub struct FieldPlugin;
+
+impl Plugin for FieldPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                forward_field_label_clicks.after(focus_roving_item_on_press),
+                update_field_errors,
+            )
+                .run_if(in_state(UiState::Ready)),
+        )
+        .add_systems(
+            PostUpdate,
+            sync_accessible_names.run_if(in_state(UiState::Ready)),
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+use crate::theme::UiTheme;
+
+/// Column holding label row, control, description and error.
+#[derive(Bundle, Clone, Debug)]
+pub struct FieldStyle {
+    pub node: Node,
+}
+
+impl FieldStyle {
+    pub fn new(theme: &UiTheme) -> Self {
+        Self {
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::FlexStart,
+                row_gap: Val::Px(theme.layout.gap.xs),
+                ..default()
+            },
+        }
+    }
+
+    /// Row with label and required indicator; in the inline layout the
+    /// control comes first.
+    pub fn row(theme: &UiTheme) -> Node {
+        Node {
+            display: Display::Flex,
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(theme.layout.gap.sm),
+            ..default()
+        }
+    }
+}
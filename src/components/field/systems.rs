@@ -0,0 +1,126 @@
+use accesskit::{Node as AccessKitNode, Role};
+use bevy::a11y::AccessibilityNode;
+use bevy::input_focus::tab_navigation::TabIndex;
+use bevy::input_focus::InputFocus;
+use bevy::prelude::*;
+
+use super::{AccessibleName, FieldErrorMarker, FieldLabelFor, FieldState};
+use crate::components::checkbox::{CheckboxChangedEvent, CheckboxState};
+use crate::components::disabled::InheritedDisabled;
+use crate::components::switch::{SwitchChangedEvent, SwitchState};
+use crate::components::text_field::{TextFieldInvalid, TextFieldState};
+
+/// Forwards clicks on a field label to its control.
+///
+/// Checkboxes and switches toggle (with their usual change event), other
+/// controls receive focus, either themselves or their first focusable
+/// descendant.
+pub fn forward_field_label_clicks(
+    labels: Query<(&Interaction, &FieldLabelFor), Changed<Interaction>>,
+    enabled: Query<(), Without<InheritedDisabled>>,
+    mut checkboxes: Query<&mut CheckboxState>,
+    mut switches: Query<&mut SwitchState>,
+    focusable: Query<(), (With<TabIndex>, Without<InheritedDisabled>)>,
+    children: Query<&Children>,
+    mut focus: ResMut<InputFocus>,
+    mut checkbox_events: EventWriter<CheckboxChangedEvent>,
+    mut switch_events: EventWriter<SwitchChangedEvent>,
+) {
+    for (interaction, target) in labels.iter() {
+        let control = target.0;
+        if *interaction != Interaction::Pressed || !enabled.contains(control) {
+            continue;
+        }
+        if let Ok(mut state) = checkboxes.get_mut(control) {
+            if !state.disabled {
+                state.checked = !state.checked;
+                checkbox_events.write(CheckboxChangedEvent {
+                    checkbox_entity: control,
+                    is_checked: state.checked,
+                });
+            }
+        } else if let Ok(mut state) = switches.get_mut(control) {
+            if !state.disabled {
+                state.checked = !state.checked;
+                switch_events.write(SwitchChangedEvent {
+                    switch_entity: control,
+                    is_checked: state.checked,
+                });
+            }
+        } else if let Some(target) = std::iter::once(control)
+            .chain(children.iter_descendants(control))
+            .find(|e| focusable.contains(*e))
+        {
+            focus.set(target);
+        }
+    }
+}
+
+/// Shows [`FieldState::error`] in the error slot and marks text inputs as
+/// invalid while an error is set.
+pub fn update_field_errors(
+    mut commands: Commands,
+    fields: Query<(Entity, &FieldState), Changed<FieldState>>,
+    children: Query<&Children>,
+    mut slots: Query<&mut Node, With<FieldErrorMarker>>,
+    mut texts: Query<&mut Text>,
+    inputs: Query<(), With<TextFieldState>>,
+) {
+    for (entity, state) in fields.iter() {
+        let message = state.error.as_deref().unwrap_or_default();
+        for slot in children.iter_descendants(entity) {
+            let Ok(mut node) = slots.get_mut(slot) else {
+                continue;
+            };
+            let display = if message.is_empty() {
+                Display::None
+            } else {
+                Display::Flex
+            };
+            if node.display != display {
+                node.display = display;
+            }
+            for part in children.iter_descendants(slot) {
+                if let Ok(mut text) = texts.get_mut(part) {
+                    if text.0 != message {
+                        text.0 = message.to_string();
+                    }
+                }
+            }
+        }
+
+        if inputs.contains(state.control) {
+            if message.is_empty() {
+                commands.entity(state.control).remove::<TextFieldInvalid>();
+            } else {
+                commands.entity(state.control).insert(TextFieldInvalid);
+            }
+        }
+    }
+}
+
+/// Applies [`AccessibleName`] to the accessibility node of its entity.
+///
+/// Runs every frame because bevy recomputes button labels from their text
+/// children, which would drop the name again.
+pub fn sync_accessible_names(
+    mut commands: Commands,
+    mut names: Query<(Entity, &AccessibleName, Option<&mut AccessibilityNode>)>,
+) {
+    for (entity, name, node) in names.iter_mut() {
+        match node {
+            Some(mut node) => {
+                if node.label() != Some(name.0.as_str()) {
+                    node.set_label(name.0.as_str());
+                }
+            }
+            None => {
+                let mut node = AccessKitNode::new(Role::GenericContainer);
+                node.set_label(name.0.as_str());
+                commands
+                    .entity(entity)
+                    .insert(AccessibilityNode::from(node));
+            }
+        }
+    }
+}
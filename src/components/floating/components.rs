@@ -0,0 +1,138 @@
+use bevy::prelude::*;
+
+use crate::components::direction_provider::RuntimePosition;
+
+/// Side of the anchor the floating content is placed on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FloatingSide {
+    Top,
+    Right,
+    #[default]
+    Bottom,
+    Left,
+}
+
+impl FloatingSide {
+    pub fn opposite(self) -> Self {
+        match self {
+            FloatingSide::Top => FloatingSide::Bottom,
+            FloatingSide::Right => FloatingSide::Left,
+            FloatingSide::Bottom => FloatingSide::Top,
+            FloatingSide::Left => FloatingSide::Right,
+        }
+    }
+
+    /// `true` for top and bottom, where alignment runs horizontally.
+    pub fn is_vertical(self) -> bool {
+        matches!(self, FloatingSide::Top | FloatingSide::Bottom)
+    }
+}
+
+/// Alignment of the content along the chosen side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FloatingAlign {
+    Start,
+    #[default]
+    Center,
+    End,
+}
+
+/// Positions an overlay next to an anchor entity.
+///
+/// The content becomes absolutely positioned and follows the anchor's
+/// `ComputedNode`/`GlobalTransform` every frame. With `avoid_collisions`
+/// it flips to the opposite side when there is more room there and is
+/// shifted along the side to stay inside the window. The side actually
+/// used is written to [`FloatingPlacement`].
+///
+/// ```ignore
+/// commands.spawn((
+///     Floating::new(trigger).side(FloatingSide::Top).side_offset(4.0),
+///     content_style,
+/// ));
+/// ```
+#[derive(Component, Debug, Clone, Copy)]
+#[require(FloatingPlacement, RuntimePosition)]
+pub struct Floating {
+    pub anchor: Entity,
+    pub side: FloatingSide,
+    pub align: FloatingAlign,
+    /// Distance between anchor and content, in logical pixels.
+    pub side_offset: f32,
+    /// Shift along the side, in logical pixels.
+    pub align_offset: f32,
+    pub avoid_collisions: bool,
+    /// Minimum distance to the window edges when avoiding collisions.
+    pub collision_padding: f32,
+    /// Makes the content at least as wide as the anchor (selects,
+    /// comboboxes).
+    pub match_anchor_width: bool,
+    /// Anchors to this point (logical window coordinates) instead of the
+    /// anchor's rectangle, e.g. a context menu at the pointer.
+    pub point: Option<Vec2>,
+}
+
+impl Floating {
+    pub fn new(anchor: Entity) -> Self {
+        Self {
+            anchor,
+            side: FloatingSide::Bottom,
+            align: FloatingAlign::Center,
+            side_offset: 4.0,
+            align_offset: 0.0,
+            avoid_collisions: true,
+            collision_padding: 8.0,
+            match_anchor_width: false,
+            point: None,
+        }
+    }
+
+    pub fn side(mut self, side: FloatingSide) -> Self {
+        self.side = side;
+        self
+    }
+
+    pub fn align(mut self, align: FloatingAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    pub fn side_offset(mut self, offset: f32) -> Self {
+        self.side_offset = offset;
+        self
+    }
+
+    pub fn align_offset(mut self, offset: f32) -> Self {
+        self.align_offset = offset;
+        self
+    }
+
+    pub fn avoid_collisions(mut self, avoid: bool) -> Self {
+        self.avoid_collisions = avoid;
+        self
+    }
+
+    pub fn collision_padding(mut self, padding: f32) -> Self {
+        self.collision_padding = padding;
+        self
+    }
+
+    pub fn match_anchor_width(mut self, flag: bool) -> Self {
+        self.match_anchor_width = flag;
+        self
+    }
+
+    pub fn point(mut self, point: Option<Vec2>) -> Self {
+        self.point = point;
+        self
+    }
+}
+
+/// Where a [`Floating`] element ended up after collision handling.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Default)]
+pub struct FloatingPlacement {
+    /// Side after flipping; differs from [`Floating::side`] when flipped.
+    pub side: FloatingSide,
+    /// Top-left corner in window coordinates (logical pixels).
+    pub position: Vec2,
+}
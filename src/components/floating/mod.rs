@@ -0,0 +1,9 @@
+mod components;
+mod placement;
+mod plugin;
+mod systems;
+
+pub use components::*;
+pub use placement::compute_floating_position;
+pub use plugin::FloatingPlugin;
+pub use systems::update_floating_positions;
@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+
+use super::{Floating, FloatingAlign, FloatingSide};
+
+/// Top-left corner for content of `size` placed at `anchor` inside
+/// `bounds`, together with the side that was used.
+///
+/// All values are logical pixels in window coordinates (y pointing down).
+pub fn compute_floating_position(
+    floating: &Floating,
+    anchor: Rect,
+    size: Vec2,
+    bounds: Rect,
+) -> (Vec2, FloatingSide) {
+    let mut side = floating.side;
+    let mut position = place(floating, side, anchor, size);
+
+    if floating.avoid_collisions {
+        let inner = inset(bounds, floating.collision_padding);
+        // Umklappen, wenn gegenüber weniger übersteht
+        let overflow = main_overflow(side, position, size, inner);
+        if overflow > 0.0 {
+            let flipped = place(floating, side.opposite(), anchor, size);
+            if main_overflow(side.opposite(), flipped, size, inner) < overflow {
+                side = side.opposite();
+                position = flipped;
+            }
+        }
+        // Entlang der Seite verschieben, damit der Inhalt im Fenster bleibt
+        if side.is_vertical() {
+            position.x = shift(position.x, size.x, inner.min.x, inner.max.x);
+        } else {
+            position.y = shift(position.y, size.y, inner.min.y, inner.max.y);
+        }
+    }
+
+    (position, side)
+}
+
+fn place(floating: &Floating, side: FloatingSide, anchor: Rect, size: Vec2) -> Vec2 {
+    let aligned = |start: f32, end: f32, length: f32| {
+        let base = match floating.align {
+            FloatingAlign::Start => start,
+            FloatingAlign::Center => (start + end - length) / 2.0,
+            FloatingAlign::End => end - length,
+        };
+        base + floating.align_offset
+    };
+    let offset = floating.side_offset;
+    match side {
+        FloatingSide::Top => Vec2::new(
+            aligned(anchor.min.x, anchor.max.x, size.x),
+            anchor.min.y - offset - size.y,
+        ),
+        FloatingSide::Bottom => Vec2::new(
+            aligned(anchor.min.x, anchor.max.x, size.x),
+            anchor.max.y + offset,
+        ),
+        FloatingSide::Left => Vec2::new(
+            anchor.min.x - offset - size.x,
+            aligned(anchor.min.y, anchor.max.y, size.y),
+        ),
+        FloatingSide::Right => Vec2::new(
+            anchor.max.x + offset,
+            aligned(anchor.min.y, anchor.max.y, size.y),
+        ),
+    }
+}
+
+/// How far the content sticks out of `bounds` on the far end of `side`.
+fn main_overflow(side: FloatingSide, position: Vec2, size: Vec2, bounds: Rect) -> f32 {
+    let overflow = match side {
+        FloatingSide::Top => bounds.min.y - position.y,
+        FloatingSide::Bottom => position.y + size.y - bounds.max.y,
+        FloatingSide::Left => bounds.min.x - position.x,
+        FloatingSide::Right => position.x + size.x - bounds.max.x,
+    };
+    overflow.max(0.0)
+}
+
+/// Clamps `start` so that `length` fits between `min` and `max`; content
+/// larger than the range sticks to `min`.
+fn shift(start: f32, length: f32, min: f32, max: f32) -> f32 {
+    start.min(max - length).max(min)
+}
+
+fn inset(rect: Rect, padding: f32) -> Rect {
+    let padding = padding
+        .min(rect.width() / 2.0)
+        .min(rect.height() / 2.0)
+        .max(0.0);
+    Rect::from_corners(rect.min + padding, rect.max - padding)
+}
@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+
+use super::systems::update_floating_positions;
+
+/// Plugin that keeps [`Floating`](super::Floating) overlays next to their
+/// anchors.
+pub struct FloatingPlugin;
+
+impl Plugin for FloatingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            update_floating_positions.before(UiSystem::Layout),
+        );
+    }
+}
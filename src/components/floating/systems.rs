@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+use bevy::ui::ComputedNodeTarget;
+
+use super::{compute_floating_position, Floating, FloatingPlacement};
+
+/// Screen rectangle of a UI node in logical pixels.
+fn logical_rect(node: &ComputedNode, transform: &GlobalTransform) -> Rect {
+    let scale = node.inverse_scale_factor();
+    let center = transform.translation().truncate();
+    Rect::from_center_size(center * scale, node.size() * scale)
+}
+
+/// Moves every [`Floating`] element next to its anchor.
+///
+/// Runs before the UI layout and uses the layout of the previous frame, so
+/// content follows a moving anchor with the usual one-frame delay of UI
+/// positioning. Nodes are only written when the position changes.
+pub fn update_floating_positions(
+    mut floatings: Query<(
+        &Floating,
+        &mut Node,
+        &mut FloatingPlacement,
+        &ComputedNode,
+        &ComputedNodeTarget,
+        Option<&ChildOf>,
+    )>,
+    nodes: Query<(&ComputedNode, &GlobalTransform)>,
+) {
+    let rect_of = |entity: Entity| {
+        nodes
+            .get(entity)
+            .ok()
+            .map(|(node, transform)| (node, logical_rect(node, transform)))
+    };
+
+    for (floating, mut node, mut placement, computed, target, parent) in floatings.iter_mut() {
+        let anchor = match floating.point {
+            Some(point) => Rect::from_corners(point, point),
+            None => match rect_of(floating.anchor) {
+                Some((_, rect)) => rect,
+                None => continue,
+            },
+        };
+        let scale = computed.inverse_scale_factor();
+        let size = computed.size() * scale;
+        let bounds = Rect::from_corners(Vec2::ZERO, target.physical_size().as_vec2() * scale);
+        let (position, side) = compute_floating_position(floating, anchor, size, bounds);
+
+        let wanted = FloatingPlacement { side, position };
+        if *placement != wanted {
+            *placement = wanted;
+        }
+
+        // Absolute Positionen beziehen sich auf die Innenkante des Elternknotens
+        let origin = parent
+            .and_then(|p| rect_of(p.parent()))
+            .map(|(parent_node, rect)| {
+                let border = parent_node.border();
+                rect.min + Vec2::new(border.left, border.top) * parent_node.inverse_scale_factor()
+            })
+            .unwrap_or(Vec2::ZERO);
+        let local = position - origin;
+        if floating.match_anchor_width {
+            let min_width = Val::Px(anchor.width());
+            if node.min_width != min_width {
+                node.min_width = min_width;
+            }
+        }
+        let (left, top) = (Val::Px(local.x), Val::Px(local.y));
+        if node.position_type != PositionType::Absolute
+            || node.left != left
+            || node.top != top
+            || node.right != Val::Auto
+            || node.bottom != Val::Auto
+        {
+            node.position_type = PositionType::Absolute;
+            node.left = left;
+            node.top = top;
+            node.right = Val::Auto;
+            node.bottom = Val::Auto;
+        }
+    }
+}
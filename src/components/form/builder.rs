@@ -0,0 +1,195 @@
+use bevy::prelude::*;
+
+use super::*;
+use crate::components::button::ButtonBuilder;
+use crate::components::field::{FieldBuilder, FieldControlFn, FieldLayout};
+use crate::components::helper::{NoAction, UiBuilder};
+use crate::theme::UiTheme;
+
+/// One field of a [`FormBuilder`]: label, control and validators.
+///
+/// The closure spawns the control and returns the entity that carries its
+/// state, e.g. `|p, theme, font| TextFieldBuilder::new().spawn(p, theme, font)`.
+/// The field is laid out by [`FieldBuilder`].
+pub struct FormFieldDef {
+    name: String,
+    label: Option<String>,
+    description: Option<String>,
+    layout: FieldLayout,
+    required: bool,
+    validators: Vec<FormValidator>,
+    control: FieldControlFn,
+}
+
+impl FormFieldDef {
+    pub fn new<F>(name: impl Into<String>, control: F) -> Self
+    where
+        F: FnOnce(&mut ChildSpawnerCommands, &UiTheme, &Handle<Font>) -> Entity
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self {
+            name: name.into(),
+            label: None,
+            description: None,
+            layout: FieldLayout::Stacked,
+            required: false,
+            validators: Vec::new(),
+            control: Box::new(control),
+        }
+    }
+
+    /// Text above the control.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Help text below the control.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Control followed by the label, for checkboxes and switches.
+    pub fn inline(mut self) -> Self {
+        self.layout = FieldLayout::Inline;
+        self
+    }
+
+    pub fn validator(mut self, validator: FormValidator) -> Self {
+        self.validators.push(validator);
+        self
+    }
+
+    /// Adds [`FormValidator::required`] and the required indicator.
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self.validator(FormValidator::required())
+    }
+}
+
+/// Builder for a form that collects the values of its fields.
+///
+/// Fields are registered by name through [`FormField`]; the form reads the
+/// value straight from the widget state (text fields, number fields,
+/// checkboxes, switches, selects, comboboxes, radio, checkbox and toggle
+/// groups, ...).
+/// Submitting via the submit button or Enter in a text field runs all
+/// validators, shows their messages below the fields and sends a
+/// [`FormSubmittedEvent`] only if everything is valid.
+pub struct FormBuilder {
+    fields: Vec<FormFieldDef>,
+    validators: Vec<(String, FormValidator)>,
+    submit_label: Option<String>,
+    width: Option<Val>,
+}
+
+impl FormBuilder {
+    pub fn new() -> Self {
+        Self {
+            fields: Vec::new(),
+            validators: Vec::new(),
+            submit_label: Some("Submit".to_string()),
+            width: None,
+        }
+    }
+
+    pub fn field(mut self, field: FormFieldDef) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// Validator for a field registered outside the builder with
+    /// [`FormField`].
+    pub fn validator(mut self, field: impl Into<String>, validator: FormValidator) -> Self {
+        self.validators.push((field.into(), validator));
+        self
+    }
+
+    /// Text of the submit button.
+    pub fn submit_label(mut self, label: impl Into<String>) -> Self {
+        self.submit_label = Some(label.into());
+        self
+    }
+
+    /// Leaves out the submit button, e.g. when an own button carries
+    /// [`FormSubmitMarker`].
+    pub fn without_submit(mut self) -> Self {
+        self.submit_label = None;
+        self
+    }
+
+    pub fn width(mut self, width: Val) -> Self {
+        self.width = Some(width);
+        self
+    }
+}
+
+impl Default for FormBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'w, 's> UiBuilder<'w, 's> for FormBuilder {
+    type Output = Entity;
+
+    fn spawn(
+        self,
+        parent: &'s mut ChildSpawnerCommands<'w>,
+        theme: &UiTheme,
+        font: &Handle<Font>,
+    ) -> Self::Output {
+        let mut state = FormState::default();
+        for (name, validator) in self.validators {
+            state.add_validator(name, validator);
+        }
+        for field in &self.fields {
+            for validator in &field.validators {
+                state.add_validator(field.name.clone(), validator.clone());
+            }
+        }
+
+        let mut style = FormStyle::new(theme);
+        if let Some(width) = self.width {
+            style.node.width = width;
+        }
+
+        let mut root = parent.spawn((FormMarker, style, state, Name::new("Form")));
+        root.with_children(|rc| {
+            for field in self.fields {
+                let name = field.name;
+                let control = field.control;
+                let mut builder =
+                    FieldBuilder::new(field.label.unwrap_or_default(), move |p, theme, font| {
+                        let entity = control(p, theme, font);
+                        p.commands().entity(entity).insert(FormField(name));
+                        entity
+                    })
+                    .required(field.required)
+                    .layout(field.layout);
+                if let Some(description) = field.description {
+                    builder = builder.description(description);
+                }
+                let _ = builder.spawn(rc, theme, font);
+            }
+
+            if let Some(label) = self.submit_label {
+                rc.spawn(Node {
+                    display: Display::Flex,
+                    justify_content: JustifyContent::FlexEnd,
+                    ..default()
+                })
+                .with_child((
+                    ButtonBuilder::<NoAction>::new("Submit")
+                        .text(label)
+                        .build(theme, font),
+                    FormSubmitMarker,
+                ));
+            }
+        });
+        root.id()
+    }
+}
@@ -0,0 +1,49 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use super::FormValidator;
+
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct FormMarker;
+
+/// Registers a control under its form by name.
+///
+/// Put it on the entity that carries the widget state (e.g.
+/// `TextFieldState`, `CheckboxState`, `SelectState`). Controls spawned by
+/// [`FormBuilder`](super::FormBuilder) get it automatically; any other
+/// descendant of the form can be registered by inserting it. Errors are
+/// shown when the control is wrapped in a
+/// [`FieldBuilder`](crate::components::field::FieldBuilder).
+#[derive(Component, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FormField(pub String);
+
+/// Button that submits the surrounding form.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct FormSubmitMarker;
+
+/// Validators and current errors of a form.
+///
+/// Errors are shown after the first submit attempt and from then on kept
+/// up to date while the user edits the fields.
+#[derive(Component, Debug, Clone, Default)]
+pub struct FormState {
+    pub validators: HashMap<String, Vec<FormValidator>>,
+    /// First failing validator message per field name.
+    pub errors: HashMap<String, String>,
+    pub(crate) attempted: bool,
+}
+
+impl FormState {
+    /// Adds a validator for the named field.
+    pub fn add_validator(&mut self, field: impl Into<String>, validator: FormValidator) {
+        self.validators
+            .entry(field.into())
+            .or_default()
+            .push(validator);
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
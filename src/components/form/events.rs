@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+
+use super::FormValues;
+
+/// Sent when a form is submitted and every field passed its validators.
+///
+/// Invalid submissions only update the error texts and send nothing.
+#[derive(Event, Debug, Clone)]
+pub struct FormSubmittedEvent {
+    pub form: Entity,
+    pub values: FormValues,
+}
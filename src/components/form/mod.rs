@@ -0,0 +1,17 @@
+mod builder;
+mod components;
+mod events;
+mod plugin;
+mod style;
+mod systems;
+mod validation;
+mod values;
+
+pub use builder::{FormBuilder, FormFieldDef};
+pub use components::{FormField, FormMarker, FormState, FormSubmitMarker};
+pub use events::FormSubmittedEvent;
+pub use plugin::FormPlugin;
+pub use style::FormStyle;
+pub use systems::{handle_form_submit, revalidate_forms, update_form_errors, FormControls};
+pub use validation::FormValidator;
+pub use values::{FormValue, FormValues};
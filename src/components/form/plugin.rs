@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+
+use super::events::FormSubmittedEvent;
+use super::systems::*;
+use crate::components::button::handle_button_release;
+use crate::components::helper::NoAction;
+use crate::components::number_field::{sync_number_field_text, validate_number_field_input};
+use crate::components::text_field::handle_text_field_keyboard;
+use crate::plugin::UiState;
+
+/// Plugin for [`FormBuilder`](super::FormBuilder).
+pub struct FormPlugin;
+
+impl Plugin for FormPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<FormSubmittedEvent>().add_systems(
+            Update,
+            (
+                // Eingetippte Zahlen erst übernehmen, dann absenden
+                handle_form_submit
+                    .after(handle_button_release::<NoAction>)
+                    .after(handle_text_field_keyboard)
+                    .after(validate_number_field_input)
+                    .after(sync_number_field_text),
+                revalidate_forms,
+                update_form_errors,
+            )
+                .chain()
+                .run_if(in_state(UiState::Ready)),
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+
+use crate::theme::UiTheme;
+
+/// Column holding the fields and the submit button.
+#[derive(Bundle, Clone, Debug)]
+pub struct FormStyle {
+    pub node: Node,
+}
+
+impl FormStyle {
+    pub fn new(theme: &UiTheme) -> Self {
+        Self {
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Stretch,
+                row_gap: Val::Px(theme.layout.gap.base),
+                ..default()
+            },
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use super::*;
+use crate::components::button::ButtonClickedEvent;
+use crate::components::checkbox::CheckboxState;
+use crate::components::checkbox_group::CheckboxGroupState;
+use crate::components::combobox::{ComboboxInputMarker, ComboboxState};
+use crate::components::disabled::InheritedDisabled;
+use crate::components::field::FieldState;
+use crate::components::helper::NoAction;
+use crate::components::number_field::NumberFieldState;
+use crate::components::one_time_password_field::OtpFieldState;
+use crate::components::password_toggle_field::PasswordToggleFieldState;
+use crate::components::radio_group::RadioGroupState;
+use crate::components::select::SelectState;
+use crate::components::switch::SwitchState;
+use crate::components::text_field::{TextFieldState, TextSubmittedEvent};
+use crate::components::toggle::ToggleState;
+use crate::components::toggle_group::ToggleGroupState;
+
+/// Read access to the state of every widget a form can collect.
+#[derive(SystemParam)]
+pub struct FormControls<'w, 's> {
+    fields: Query<'w, 's, &'static FormField, Without<InheritedDisabled>>,
+    children: Query<'w, 's, &'static Children>,
+    texts: Query<'w, 's, &'static TextFieldState>,
+    numbers: Query<'w, 's, &'static NumberFieldState>,
+    passwords: Query<'w, 's, &'static PasswordToggleFieldState>,
+    otps: Query<'w, 's, &'static OtpFieldState>,
+    checkboxes: Query<'w, 's, &'static CheckboxState>,
+    switches: Query<'w, 's, &'static SwitchState>,
+    toggles: Query<'w, 's, &'static ToggleState>,
+    selects: Query<'w, 's, &'static SelectState>,
+    comboboxes: Query<'w, 's, &'static ComboboxState>,
+    radio_groups: Query<'w, 's, &'static RadioGroupState>,
+    checkbox_groups: Query<'w, 's, &'static CheckboxGroupState>,
+    toggle_groups: Query<'w, 's, &'static ToggleGroupState>,
+}
+
+impl FormControls<'_, '_> {
+    /// Value of the widget state on `entity`, `None` for unknown widgets.
+    pub fn value(&self, entity: Entity) -> Option<FormValue> {
+        let sorted = |values: &std::collections::HashSet<String>| {
+            let mut list: Vec<String> = values.iter().cloned().collect();
+            list.sort();
+            FormValue::List(list)
+        };
+        if let Ok(state) = self.numbers.get(entity) {
+            Some(FormValue::Number(state.value))
+        } else if let Ok(state) = self.passwords.get(entity) {
+            Some(FormValue::Text(state.password.clone()))
+        } else if let Ok(state) = self.texts.get(entity) {
+            Some(FormValue::Text(state.value.clone()))
+        } else if let Ok(state) = self.otps.get(entity) {
+            Some(FormValue::Text(state.value()))
+        } else if let Ok(state) = self.checkboxes.get(entity) {
+            Some(FormValue::Bool(state.checked))
+        } else if let Ok(state) = self.switches.get(entity) {
+            Some(FormValue::Bool(state.checked))
+        } else if let Ok(state) = self.toggles.get(entity) {
+            Some(FormValue::Bool(state.pressed))
+        } else if let Ok(state) = self.selects.get(entity) {
+            Some(FormValue::Choice(state.selected.clone()))
+        } else if let Ok(state) = self.comboboxes.get(entity) {
+            Some(FormValue::Choice(state.selected.clone()))
+        } else if let Ok(state) = self.radio_groups.get(entity) {
+            Some(FormValue::Choice(state.selected_value.clone()))
+        } else if let Ok(state) = self.checkbox_groups.get(entity) {
+            Some(sorted(&state.checked_values))
+        } else if let Ok(state) = self.toggle_groups.get(entity) {
+            Some(sorted(&state.active_values))
+        } else {
+            None
+        }
+    }
+
+    /// Values of all enabled fields below `form`. Disabled fields are left
+    /// out, like in HTML forms.
+    pub fn collect(&self, form: Entity) -> FormValues {
+        let mut values = FormValues::default();
+        for entity in self.children.iter_descendants(form) {
+            let Ok(field) = self.fields.get(entity) else {
+                continue;
+            };
+            if let Some(value) = self.value(entity) {
+                values.insert(field.0.clone(), value);
+            }
+        }
+        values
+    }
+}
+
+/// Runs the validators of `state` against `values`.
+fn validate(state: &FormState, values: &FormValues) -> HashMap<String, String> {
+    let mut errors = HashMap::new();
+    for (name, validators) in &state.validators {
+        let Some(value) = values.get(name) else {
+            continue;
+        };
+        if let Some(message) = validators.iter().find_map(|v| v.validate(value).err()) {
+            errors.insert(name.clone(), message);
+        }
+    }
+    errors
+}
+
+/// Submits forms through their submit button or Enter in one of their text
+/// fields. Sends [`FormSubmittedEvent`] when all fields are valid.
+pub fn handle_form_submit(
+    mut clicks: EventReader<ButtonClickedEvent<NoAction>>,
+    mut text_submits: EventReader<TextSubmittedEvent>,
+    submit_buttons: Query<(), With<FormSubmitMarker>>,
+    combobox_inputs: Query<(), With<ComboboxInputMarker>>,
+    parents: Query<&ChildOf>,
+    mut forms: Query<&mut FormState, (With<FormMarker>, Without<InheritedDisabled>)>,
+    controls: FormControls,
+    mut submitted: EventWriter<FormSubmittedEvent>,
+) {
+    let sources = clicks
+        .read()
+        .map(|e| e.source_entity)
+        .filter(|e| submit_buttons.contains(*e))
+        // Enter in einer Combobox wählt eine Option statt abzusenden
+        .chain(
+            text_submits
+                .read()
+                .map(|e| e.entity)
+                .filter(|e| !combobox_inputs.contains(*e)),
+        )
+        .collect::<Vec<_>>();
+
+    for source in sources {
+        let Some(form) = parents.iter_ancestors(source).find(|e| forms.contains(*e)) else {
+            continue;
+        };
+        let Ok(mut state) = forms.get_mut(form) else {
+            continue;
+        };
+        let values = controls.collect(form);
+        let errors = validate(&state, &values);
+        state.attempted = true;
+        if state.errors != errors {
+            state.errors = errors;
+        }
+        if state.is_valid() {
+            submitted.write(FormSubmittedEvent { form, values });
+        }
+    }
+}
+
+/// Keeps the error messages current after the first submit attempt, so
+/// they disappear as soon as the user fixes a field.
+pub fn revalidate_forms(
+    mut forms: Query<(Entity, &mut FormState), With<FormMarker>>,
+    controls: FormControls,
+) {
+    for (form, mut state) in forms.iter_mut() {
+        if !state.attempted {
+            continue;
+        }
+        let errors = validate(&state, &controls.collect(form));
+        if state.errors != errors {
+            state.errors = errors;
+        }
+    }
+}
+
+/// Passes the current errors to the [`FieldState`] of each registered
+/// field, which shows them below the control.
+pub fn update_form_errors(
+    forms: Query<(Entity, &FormState), (With<FormMarker>, Changed<FormState>)>,
+    children: Query<&Children>,
+    controls: Query<&FormField>,
+    mut fields: Query<&mut FieldState>,
+) {
+    for (form, state) in forms.iter() {
+        for entity in children.iter_descendants(form) {
+            let Ok(mut field) = fields.get_mut(entity) else {
+                continue;
+            };
+            let Ok(name) = controls.get(field.control) else {
+                continue;
+            };
+            let error = state.errors.get(&name.0).cloned();
+            if field.error != error {
+                field.error = error;
+            }
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use regex::Regex;
+
+use super::FormValue;
+
+type ValidateFn = dyn Fn(&FormValue) -> Result<(), String> + Send + Sync;
+
+/// Check for a single form field; `Err` carries the message shown below
+/// the field.
+///
+/// Validators run in the order they were added, the first failing one
+/// wins. Apart from [`required`](Self::required) they accept empty values,
+/// so optional fields only have to be valid once filled in.
+#[derive(Clone)]
+pub struct FormValidator(Arc<ValidateFn>);
+
+impl FormValidator {
+    /// Custom check.
+    pub fn new(f: impl Fn(&FormValue) -> Result<(), String> + Send + Sync + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+
+    /// Rejects empty text, unchecked booleans and missing choices.
+    pub fn required() -> Self {
+        Self::new(|value| {
+            if value.is_empty() {
+                Err("This field is required.".to_string())
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    /// Minimum number of characters (or list entries).
+    pub fn min_length(min: usize) -> Self {
+        Self::new(move |value| match length(value) {
+            Some(len) if len > 0 && len < min => Err(format!("Enter at least {min} characters.")),
+            _ => Ok(()),
+        })
+    }
+
+    /// Maximum number of characters (or list entries).
+    pub fn max_length(max: usize) -> Self {
+        Self::new(move |value| match length(value) {
+            Some(len) if len > max => Err(format!("Enter at most {max} characters.")),
+            _ => Ok(()),
+        })
+    }
+
+    /// Inclusive range for numbers.
+    pub fn range(min: f64, max: f64) -> Self {
+        Self::new(move |value| match value.as_number() {
+            Some(n) if n < min || n > max => Err(format!("Enter a value between {min} and {max}.")),
+            _ => Ok(()),
+        })
+    }
+
+    /// Text must match `regex` completely.
+    ///
+    /// The pattern is anchored at both ends, so every alternative is tried
+    /// against the whole text (`a|ab` accepts `"ab"`). Options set through a
+    /// `RegexBuilder` are not kept; use inline flags such as `(?i)` instead.
+    pub fn pattern(regex: Regex, message: impl Into<String>) -> Self {
+        let message = message.into();
+        let anchored = Regex::new(&format!(r"\A(?:{})\z", regex.as_str()))
+            .expect("anchoring a valid pattern keeps it valid");
+        Self::new(move |value| match value.as_str() {
+            Some(text) if !text.is_empty() => {
+                if anchored.is_match(text) {
+                    Ok(())
+                } else {
+                    Err(message.clone())
+                }
+            }
+            _ => Ok(()),
+        })
+    }
+
+    pub fn validate(&self, value: &FormValue) -> Result<(), String> {
+        (self.0)(value)
+    }
+}
+
+impl std::fmt::Debug for FormValidator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("FormValidator(..)")
+    }
+}
+
+fn length(value: &FormValue) -> Option<usize> {
+    match value {
+        FormValue::Text(text) => Some(text.chars().count()),
+        FormValue::List(items) => Some(items.len()),
+        _ => None,
+    }
+}
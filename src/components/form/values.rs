@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+
+use serde::de::value::{Error as DeError, MapDeserializer, SeqDeserializer};
+use serde::de::{DeserializeOwned, IntoDeserializer, Visitor};
+use serde::{forward_to_deserialize_any, Deserializer, Serialize};
+
+/// Value of a single form control.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum FormValue {
+    /// Checkbox, switch, toggle.
+    Bool(bool),
+    /// Number field.
+    Number(f64),
+    /// Text field, text area, password and one-time password fields.
+    Text(String),
+    /// Select and radio group; `None` while nothing is chosen.
+    Choice(Option<String>),
+    /// Checkbox group and toggle group, sorted.
+    List(Vec<String>),
+}
+
+impl FormValue {
+    /// Whether a `required` validator rejects the value. Unchecked booleans
+    /// count as empty, numbers never do.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            FormValue::Bool(checked) => !checked,
+            FormValue::Number(_) => false,
+            FormValue::Text(text) => text.trim().is_empty(),
+            FormValue::Choice(choice) => choice.is_none(),
+            FormValue::List(items) => items.is_empty(),
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            FormValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            FormValue::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Text of text inputs and the chosen value of selects and radio groups.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            FormValue::Text(text) => Some(text),
+            FormValue::Choice(choice) => choice.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Values of all registered fields of a form, keyed by field name.
+///
+/// Read single values with [`get`](Self::get) or turn the whole map into a
+/// struct with [`deserialize`](Self::deserialize):
+///
+/// ```ignore
+/// #[derive(Deserialize)]
+/// struct Settings {
+///     name: String,
+///     volume: u8,
+///     theme: Option<String>,
+///     newsletter: bool,
+/// }
+///
+/// let settings: Settings = event.values.deserialize()?;
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct FormValues(pub HashMap<String, FormValue>);
+
+impl FormValues {
+    pub fn get(&self, field: &str) -> Option<&FormValue> {
+        self.0.get(field)
+    }
+
+    pub fn insert(&mut self, field: impl Into<String>, value: FormValue) {
+        self.0.insert(field.into(), value);
+    }
+
+    /// Deserializes the values into `T`, field names become struct fields.
+    ///
+    /// Whole numbers also deserialize into integer fields, enums with unit
+    /// variants are matched by name.
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T, DeError> {
+        T::deserialize(MapDeserializer::<_, DeError>::new(
+            self.0.clone().into_iter(),
+        ))
+    }
+}
+
+impl<'de> IntoDeserializer<'de, DeError> for FormValue {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+/// Forwards integer requests to `visit_i64`/`visit_u64` when the number is
+/// whole and not negative for unsigned targets; serde's integer visitors
+/// reject floats.
+macro_rules! deserialize_integer {
+    ($($method:ident => $visit:ident as $ty:ty),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+                match self {
+                    FormValue::Number(n) if n.fract() == 0.0 && n >= <$ty>::MIN as f64 => {
+                        visitor.$visit(n as $ty)
+                    }
+                    other => other.deserialize_any(visitor),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for FormValue {
+    type Error = DeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        match self {
+            FormValue::Bool(value) => visitor.visit_bool(value),
+            FormValue::Number(value) => visitor.visit_f64(value),
+            FormValue::Text(text) | FormValue::Choice(Some(text)) => visitor.visit_string(text),
+            FormValue::Choice(None) => visitor.visit_none(),
+            FormValue::List(items) => {
+                let mut seq = SeqDeserializer::<_, DeError>::new(items.into_iter());
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        match self {
+            FormValue::Choice(None) => visitor.visit_none(),
+            FormValue::Choice(Some(text)) => visitor.visit_some(text.into_deserializer()),
+            other => visitor.visit_some(other),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        match self {
+            FormValue::Text(text) | FormValue::Choice(Some(text)) => {
+                visitor.visit_enum(text.into_deserializer())
+            }
+            other => other.deserialize_any(visitor),
+        }
+    }
+
+    deserialize_integer! {
+        deserialize_i8 => visit_i64 as i64,
+        deserialize_i16 => visit_i64 as i64,
+        deserialize_i32 => visit_i64 as i64,
+        deserialize_i64 => visit_i64 as i64,
+        deserialize_u8 => visit_u64 as u64,
+        deserialize_u16 => visit_u64 as u64,
+        deserialize_u32 => visit_u64 as u64,
+        deserialize_u64 => visit_u64 as u64,
+    }
+
+    forward_to_deserialize_any! {
+        bool f32 f64 char str string bytes byte_buf unit unit_struct
+        newtype_struct seq tuple tuple_struct map struct identifier ignored_any
+        i128 u128
+    }
+}
@@ -1,7 +1,11 @@
 use bevy::prelude::*;
 use bevy::ui::FocusPolicy;
 
+use crate::components::floating::{Floating, FloatingAlign, FloatingSide};
+use crate::components::hover_intent::HoverIntent;
 use crate::components::label::LabelBuilder;
+use crate::components::layer::{Layer, UiLayer};
+use crate::components::portal::Portal;
 use crate::theme::UiTheme;
 
 use super::{
@@ -10,10 +14,19 @@ use super::{
 };
 
 /// Builder for a simple hover card.
+///
+/// The content floats below the trigger (see [`Floating`]) and is rendered
+/// through a [`Portal`], so clipping ancestors do not cut it off. Open and
+/// close are delayed, and the pointer can move from the trigger onto the
+/// card (see [`HoverIntent`]).
 pub struct HoverCardBuilder {
     trigger: String,
     content:
         Option<Box<dyn FnOnce(&mut ChildSpawnerCommands, &UiTheme, &Handle<Font>) + Send + Sync>>,
+    side: FloatingSide,
+    align: FloatingAlign,
+    open_delay: f32,
+    close_delay: f32,
 }
 
 impl HoverCardBuilder {
@@ -22,9 +35,36 @@ impl HoverCardBuilder {
         Self {
             trigger: trigger.into(),
             content: None,
+            side: FloatingSide::Bottom,
+            align: FloatingAlign::Center,
+            open_delay: 0.7,
+            close_delay: 0.3,
         }
     }
 
+    /// Preferred side of the trigger.
+    pub fn side(mut self, side: FloatingSide) -> Self {
+        self.side = side;
+        self
+    }
+
+    pub fn align(mut self, align: FloatingAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Seconds the pointer has to rest on the trigger (default `0.7`).
+    pub fn open_delay(mut self, secs: f32) -> Self {
+        self.open_delay = secs;
+        self
+    }
+
+    /// Seconds before closing once the pointer left (default `0.3`).
+    pub fn close_delay(mut self, secs: f32) -> Self {
+        self.close_delay = secs;
+        self
+    }
+
     /// Provide custom content for the hover card.
     pub fn content<F>(mut self, f: F) -> Self
     where
@@ -47,9 +87,10 @@ impl HoverCardBuilder {
             HoverCardState { open: false },
             Name::new("HoverCard"),
         ));
+        let root = cmd.id();
 
         cmd.with_children(|cb| {
-            cb.spawn((
+            let trigger = cb.spawn((
                 HoverCardTriggerMarker,
                 Node {
                     display: Display::Flex,
@@ -61,15 +102,30 @@ impl HoverCardBuilder {
             ))
             .with_children(|tc| {
                 let _ = LabelBuilder::new(trigger_label).spawn(tc, theme, font);
-            });
+            })
+            .id();
 
             let style = HoverCardContentStyle::new(theme);
-            let mut content_cmd = cb.spawn((HoverCardContentMarker, style, Visibility::Hidden));
+            let floating = Floating::new(trigger).side(self.side).align(self.align);
+            let mut content_cmd = cb.spawn((
+                HoverCardContentMarker,
+                style,
+                floating,
+                Portal::global(),
+                Layer(UiLayer::Popover),
+                Visibility::Hidden,
+            ));
             if let Some(f) = self.content {
                 content_cmd.with_children(|c| {
                     f(c, theme, font);
                 });
             }
+            let content = content_cmd.id();
+
+            let intent = HoverIntent::new(trigger, content)
+                .open_delay(self.open_delay)
+                .close_delay(self.close_delay);
+            cb.commands_mut().entity(root).insert(intent);
         });
 
         cmd.id()
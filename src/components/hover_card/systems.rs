@@ -1,23 +1,21 @@
 use bevy::prelude::*;
 
-use super::{HoverCardContentMarker, HoverCardMarker, HoverCardState, HoverCardTriggerMarker};
+use super::{HoverCardContentMarker, HoverCardMarker, HoverCardState};
+use crate::components::hover_intent::HoverIntent;
 
-/// Shows or hides hover card content when the trigger changes hover state.
+/// Shows or hides hover card content once its [`HoverIntent`] opens or closes.
 pub fn handle_hover_card_interaction(
-    mut root_q: Query<(&mut HoverCardState, &Children), With<HoverCardMarker>>,
-    trigger_q: Query<&Interaction, With<HoverCardTriggerMarker>>,
+    mut root_q: Query<(&mut HoverCardState, &HoverIntent), With<HoverCardMarker>>,
     mut content_q: Query<&mut Visibility, With<HoverCardContentMarker>>,
 ) {
-    for (mut state, children) in root_q.iter_mut() {
-        let mut open = state.open;
-        for child in children.iter() {
-            if let Ok(interaction) = trigger_q.get(child) {
-                open = *interaction == Interaction::Hovered;
-            }
-            if let Ok(mut vis) = content_q.get_mut(child) {
-                *vis = if open { Visibility::Inherited } else { Visibility::Hidden };
-            }
+    for (mut state, intent) in root_q.iter_mut() {
+        let open = intent.is_open();
+        if state.open == open {
+            continue;
         }
         state.open = open;
+        if let Ok(mut vis) = content_q.get_mut(intent.content) {
+            *vis = if open { Visibility::Inherited } else { Visibility::Hidden };
+        }
     }
 }
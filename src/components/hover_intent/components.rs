@@ -0,0 +1,88 @@
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+/// Part of a [`HoverIntent`] the pointer is over.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum HoverTarget {
+    #[default]
+    None,
+    Trigger,
+    Content,
+}
+
+/// Decides when a hover-driven overlay opens and closes.
+///
+/// The overlay opens once the pointer rested on `trigger` for `open_delay`
+/// seconds and closes `close_delay` seconds after it left both trigger and
+/// `content`. While moving from one to the other the pointer may cross a
+/// grace area spanned between its exit point and the target, so diagonal
+/// moves do not close the overlay. Intents in the same `group` open without
+/// delay while another member is open or closed less than `skip_delay`
+/// seconds ago (e.g. neighbouring tooltips).
+#[derive(Component, Debug, Clone)]
+pub struct HoverIntent {
+    pub trigger: Entity,
+    pub content: Entity,
+    pub open_delay: f32,
+    pub close_delay: f32,
+    pub group: Option<String>,
+    pub skip_delay: f32,
+    pub(crate) open: bool,
+    pub(crate) elapsed: f32,
+    pub(crate) over: HoverTarget,
+    pub(crate) last_pointer: Option<Vec2>,
+    pub(crate) grace: Option<Vec<Vec2>>,
+}
+
+impl HoverIntent {
+    pub fn new(trigger: Entity, content: Entity) -> Self {
+        Self {
+            trigger,
+            content,
+            open_delay: 0.0,
+            close_delay: 0.0,
+            group: None,
+            skip_delay: 0.0,
+            open: false,
+            elapsed: 0.0,
+            over: HoverTarget::None,
+            last_pointer: None,
+            grace: None,
+        }
+    }
+
+    pub fn open_delay(mut self, secs: f32) -> Self {
+        self.open_delay = secs;
+        self
+    }
+
+    pub fn close_delay(mut self, secs: f32) -> Self {
+        self.close_delay = secs;
+        self
+    }
+
+    /// Skips the open delay within `skip_delay` seconds of another member of
+    /// `group` closing.
+    pub fn group(mut self, group: impl Into<String>, skip_delay: f32) -> Self {
+        self.group = Some(group.into());
+        self.skip_delay = skip_delay;
+        self
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Opens or closes right away, e.g. from the keyboard.
+    pub fn set_open(&mut self, open: bool) {
+        self.open = open;
+        self.elapsed = 0.0;
+        self.grace = None;
+    }
+}
+
+/// When a member of each [`HoverIntent`] group closed last.
+#[derive(Resource, Debug, Default)]
+pub(crate) struct HoverIntentGroups {
+    pub(crate) closed_at: HashMap<String, f64>,
+}
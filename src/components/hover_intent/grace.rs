@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+
+/// Convex hull of `points` (monotone chain), counter-clockwise.
+fn convex_hull(mut points: Vec<Vec2>) -> Vec<Vec2> {
+    points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+    let cross = |o: Vec2, a: Vec2, b: Vec2| (a - o).perp_dot(b - o);
+
+    let mut hull: Vec<Vec2> = Vec::with_capacity(points.len() * 2);
+    for pass in [points.clone(), points.into_iter().rev().collect()] {
+        let start = hull.len();
+        for p in pass {
+            while hull.len() >= start + 2
+                && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0
+            {
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        // Endpunkt ist der Startpunkt des nächsten Durchlaufs
+        hull.pop();
+    }
+    hull
+}
+
+/// Area the pointer may cross on its way from `exit` to `target`: the hull
+/// of a small square around the exit point and the target's corners.
+pub(crate) fn grace_polygon(exit: Vec2, target: Rect, padding: f32) -> Vec<Vec2> {
+    let mut points: Vec<Vec2> = [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]
+        .into_iter()
+        .map(|(x, y)| exit + Vec2::new(x, y) * padding)
+        .collect();
+    points.extend([
+        target.min,
+        Vec2::new(target.max.x, target.min.y),
+        target.max,
+        Vec2::new(target.min.x, target.max.y),
+    ]);
+    convex_hull(points)
+}
+
+/// Even-odd test whether `point` lies inside `polygon`.
+pub(crate) fn polygon_contains(polygon: &[Vec2], point: Vec2) -> bool {
+    let mut inside = false;
+    let mut j = polygon.len().wrapping_sub(1);
+    for i in 0..polygon.len() {
+        let (a, b) = (polygon[i], polygon[j]);
+        if (a.y > point.y) != (b.y > point.y)
+            && point.x < (b.x - a.x) * (point.y - a.y) / (b.y - a.y) + a.x
+        {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
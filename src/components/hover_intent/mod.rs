@@ -0,0 +1,9 @@
+// src/components/hover_intent/mod.rs
+
+mod components;
+mod grace;
+mod plugin;
+mod systems;
+
+pub use components::HoverIntent;
+pub use plugin::HoverIntentPlugin;
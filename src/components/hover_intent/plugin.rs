@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+
+use super::components::HoverIntentGroups;
+use super::systems::update_hover_intents;
+use crate::plugin::UiState;
+
+/// Plugin for [`HoverIntent`](super::HoverIntent).
+///
+/// Runs in `PreUpdate`, so widgets read the decided open state in `Update`.
+pub struct HoverIntentPlugin;
+
+impl Plugin for HoverIntentPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HoverIntentGroups>().add_systems(
+            PreUpdate,
+            update_hover_intents
+                .after(UiSystem::Focus)
+                .run_if(in_state(UiState::Ready)),
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use bevy::window::PrimaryWindow;
+
+use super::components::{HoverIntentGroups, HoverTarget};
+use super::grace::{grace_polygon, polygon_contains};
+use super::HoverIntent;
+use crate::components::disabled::InheritedDisabled;
+use crate::components::layer::LayerStack;
+
+/// Half size of the square around the exit point of the grace area, in
+/// logical pixels.
+const GRACE_PADDING: f32 = 5.0;
+
+type NodeParts<'a> = (
+    &'a ComputedNode,
+    &'a GlobalTransform,
+    &'a InheritedVisibility,
+);
+
+/// Physical rect of a visible node.
+fn visible_rect(entity: Entity, nodes: &Query<NodeParts>) -> Option<Rect> {
+    let (node, transform, visibility) = nodes.get(entity).ok()?;
+    visibility
+        .get()
+        .then(|| Rect::from_center_size(transform.translation().truncate(), node.size()))
+}
+
+/// Physical rects of the blocking nodes of all open layers, with the
+/// z-index of their layer.
+fn layer_blockers(
+    layers: &LayerStack,
+    children_q: &Query<&Children>,
+    nodes: &Query<NodeParts>,
+    policies: &Query<&FocusPolicy>,
+) -> Vec<(i32, Rect)> {
+    layers
+        .entries()
+        .iter()
+        .flat_map(|entry| {
+            std::iter::once(entry.entity)
+                .chain(children_q.iter_descendants(entry.entity))
+                .filter(|e| matches!(policies.get(*e), Ok(FocusPolicy::Block)))
+                .filter_map(|e| visible_rect(e, nodes))
+                .map(move |rect| (entry.z_index, rect))
+        })
+        .collect()
+}
+
+/// `GlobalZIndex` of the open layer `entity` lives in; `i32::MIN` outside
+/// of all layers.
+fn layer_z(entity: Entity, layers: &LayerStack, parents: &Query<&ChildOf>) -> i32 {
+    std::iter::once(entity)
+        .chain(parents.iter_ancestors(entity))
+        .find_map(|e| layers.get(e))
+        .map_or(i32::MIN, |entry| entry.z_index)
+}
+
+/// Tracks the pointer over trigger, content and grace area of every
+/// [`HoverIntent`] and opens or closes it once the delay has passed.
+///
+/// Points covered by a blocking node of a higher layer (e.g. a modal
+/// dialog's overlay) do not count as over trigger or content, and disabled
+/// or inert triggers are ignored.
+pub(crate) fn update_hover_intents(
+    time: Res<Time>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut groups: ResMut<HoverIntentGroups>,
+    mut intents: Query<&mut HoverIntent>,
+    nodes: Query<NodeParts>,
+    disabled: Query<(), With<InheritedDisabled>>,
+    layers: Option<Res<LayerStack>>,
+    parents: Query<&ChildOf>,
+    children_q: Query<&Children>,
+    policies: Query<&FocusPolicy>,
+) {
+    let window = windows.single().ok();
+    let cursor = window.and_then(Window::physical_cursor_position);
+    let padding = GRACE_PADDING * window.map_or(1.0, Window::scale_factor);
+    let now = time.elapsed_secs_f64();
+
+    // Gruppen, in denen gerade ein Mitglied offen ist
+    let open_groups: Vec<String> = intents
+        .iter()
+        .filter(|intent| intent.open)
+        .filter_map(|intent| intent.group.clone())
+        .collect();
+
+    let no_layers = LayerStack::default();
+    let layers = layers.as_deref().unwrap_or(&no_layers);
+    let blockers = layer_blockers(layers, &children_q, &nodes, &policies);
+    let reachable = |entity: Entity, rect: Option<Rect>, p: Vec2| {
+        let z = layer_z(entity, layers, &parents);
+        rect.is_some_and(|r| r.contains(p))
+            && !blockers.iter().any(|(bz, b)| *bz > z && b.contains(p))
+    };
+
+    for mut intent in intents.iter_mut() {
+        let trigger = (!disabled.contains(intent.trigger))
+            .then(|| visible_rect(intent.trigger, &nodes))
+            .flatten();
+        let content = intent
+            .open
+            .then(|| visible_rect(intent.content, &nodes))
+            .flatten();
+        let over = match cursor {
+            Some(p) if reachable(intent.trigger, trigger, p) => HoverTarget::Trigger,
+            Some(p) if reachable(intent.content, content, p) => HoverTarget::Content,
+            _ => HoverTarget::None,
+        };
+
+        // 1) Gnadenbereich beim Verlassen von Trigger oder Inhalt aufspannen
+        if over != HoverTarget::None {
+            intent.grace = None;
+        } else if let Some(exit) = intent.last_pointer {
+            let target = match intent.over {
+                HoverTarget::Trigger => content,
+                HoverTarget::Content => trigger,
+                HoverTarget::None => None,
+            };
+            if let Some(target) = target {
+                intent.grace = Some(grace_polygon(exit, target, padding));
+            }
+        }
+        let in_grace = match (&intent.grace, cursor) {
+            (Some(polygon), Some(p)) => polygon_contains(polygon, p),
+            _ => false,
+        };
+        if !in_grace {
+            intent.grace = None;
+        }
+
+        // 2) Verzögert öffnen bzw. schließen
+        let wants_open = over != HoverTarget::None || in_grace;
+        if wants_open == intent.open {
+            intent.elapsed = 0.0;
+        } else {
+            intent.elapsed += time.delta_secs();
+            let delay = if !wants_open {
+                intent.close_delay
+            } else if intent.group.as_ref().is_some_and(|group| {
+                open_groups.contains(group)
+                    || groups
+                        .closed_at
+                        .get(group)
+                        .is_some_and(|closed| now - closed <= intent.skip_delay as f64)
+            }) {
+                0.0
+            } else {
+                intent.open_delay
+            };
+            if intent.elapsed >= delay {
+                intent.open = wants_open;
+                intent.elapsed = 0.0;
+                if let (false, Some(group)) = (wants_open, intent.group.clone()) {
+                    groups.closed_at.insert(group, now);
+                }
+            }
+        }
+
+        intent.over = over;
+        intent.last_pointer = cursor;
+    }
+}
@@ -1,4 +1,7 @@
 use bevy::prelude::*; // Für den Rückgabetyp von spawn
+use bevy::ui::{FocusPolicy, RelativeCursorPosition};
+
+use crate::components::clipboard::SelectableText;
 
 use crate::theme::UiTheme; // Theme für Styling
 use super::components::LabelMarker;
@@ -54,6 +57,8 @@ pub struct LabelBuilder {
     // label_style: LabelStyle,
     // Optional: Textausrichtung
     alignment: JustifyText,
+    // Text mit der Maus markier- und kopierbar
+    selectable: bool,
 }
 
 impl LabelBuilder {
@@ -66,6 +71,7 @@ impl LabelBuilder {
             margin: None,
 
             alignment: JustifyText::Left, // Standardmäßig linksbündig
+            selectable: false,
         }
     }
 
@@ -91,6 +97,13 @@ impl LabelBuilder {
         self
     }
 
+    /// Macht den Text mit der Maus markierbar; Strg+C kopiert ihn in das
+    /// [`Clipboard`](crate::components::clipboard::Clipboard).
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
     /// Spawnt das Label als Kind des gegebenen UI‑Parents und gibt
     /// das erstellte [`Entity`] zurück.
     ///
@@ -127,7 +140,7 @@ impl LabelBuilder {
             },
         ));
         entity_commands.with_children(|builder| {
-            builder.spawn((
+            let mut text = builder.spawn((
                 Text::new(self.text.clone()),
                 TextFont {
                     font_size: final_font_size,
@@ -136,8 +149,20 @@ impl LabelBuilder {
                 },
                 TextLayout::new_with_justify(self.alignment).with_no_wrap(),
                 TextColor(final_color),
+                // Klicks landen beim Label-Knoten (z. B. für FieldBuilder)
+                FocusPolicy::Pass,
             ));
+            if self.selectable {
+                text.insert(RelativeCursorPosition::default());
+            }
         });
+        if self.selectable {
+            entity_commands.insert((
+                SelectableText::default(),
+                Interaction::default(),
+                FocusPolicy::Block,
+            ));
+        }
         entity_commands.id()
     }
 }
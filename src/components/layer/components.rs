@@ -0,0 +1,110 @@
+use bevy::prelude::*;
+
+use crate::theme::UiZIndex;
+
+/// Named stacking layers, from bottom to top.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UiLayer {
+    Base,
+    Dropdown,
+    Sticky,
+    Overlay,
+    Modal,
+    Popover,
+    Toast,
+    Tooltip,
+}
+
+impl UiLayer {
+    /// Base `GlobalZIndex` of the layer taken from the theme.
+    pub fn z_index(self, z: &UiZIndex) -> i32 {
+        match self {
+            UiLayer::Base => z.base,
+            UiLayer::Dropdown => z.dropdown,
+            UiLayer::Sticky => z.sticky,
+            UiLayer::Overlay => z.overlay,
+            UiLayer::Modal => z.modal,
+            UiLayer::Popover => z.popover,
+            UiLayer::Toast => z.toast,
+            UiLayer::Tooltip => z.tooltip,
+        }
+    }
+
+    /// Whether Escape and outside clicks are meant for this layer. Toasts and
+    /// tooltips float above everything but never take them.
+    pub fn is_dismissable(self) -> bool {
+        matches!(
+            self,
+            UiLayer::Dropdown | UiLayer::Overlay | UiLayer::Modal | UiLayer::Popover
+        )
+    }
+}
+
+/// Puts an overlay on the [`LayerStack`] while it is open.
+///
+/// An entity counts as open while it is visible, not `Display::None` and,
+/// if it has a [`UiTransition`](crate::components::transition::UiTransition),
+/// not closing. It then gets a `GlobalZIndex` of its layer, or just above the
+/// layer that opened it if that one is higher (e.g. a select in a dialog).
+/// Open entries of the same layer stack in opening order, so a dialog opened
+/// from another dialog ends up above it.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Layer(pub UiLayer);
+
+/// One open overlay on the [`LayerStack`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LayerEntry {
+    pub entity: Entity,
+    pub layer: UiLayer,
+    /// Open layer this one was opened from, if any.
+    pub opener: Option<Entity>,
+    pub z_index: i32,
+}
+
+/// All open overlays ordered from bottom to top.
+#[derive(Resource, Debug, Default)]
+pub struct LayerStack {
+    entries: Vec<LayerEntry>,
+}
+
+impl LayerStack {
+    pub fn entries(&self) -> &[LayerEntry] {
+        &self.entries
+    }
+
+    pub fn get(&self, entity: Entity) -> Option<&LayerEntry> {
+        self.entries.iter().find(|e| e.entity == entity)
+    }
+
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.get(entity).is_some()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Topmost open layer that takes Escape and outside clicks.
+    pub fn topmost(&self) -> Option<&LayerEntry> {
+        self.entries.iter().rev().find(|e| e.layer.is_dismissable())
+    }
+
+    /// Whether `entity` is the [`topmost`](Self::topmost) layer.
+    pub fn is_topmost(&self, entity: Entity) -> bool {
+        self.topmost().is_some_and(|e| e.entity == entity)
+    }
+
+    /// Inserts above all entries with a lower or equal z-index.
+    pub(crate) fn push(&mut self, entry: LayerEntry) {
+        let index = self
+            .entries
+            .iter()
+            .position(|e| e.z_index > entry.z_index)
+            .unwrap_or(self.entries.len());
+        self.entries.insert(index, entry);
+    }
+
+    pub(crate) fn retain(&mut self, keep: impl FnMut(&LayerEntry) -> bool) {
+        self.entries.retain(keep);
+    }
+}
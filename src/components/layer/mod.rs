@@ -0,0 +1,9 @@
+// src/components/layer/mod.rs
+
+mod components;
+mod plugin;
+mod systems;
+
+pub use components::{Layer, LayerEntry, LayerStack, UiLayer};
+pub use plugin::LayerPlugin;
+pub(crate) use systems::update_layer_stack;
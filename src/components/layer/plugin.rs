@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+use super::{systems::update_layer_stack, LayerStack};
+use crate::plugin::UiState;
+
+/// Plugin for the overlay [`LayerStack`].
+///
+/// The stack is updated in `PreUpdate`, so widget systems in `Update` see
+/// which overlay is topmost before handling Escape or outside clicks.
+pub struct LayerPlugin;
+
+impl Plugin for LayerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LayerStack>().add_systems(
+            PreUpdate,
+            update_layer_stack.run_if(in_state(UiState::Ready)),
+        );
+    }
+}
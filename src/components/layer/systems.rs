@@ -0,0 +1,70 @@
+use bevy::prelude::*;
+
+use super::{Layer, LayerEntry, LayerStack};
+use crate::components::portal::PortalHierarchy;
+use crate::components::transition::UiTransition;
+use crate::theme::UiTheme;
+
+type LayerParts<'a> = (
+    Entity,
+    &'a Layer,
+    &'a InheritedVisibility,
+    Option<&'a Node>,
+    Option<&'a UiTransition>,
+    Option<&'a GlobalZIndex>,
+);
+
+fn is_open((_, _, visibility, node, transition, _): &LayerParts) -> bool {
+    visibility.get()
+        && node.is_none_or(|n| n.display != Display::None)
+        && transition.is_none_or(|t| t.is_open())
+}
+
+/// Pushes opened layers onto the [`LayerStack`], drops closed ones and
+/// assigns the `GlobalZIndex` of each new entry.
+pub(crate) fn update_layer_stack(
+    mut commands: Commands,
+    theme: Option<Res<UiTheme>>,
+    mut stack: ResMut<LayerStack>,
+    layers: Query<LayerParts>,
+    hierarchy: PortalHierarchy,
+) {
+    let Some(theme) = theme else {
+        return;
+    };
+
+    // 1) Geschlossene oder entfernte Ebenen austragen
+    stack.retain(|entry| layers.get(entry.entity).is_ok_and(|parts| is_open(&parts)));
+
+    // 2) Neu geöffnete Ebenen, äußere zuerst, damit verschachtelte ihren Opener finden
+    let mut opened: Vec<(usize, LayerParts)> = layers
+        .iter()
+        .filter(|parts| is_open(parts) && !stack.contains(parts.0))
+        .map(|parts| (hierarchy.ancestors(parts.0).count(), parts))
+        .collect();
+    opened.sort_by_key(|(depth, _)| *depth);
+
+    for (_, (entity, layer, _, _, _, current)) in opened {
+        let opener = hierarchy
+            .ancestors(entity)
+            .find_map(|e| stack.get(e))
+            .copied();
+        // Über dem Opener und über bereits offenen Ebenen derselben Art
+        let z_index = stack
+            .entries()
+            .iter()
+            .filter(|e| e.layer == layer.0)
+            .map(|e| e.z_index + 1)
+            .chain(opener.map(|o| o.z_index + 1))
+            .fold(layer.0.z_index(&theme.layout.z_index), i32::max);
+        stack.push(LayerEntry {
+            entity,
+            layer: layer.0,
+            opener: opener.map(|o| o.entity),
+            z_index,
+        });
+        if current != Some(&GlobalZIndex(z_index)) {
+            commands.entity(entity).insert(GlobalZIndex(z_index));
+        }
+    }
+}
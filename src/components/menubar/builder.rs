@@ -1,52 +1,152 @@
 use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
 
-use crate::components::helper::UiBuilder;
+use crate::components::base_menu::{
+    spawn_menu_items, BaseMenuContentMarker, MenuContentState, MenuItems,
+};
+use crate::components::dismissable_layer::DismissableLayer;
+use crate::components::floating::{Floating, FloatingAlign};
+use crate::components::helper::{NoAction, UiBuilder};
 use crate::components::label::LabelBuilder;
+use crate::components::layer::{Layer, UiLayer};
+use crate::components::portal::Portal;
+use crate::components::roving_focus::{RovingFocusGroup, RovingFocusItem, RovingFocusOrientation};
+use crate::components::shortcut::Accelerator;
 use crate::theme::UiTheme;
 
 use super::{
-    MenubarMarker, MenubarMenuContentMarker, MenubarMenuContentStyle, MenubarMenuItemMarker,
-    MenubarMenuItemStyle, MenubarMenuMarker, MenubarMenuState, MenubarMenuStyle,
-    MenubarMenuTriggerMarker, MenubarStyle,
+    MenubarMarker, MenubarMenuContentMarker, MenubarMenuContentStyle, MenubarMenuMarker,
+    MenubarMenuState, MenubarMenuStyle, MenubarMenuTriggerMarker, MenubarMenuTriggerStyle,
+    MenubarStyle,
 };
 
 /// Builder for an individual menu within the menubar.
-pub struct MenubarMenuBuilder {
+pub struct MenubarMenuBuilder<A: Component + Clone = NoAction> {
     label: String,
-    items: Vec<String>,
+    items: MenuItems<A>,
 }
 
-impl MenubarMenuBuilder {
+impl<A: Component + Clone> MenubarMenuBuilder<A> {
     pub fn new(label: impl Into<String>) -> Self {
         Self {
             label: label.into(),
-            items: Vec::new(),
+            items: MenuItems::new(),
         }
     }
 
+    /// Replaces all entries of the menu.
+    pub fn items(mut self, items: MenuItems<A>) -> Self {
+        self.items = items;
+        self
+    }
+
     pub fn item(mut self, text: impl Into<String>) -> Self {
-        self.items.push(text.into());
+        self.items = self.items.item(text);
+        self
+    }
+
+    /// Adds an item that can also be triggered with `accelerator`.
+    /// The accelerator is shown as a right-aligned hint.
+    pub fn item_with_shortcut(mut self, text: impl Into<String>, accelerator: Accelerator) -> Self {
+        self.items = self.items.item(text).shortcut(accelerator);
+        self
+    }
+
+    /// Add an item with a check mark that toggles when chosen.
+    pub fn checkbox(mut self, text: impl Into<String>, checked: bool) -> Self {
+        self.items = self.items.checkbox(text, checked);
+        self
+    }
+
+    /// Add an item of the radio group `group`.
+    pub fn radio(
+        mut self,
+        group: impl Into<String>,
+        text: impl Into<String>,
+        checked: bool,
+    ) -> Self {
+        self.items = self.items.radio(group, text, checked);
+        self
+    }
+
+    /// Add an item that opens a nested menu.
+    pub fn submenu(mut self, text: impl Into<String>, items: MenuItems<A>) -> Self {
+        self.items = self.items.submenu(text, items);
+        self
+    }
+
+    pub fn separator(mut self) -> Self {
+        self.items = self.items.separator();
+        self
+    }
+
+    /// Add a non-interactive group heading.
+    pub fn label(mut self, text: impl Into<String>) -> Self {
+        self.items = self.items.label(text);
+        self
+    }
+
+    /// Shortcut of the item added last.
+    pub fn shortcut(mut self, accelerator: Accelerator) -> Self {
+        self.items = self.items.shortcut(accelerator);
+        self
+    }
+
+    /// Action of the item added last, sent with
+    /// [`MenubarItemSelected<A>`](super::MenubarItemSelected).
+    pub fn action(mut self, action: A) -> Self {
+        self.items = self.items.action(action);
+        self
+    }
+
+    /// Disables the item added last.
+    pub fn disabled(mut self) -> Self {
+        self.items = self.items.disabled();
         self
     }
 }
 
-/// Builder for a simple menubar.
-pub struct MenubarBuilder {
-    menus: Vec<MenubarMenuBuilder>,
+/// Builder for a desktop-style menubar.
+///
+/// Clicking a trigger opens its menu; while one is open, hovering another
+/// trigger switches to it. Arrow Left/Right move between the menus and
+/// Escape closes them.
+///
+/// ```ignore
+/// MenubarBuilder::<EditorAction>::new()
+///     .menu(
+///         MenubarMenuBuilder::new("File")
+///             .item_with_shortcut("Save", Accelerator::primary(KeyCode::KeyS))
+///             .action(EditorAction::Save),
+///     )
+///     .spawn(parent, &theme, &font);
+/// ```
+pub struct MenubarBuilder<A: Component + Clone = NoAction> {
+    menus: Vec<MenubarMenuBuilder<A>>,
+    loop_navigation: bool,
 }
 
-impl MenubarBuilder {
+impl<A: Component + Clone> MenubarBuilder<A> {
     pub fn new() -> Self {
-        Self { menus: Vec::new() }
+        Self {
+            menus: Vec::new(),
+            loop_navigation: true,
+        }
     }
 
-    pub fn menu(mut self, menu: MenubarMenuBuilder) -> Self {
+    /// Whether arrow-key navigation between menus wraps around (default: `true`).
+    pub fn loop_navigation(mut self, flag: bool) -> Self {
+        self.loop_navigation = flag;
+        self
+    }
+
+    pub fn menu(mut self, menu: MenubarMenuBuilder<A>) -> Self {
         self.menus.push(menu);
         self
     }
 }
 
-impl<'w, 's> UiBuilder<'w, 's> for MenubarBuilder {
+impl<'w, 's, A: Component + Clone> UiBuilder<'w, 's> for MenubarBuilder<A> {
     type Output = Entity;
 
     fn spawn(
@@ -58,8 +158,10 @@ impl<'w, 's> UiBuilder<'w, 's> for MenubarBuilder {
         let mut root = parent.spawn((
             MenubarMarker,
             MenubarStyle::new(theme),
+            RovingFocusGroup::new(RovingFocusOrientation::Horizontal, self.loop_navigation),
             Name::new("Menubar"),
         ));
+        let bar = root.id();
         root.with_children(|rc| {
             for menu in self.menus {
                 let mut menu_cmd = rc.spawn((
@@ -68,36 +170,40 @@ impl<'w, 's> UiBuilder<'w, 's> for MenubarBuilder {
                     MenubarMenuStyle::new(theme),
                 ));
                 menu_cmd.with_children(|mc| {
+                    let trigger = mc
+                        .spawn((
+                            MenubarMenuTriggerMarker,
+                            RovingFocusItem,
+                            MenubarMenuTriggerStyle::new(theme),
+                            Interaction::default(),
+                            FocusPolicy::Block,
+                        ))
+                        .with_children(|tc| {
+                            let _ = LabelBuilder::new(menu.label.clone()).spawn(tc, theme, font);
+                        })
+                        .id();
+
+                    // Die ganze Leiste zählt als innen, damit das Überfahren
+                    // der anderen Auslöser das Menü nicht schließt
                     mc.spawn((
-                        MenubarMenuTriggerMarker,
-                        Node {
-                            display: Display::Flex,
-                            align_items: AlignItems::Center,
+                        MenubarMenuContentMarker,
+                        BaseMenuContentMarker,
+                        MenuContentState {
+                            return_focus: Some(trigger),
                             ..default()
                         },
-                        Interaction::default(),
-                    ))
-                    .with_children(|tc| {
-                        let _ = LabelBuilder::new(menu.label.clone()).spawn(tc, theme, font);
-                    });
-
-                    let mut content_cmd = mc.spawn((
-                        MenubarMenuContentMarker,
                         MenubarMenuContentStyle::new(theme),
+                        Floating::new(trigger).align(FloatingAlign::Start),
+                        Portal::global(),
+                        Layer(UiLayer::Dropdown),
+                        DismissableLayer::new().branch(bar),
                         Visibility::Hidden,
-                    ));
-                    content_cmd.with_children(|cc| {
-                        for item in &menu.items {
-                            let mut item_cmd =
-                                cc.spawn((MenubarMenuItemMarker, MenubarMenuItemStyle::new(theme)));
-                            item_cmd.with_children(|icb| {
-                                let _ = LabelBuilder::new(item.clone()).spawn(icb, theme, font);
-                            });
-                        }
-                    });
+                        Name::new(format!("Menu {}", menu.label)),
+                    ))
+                    .with_children(|cc| spawn_menu_items(cc, menu.items, theme, font));
                 });
             }
         });
-        root.id()
+        bar
     }
 }
@@ -12,9 +12,8 @@ pub struct MenubarMenuTriggerMarker;
 #[derive(Component, Debug, Clone, Copy, Default)]
 pub struct MenubarMenuContentMarker;
 
-#[derive(Component, Debug, Clone, Copy, Default)]
-pub struct MenubarMenuItemMarker;
-
+/// Mirrors whether the menu's panel is open. The panel itself is driven by
+/// its [`MenuContentState`](crate::components::base_menu::MenuContentState).
 #[derive(Component, Debug, Clone)]
 pub struct MenubarMenuState {
     pub open: bool,
@@ -0,0 +1,35 @@
+use bevy::prelude::{Component, Entity, Event};
+use std::fmt::Debug;
+
+/// Sent when a menu item is clicked, chosen with the keyboard or triggered
+/// via its shortcut.
+///
+/// Generic over the action type `A` set with
+/// [`MenubarMenuBuilder::action`](super::MenubarMenuBuilder::action); each
+/// type needs its own [`MenubarActionPlugin`](super::MenubarActionPlugin).
+#[derive(Event, Clone)]
+pub struct MenubarItemSelected<A: Component + Clone + Send + Sync> {
+    /// The chosen item.
+    pub source_entity: Entity,
+    /// Root entity of the menubar.
+    pub menubar: Entity,
+    /// Text of the chosen item.
+    pub label: String,
+    /// Clone of the item's action, `None` if it has none.
+    pub action_id: Option<A>,
+    /// New checked state of checkbox and radio items.
+    pub checked: Option<bool>,
+}
+
+// Wie beim ButtonClickedEvent: A muss kein Debug implementieren
+impl<A: Component + Clone + Send + Sync> Debug for MenubarItemSelected<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MenubarItemSelected")
+            .field("source_entity", &self.source_entity)
+            .field("menubar", &self.menubar)
+            .field("label", &self.label)
+            .field("action_id", &self.action_id.as_ref().map(|_| "Some<A>"))
+            .field("checked", &self.checked)
+            .finish()
+    }
+}
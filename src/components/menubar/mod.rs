@@ -1,13 +1,15 @@
 mod builder;
 mod components;
+mod events;
 mod plugin;
 mod style;
 mod systems;
 
 pub use builder::{MenubarBuilder, MenubarMenuBuilder};
 pub use components::{
-    MenubarMarker, MenubarMenuContentMarker, MenubarMenuItemMarker, MenubarMenuMarker,
-    MenubarMenuState, MenubarMenuTriggerMarker,
+    MenubarMarker, MenubarMenuContentMarker, MenubarMenuMarker, MenubarMenuState,
+    MenubarMenuTriggerMarker,
 };
-pub use plugin::MenubarPlugin;
-pub use style::{MenubarMenuContentStyle, MenubarMenuItemStyle, MenubarMenuStyle, MenubarStyle};
+pub use events::MenubarItemSelected;
+pub use plugin::{MenubarActionPlugin, MenubarPlugin};
+pub use style::{MenubarMenuContentStyle, MenubarMenuStyle, MenubarMenuTriggerStyle, MenubarStyle};
@@ -1,9 +1,17 @@
 use bevy::prelude::*;
+use std::marker::PhantomData;
 
+use super::events::MenubarItemSelected;
+use super::systems::*;
+use crate::components::base_menu::{
+    apply_menu_activation, handle_menu_dismiss, handle_menu_keyboard, sync_menu_panels,
+};
+use crate::components::helper::NoAction;
 use crate::plugin::UiState;
 
-use super::systems::handle_menubar_interaction;
-
+/// Opens, closes and switches the menus of a menubar. The menu items
+/// themselves are driven by the
+/// [`BaseMenuPlugin`](crate::components::base_menu::BaseMenuPlugin).
 pub struct MenubarPlugin;
 
 impl Default for MenubarPlugin {
@@ -16,7 +24,36 @@ impl Plugin for MenubarPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             Update,
-            handle_menubar_interaction.run_if(in_state(UiState::Ready)),
+            (
+                (handle_menubar_trigger_pointer, handle_menubar_keyboard)
+                    .chain()
+                    .after(handle_menu_dismiss)
+                    .before(handle_menu_keyboard),
+                sync_menubar_state.after(sync_menu_panels),
+            )
+                .run_if(in_state(UiState::Ready)),
+        );
+    }
+}
+
+/// Registers [`MenubarItemSelected<A>`] for the action type `A`.
+/// Add one per action type used with
+/// [`MenubarBuilder`](super::MenubarBuilder).
+pub struct MenubarActionPlugin<A: Component + Clone + Send + Sync = NoAction>(PhantomData<A>);
+
+impl<A: Component + Clone + Send + Sync> Default for MenubarActionPlugin<A> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A: Component + Clone + Send + Sync> Plugin for MenubarActionPlugin<A> {
+    fn build(&self, app: &mut App) {
+        app.add_event::<MenubarItemSelected<A>>().add_systems(
+            Update,
+            send_menubar_selection::<A>
+                .after(apply_menu_activation)
+                .run_if(in_state(UiState::Ready)),
         );
     }
 }
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
 
 use crate::theme::UiTheme;
 
@@ -16,6 +17,9 @@ impl MenubarStyle {
             node: Node {
                 display: Display::Flex,
                 flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(theme.layout.gap.xs),
+                padding: UiRect::all(Val::Px(theme.layout.padding.xs)),
+                border: UiRect::all(Val::Px(1.0)),
                 ..default()
             },
             background_color: BackgroundColor(theme.color.gray.step02),
@@ -46,47 +50,65 @@ impl MenubarMenuStyle {
     }
 }
 
+/// Style for the trigger button of a menu in the menubar.
 #[derive(Bundle, Clone, Debug)]
-pub struct MenubarMenuContentStyle {
+pub struct MenubarMenuTriggerStyle {
     pub node: Node,
     pub background_color: BackgroundColor,
-    pub border_color: BorderColor,
     pub border_radius: BorderRadius,
 }
 
-impl MenubarMenuContentStyle {
+impl MenubarMenuTriggerStyle {
     pub fn new(theme: &UiTheme) -> Self {
-        MenubarMenuContentStyle {
+        MenubarMenuTriggerStyle {
             node: Node {
                 display: Display::Flex,
-                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::axes(
+                    Val::Px(theme.layout.padding.sm),
+                    Val::Px(theme.layout.padding.xs),
+                ),
                 ..default()
             },
-            background_color: BackgroundColor(theme.color.gray.step03),
-            border_color: BorderColor(theme.color.gray.step06),
+            background_color: BackgroundColor(Color::NONE),
             border_radius: BorderRadius::all(Val::Px(theme.layout.radius.xs)),
         }
     }
+
+    /// Background of the trigger, highlighted while its menu is open.
+    pub fn background(theme: &UiTheme, open: bool) -> BackgroundColor {
+        if open {
+            BackgroundColor(theme.color.gray.step04)
+        } else {
+            BackgroundColor(Color::NONE)
+        }
+    }
 }
 
 #[derive(Bundle, Clone, Debug)]
-pub struct MenubarMenuItemStyle {
+pub struct MenubarMenuContentStyle {
     pub node: Node,
     pub background_color: BackgroundColor,
+    pub border_color: BorderColor,
     pub border_radius: BorderRadius,
+    pub focus_policy: FocusPolicy,
 }
 
-impl MenubarMenuItemStyle {
+impl MenubarMenuContentStyle {
     pub fn new(theme: &UiTheme) -> Self {
-        MenubarMenuItemStyle {
+        MenubarMenuContentStyle {
             node: Node {
                 display: Display::Flex,
-                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                min_width: Val::Px(200.0),
                 padding: UiRect::all(Val::Px(theme.layout.padding.xs)),
+                border: UiRect::all(Val::Px(1.0)),
                 ..default()
             },
-            background_color: BackgroundColor(theme.color.gray.step04),
-            border_radius: BorderRadius::all(Val::Px(theme.layout.radius.xs)),
+            background_color: BackgroundColor(theme.color.gray.step02),
+            border_color: BorderColor(theme.color.gray.step06),
+            border_radius: BorderRadius::all(Val::Px(theme.layout.radius.sm)),
+            focus_policy: FocusPolicy::Block,
         }
     }
 }
@@ -1,28 +1,259 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::input_focus::InputFocus;
 use bevy::prelude::*;
 
-use super::{
-    MenubarMenuContentMarker, MenubarMenuMarker, MenubarMenuState, MenubarMenuTriggerMarker,
+use super::*;
+use crate::components::base_menu::{
+    enabled_items, MenuContentState, MenuItem, MenuItemActivatedEvent, MenuItemKind,
 };
+use crate::components::disabled::InheritedDisabled;
+use crate::components::portal::PortalHierarchy;
+use crate::components::roving_focus::RovingFocusGroup;
+use crate::theme::UiTheme;
 
-pub fn handle_menubar_interaction(
-    mut menu_q: Query<(&mut MenubarMenuState, &Children), With<MenubarMenuMarker>>,
-    trigger_q: Query<&Interaction, With<MenubarMenuTriggerMarker>>,
-    mut content_q: Query<&mut Visibility, With<MenubarMenuContentMarker>>,
+/// Trigger and panel of every menu of `bar`, in display order.
+fn menus_of(
+    bar: Entity,
+    hierarchy: &PortalHierarchy,
+    menus: &Query<(), With<MenubarMenuMarker>>,
+    parts: &Query<(Has<MenubarMenuTriggerMarker>, Has<MenubarMenuContentMarker>)>,
+) -> Vec<(Entity, Entity)> {
+    hierarchy
+        .children(bar)
+        .filter(|menu| menus.contains(*menu))
+        .filter_map(|menu| {
+            let trigger = hierarchy
+                .children(menu)
+                .find(|e| parts.get(*e).is_ok_and(|(t, _)| t))?;
+            let content = hierarchy
+                .children(menu)
+                .find(|e| parts.get(*e).is_ok_and(|(_, c)| c))?;
+            Some((trigger, content))
+        })
+        .collect()
+}
+
+/// Clicking a trigger toggles its menu. While one menu of the bar is open,
+/// hovering another trigger switches to that menu.
+pub fn handle_menubar_trigger_pointer(
+    triggers: Query<
+        (Entity, &Interaction),
+        (
+            With<MenubarMenuTriggerMarker>,
+            Changed<Interaction>,
+            Without<InheritedDisabled>,
+        ),
+    >,
+    bars: Query<(), With<MenubarMarker>>,
+    menus: Query<(), With<MenubarMenuMarker>>,
+    parts: Query<(Has<MenubarMenuTriggerMarker>, Has<MenubarMenuContentMarker>)>,
+    hierarchy: PortalHierarchy,
+    mut panels: Query<&mut MenuContentState>,
+) {
+    for (trigger, interaction) in triggers.iter() {
+        let Some(bar) = hierarchy.ancestors(trigger).find(|e| bars.contains(*e)) else {
+            continue;
+        };
+        let list = menus_of(bar, &hierarchy, &menus, &parts);
+        let Some(&(_, content)) = list.iter().find(|(t, _)| *t == trigger) else {
+            continue;
+        };
+        let is_open = |panel: Entity| panels.get(panel).is_ok_and(|s| s.open);
+        let open = match interaction {
+            Interaction::Pressed => !is_open(content),
+            Interaction::Hovered if list.iter().any(|(_, c)| *c != content && is_open(*c)) => true,
+            _ => continue,
+        };
+
+        for (_, panel) in &list {
+            let wanted = open && *panel == content;
+            let Ok(mut state) = panels.get_mut(*panel) else {
+                continue;
+            };
+            if state.open != wanted {
+                state.open = wanted;
+                state.highlighted = None;
+            }
+        }
+    }
+}
+
+/// Keyboard handling on top of the shared menu navigation: Enter, Space and
+/// Arrow Down on a focused trigger open its menu, Arrow Up opens it at the
+/// last item. Inside an open menu Arrow Left and Right move to the
+/// neighbouring menu unless a submenu is open or about to open.
+///
+/// Runs before the shared `handle_menu_keyboard`, which then ignores the
+/// keys because the focused panel is already closed.
+pub fn handle_menubar_keyboard(
+    focus: Res<InputFocus>,
+    mut key_events: EventReader<KeyboardInput>,
+    bars: Query<&RovingFocusGroup, With<MenubarMarker>>,
+    triggers: Query<(), (With<MenubarMenuTriggerMarker>, Without<InheritedDisabled>)>,
+    menus: Query<(), With<MenubarMenuMarker>>,
+    parts: Query<(Has<MenubarMenuTriggerMarker>, Has<MenubarMenuContentMarker>)>,
+    hierarchy: PortalHierarchy,
+    mut panels: Query<&mut MenuContentState>,
+    items: Query<&MenuItem>,
+    children: Query<&Children>,
 ) {
-    for (mut state, children) in menu_q.iter_mut() {
-        let mut open = state.open;
-        for child in children.iter() {
-            if let Ok(interaction) = trigger_q.get(child) {
-                open = *interaction == Interaction::Hovered;
+    let Some(focused) = focus.get().filter(|e| {
+        triggers.contains(*e)
+            || (parts.get(*e).is_ok_and(|(_, c)| c) && panels.get(*e).is_ok_and(|s| s.open))
+    }) else {
+        key_events.clear();
+        return;
+    };
+    let Some((bar, group)) = hierarchy
+        .ancestors(focused)
+        .find_map(|e| bars.get(e).ok().map(|g| (e, g)))
+    else {
+        key_events.clear();
+        return;
+    };
+    let list = menus_of(bar, &hierarchy, &menus, &parts);
+    let Some(mut index) = list
+        .iter()
+        .position(|(t, c)| *t == focused || *c == focused)
+    else {
+        key_events.clear();
+        return;
+    };
+    let from_trigger = triggers.contains(focused);
+
+    for event in key_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        let content = list[index].1;
+
+        if from_trigger {
+            let entries = enabled_items(content, &children, &items);
+            let highlighted = match event.logical_key {
+                Key::Enter | Key::Space | Key::ArrowDown => entries.first().copied(),
+                Key::ArrowUp => entries.last().copied(),
+                _ => continue,
+            };
+            if let Ok(mut state) = panels.get_mut(content) {
+                if !state.open {
+                    state.open = true;
+                    state.highlighted = highlighted;
+                }
             }
-            if let Ok(mut vis) = content_q.get_mut(child) {
-                *vis = if open {
-                    Visibility::Inherited
-                } else {
-                    Visibility::Hidden
-                };
+            continue;
+        }
+
+        // Offene Untermenüs behandelt die gemeinsame Menü-Navigation
+        if panels.iter().any(|s| s.open && s.parent == Some(content)) {
+            continue;
+        }
+        let step: isize = match event.logical_key {
+            Key::ArrowLeft => -1,
+            Key::ArrowRight => {
+                let highlighted = panels.get(content).ok().and_then(|s| s.highlighted);
+                let opens_submenu =
+                    highlighted
+                        .and_then(|h| items.get(h).ok())
+                        .is_some_and(|item| {
+                            !item.disabled && matches!(item.kind, MenuItemKind::SubTrigger { .. })
+                        });
+                if opens_submenu {
+                    continue;
+                }
+                1
             }
+            _ => continue,
+        };
+        let len = list.len() as isize;
+        let next = index as isize + step;
+        let next = if group.loop_navigation {
+            next.rem_euclid(len) as usize
+        } else if (0..len).contains(&next) {
+            next as usize
+        } else {
+            continue;
+        };
+        if next == index {
+            continue;
+        }
+
+        let next_content = list[next].1;
+        let first = enabled_items(next_content, &children, &items)
+            .first()
+            .copied();
+        if let Ok(mut state) = panels.get_mut(content) {
+            state.open = false;
+        }
+        if let Ok(mut state) = panels.get_mut(next_content) {
+            state.open = true;
+            state.highlighted = first;
+        }
+        index = next;
+    }
+}
+
+/// Mirrors the panel state into [`MenubarMenuState`] and highlights the
+/// trigger of the open menu.
+pub fn sync_menubar_state(
+    theme: Option<Res<UiTheme>>,
+    hierarchy: PortalHierarchy,
+    panels: Query<
+        (Entity, &MenuContentState),
+        (With<MenubarMenuContentMarker>, Changed<MenuContentState>),
+    >,
+    mut menus: Query<&mut MenubarMenuState>,
+    mut triggers: Query<&mut BackgroundColor, With<MenubarMenuTriggerMarker>>,
+) {
+    let Some(theme) = theme else {
+        return;
+    };
+    for (content, state) in panels.iter() {
+        let Some(menu) = hierarchy.parent(content) else {
+            continue;
+        };
+        if let Ok(mut menu_state) = menus.get_mut(menu) {
+            if menu_state.open != state.open {
+                menu_state.open = state.open;
+            }
+        }
+        let wanted = MenubarMenuTriggerStyle::background(&theme, state.open);
+        for child in hierarchy.children(menu) {
+            if let Ok(mut background) = triggers.get_mut(child) {
+                if background.0 != wanted.0 {
+                    *background = wanted;
+                }
+            }
+        }
+    }
+}
+
+/// Turns chosen items of menubar menus into typed
+/// [`MenubarItemSelected<A>`] events.
+pub fn send_menubar_selection<A: Component + Clone + Send + Sync>(
+    mut activated: EventReader<MenuItemActivatedEvent>,
+    contents: Query<(), With<MenubarMenuContentMarker>>,
+    bars: Query<(), With<MenubarMarker>>,
+    hierarchy: PortalHierarchy,
+    items: Query<(&MenuItem, Option<&A>)>,
+    mut selected: EventWriter<MenubarItemSelected<A>>,
+) {
+    for event in activated.read() {
+        if !contents.contains(event.menu) {
+            continue;
         }
-        state.open = open;
+        let Ok((item, action)) = items.get(event.item) else {
+            continue;
+        };
+        selected.write(MenubarItemSelected {
+            source_entity: event.item,
+            menubar: hierarchy
+                .ancestors(event.menu)
+                .find(|e| bars.contains(*e))
+                .unwrap_or(event.menu),
+            label: item.label.clone(),
+            action_id: action.cloned(),
+            checked: item.checked(),
+        });
     }
 }
@@ -19,20 +19,30 @@ pub mod card;
 pub mod checkbox;
 pub mod checkbox_cards;
 pub mod checkbox_group;
+pub mod clipboard;
 pub mod code;
 pub mod collapsible;
 pub mod collection;
+pub mod combobox;
 pub mod container;
 pub mod context;
 pub mod context_menu;
 pub mod dialog;
 pub mod direction_provider;
+pub mod disabled;
+pub mod dismissable_layer;
 pub mod dropdown_menu;
+pub mod field;
+pub mod floating;
+pub mod form;
 pub mod helper;
 pub mod hover_card;
+pub mod hover_intent;
 pub mod label;
+pub mod layer;
 pub mod menubar;
 pub mod navigation_menu;
+pub mod number_field;
 pub mod one_time_password_field;
 pub mod password_toggle_field;
 pub mod popover;
@@ -41,17 +51,22 @@ pub mod progress;
 pub mod radio;
 pub mod radio_cards;
 pub mod radio_group;
+pub mod roving_focus;
 pub mod scroll_area;
 pub mod select;
 pub mod separator;
+pub mod shortcut;
 pub mod slider;
 pub mod slot;
 pub mod switch;
 pub mod tabs;
+pub mod text_area;
+pub mod text_field;
 pub mod toast;
 pub mod toggle;
 pub mod toggle_group;
 pub mod toolbar;
 pub mod tooltip;
+pub mod transition;
 pub mod ui_box;
 pub mod visually_hidden;
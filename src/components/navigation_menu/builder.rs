@@ -1,55 +1,121 @@
+use bevy::input_focus::tab_navigation::TabIndex;
 use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
 
-use crate::components::helper::UiBuilder;
+use crate::components::dismissable_layer::DismissableLayer;
+use crate::components::floating::{Floating, FloatingAlign};
+use crate::components::helper::{NoAction, UiBuilder};
+use crate::components::hover_intent::HoverIntent;
 use crate::components::label::LabelBuilder;
+use crate::components::layer::{Layer, UiLayer};
+use crate::components::portal::Portal;
+use crate::components::roving_focus::{RovingFocusGroup, RovingFocusItem, RovingFocusOrientation};
+use crate::components::transition::{UiTransition, DEFAULT_TRANSITION_SECS};
 use crate::theme::UiTheme;
 
 use super::{
-    NavigationMenuContentMarker, NavigationMenuContentStyle, NavigationMenuItemMarker,
-    NavigationMenuItemState, NavigationMenuItemStyle, NavigationMenuLinkMarker,
-    NavigationMenuLinkStyle, NavigationMenuMarker, NavigationMenuStyle,
-    NavigationMenuTriggerMarker,
+    NavigationMenuContentMarker, NavigationMenuContentStyle, NavigationMenuIndicatorMarker,
+    NavigationMenuIndicatorStyle, NavigationMenuItemMarker, NavigationMenuItemState,
+    NavigationMenuItemStyle, NavigationMenuLinkLabel, NavigationMenuLinkMarker,
+    NavigationMenuLinkStyle, NavigationMenuListMarker, NavigationMenuListStyle,
+    NavigationMenuMarker, NavigationMenuState, NavigationMenuStyle, NavigationMenuTriggerMarker,
+    NavigationMenuTriggerStyle, NavigationMenuViewportMarker, NavigationMenuViewportStyle,
 };
 
+/// Duration of the slide when the viewport switches to another item's content.
+const CONTENT_SWAP_SECS: f32 = 0.2;
+
 /// Builder for a navigation menu item with optional submenu links.
-pub struct NavigationMenuItemBuilder {
+///
+/// Items with links open their content in the shared viewport. Items
+/// without links are plain links themselves.
+pub struct NavigationMenuItemBuilder<A: Component + Clone = NoAction> {
     label: String,
-    links: Vec<String>,
+    links: Vec<(String, Option<A>)>,
+    action: Option<A>,
 }
 
-impl NavigationMenuItemBuilder {
+impl<A: Component + Clone> NavigationMenuItemBuilder<A> {
     pub fn new(label: impl Into<String>) -> Self {
         Self {
             label: label.into(),
             links: Vec::new(),
+            action: None,
         }
     }
 
     /// Add a link label to this item.
     pub fn link(mut self, text: impl Into<String>) -> Self {
-        self.links.push(text.into());
+        self.links.push((text.into(), None));
+        self
+    }
+
+    /// Action of the link added last, or of the item itself while it has no
+    /// links. Sent with
+    /// [`NavigationMenuLinkSelected<A>`](super::NavigationMenuLinkSelected).
+    pub fn action(mut self, action: A) -> Self {
+        match self.links.last_mut() {
+            Some((_, link_action)) => *link_action = Some(action),
+            None => self.action = Some(action),
+        }
         self
     }
 }
 
 /// Builder for a navigation menu root element.
-pub struct NavigationMenuBuilder {
-    items: Vec<NavigationMenuItemBuilder>,
+///
+/// Hovering a trigger opens its content after a short delay, moving to
+/// another trigger switches right away. All contents share one floating
+/// viewport that resizes to the open content, and an indicator slides
+/// under the active trigger.
+///
+/// ```ignore
+/// NavigationMenuBuilder::<LauncherAction>::new()
+///     .item(
+///         NavigationMenuItemBuilder::new("Campaign")
+///             .link("Continue").action(LauncherAction::Continue)
+///             .link("New Game").action(LauncherAction::NewGame),
+///     )
+///     .item(NavigationMenuItemBuilder::new("Credits").action(LauncherAction::Credits))
+///     .spawn(parent, &theme, &font);
+/// ```
+pub struct NavigationMenuBuilder<A: Component + Clone = NoAction> {
+    items: Vec<NavigationMenuItemBuilder<A>>,
+    open_delay: f32,
+    close_delay: f32,
 }
 
-impl NavigationMenuBuilder {
+impl<A: Component + Clone> NavigationMenuBuilder<A> {
     pub fn new() -> Self {
-        Self { items: Vec::new() }
+        Self {
+            items: Vec::new(),
+            open_delay: 0.2,
+            close_delay: 0.3,
+        }
     }
 
     /// Add an item to the menu.
-    pub fn item(mut self, item: NavigationMenuItemBuilder) -> Self {
+    pub fn item(mut self, item: NavigationMenuItemBuilder<A>) -> Self {
         self.items.push(item);
         self
     }
+
+    /// Seconds the pointer has to rest on a trigger before its content opens
+    /// (default: `0.2`).
+    pub fn open_delay(mut self, secs: f32) -> Self {
+        self.open_delay = secs;
+        self
+    }
+
+    /// Seconds after the pointer left trigger and content until the content
+    /// closes (default: `0.3`).
+    pub fn close_delay(mut self, secs: f32) -> Self {
+        self.close_delay = secs;
+        self
+    }
 }
 
-impl<'w, 's> UiBuilder<'w, 's> for NavigationMenuBuilder {
+impl<'w, 's, A: Component + Clone> UiBuilder<'w, 's> for NavigationMenuBuilder<A> {
     type Output = Entity;
 
     fn spawn(
@@ -63,46 +129,130 @@ impl<'w, 's> UiBuilder<'w, 's> for NavigationMenuBuilder {
             NavigationMenuStyle::new(theme),
             Name::new("NavigationMenu"),
         ));
-        root.with_children(|rc| {
-            for item in self.items {
-                let mut item_cmd = rc.spawn((
-                    NavigationMenuItemMarker,
-                    NavigationMenuItemState { open: false },
-                    NavigationMenuItemStyle::new(theme),
-                ));
-                item_cmd.with_children(|ic| {
-                    ic.spawn((
-                        NavigationMenuTriggerMarker,
-                        Node {
-                            display: Display::Flex,
-                            align_items: AlignItems::Center,
-                            ..default()
-                        },
-                        Interaction::default(),
-                    ))
-                    .with_children(|tc| {
-                        let _ = LabelBuilder::new(item.label.clone()).spawn(tc, theme, font);
-                    });
+        // Zwischen Einträgen desselben Menüs ohne erneute Verzögerung wechseln
+        let group = format!("navigation_menu:{}", root.id());
+        let (open_delay, close_delay) = (self.open_delay, self.close_delay);
+        let mut state = None;
 
-                    let mut content_cmd = ic.spawn((
-                        NavigationMenuContentMarker,
-                        NavigationMenuContentStyle::new(theme),
-                        Visibility::Hidden,
+        root.with_children(|rc| {
+            let mut entries = Vec::new();
+            let mut indicator = Entity::PLACEHOLDER;
+            let mut list_cmd = rc.spawn((
+                NavigationMenuListMarker,
+                NavigationMenuListStyle::new(theme),
+                RovingFocusGroup::new(RovingFocusOrientation::Horizontal, true),
+            ));
+            let list = list_cmd.id();
+            list_cmd.with_children(|lc| {
+                for item in self.items {
+                    let mut item_cmd = lc.spawn((
+                        NavigationMenuItemMarker,
+                        NavigationMenuItemStyle::new(theme),
                     ));
-                    content_cmd.with_children(|cc| {
-                        for link in &item.links {
-                            let mut link_cmd = cc.spawn((
+                    let item_entity = item_cmd.id();
+                    let mut trigger = Entity::PLACEHOLDER;
+                    item_cmd.with_children(|ic| {
+                        let mut trigger_cmd = ic.spawn((
+                            NavigationMenuTriggerMarker,
+                            RovingFocusItem,
+                            NavigationMenuTriggerStyle::new(theme),
+                            Interaction::default(),
+                            FocusPolicy::Block,
+                        ));
+                        if item.links.is_empty() {
+                            trigger_cmd.insert((
                                 NavigationMenuLinkMarker,
-                                NavigationMenuLinkStyle::new(theme),
+                                NavigationMenuLinkLabel(item.label.clone()),
                             ));
-                            link_cmd.with_children(|lc| {
-                                let _ = LabelBuilder::new(link.clone()).spawn(lc, theme, font);
-                            });
+                            if let Some(action) = item.action {
+                                trigger_cmd.insert(action);
+                            }
                         }
+                        trigger_cmd.with_children(|tc| {
+                            let _ = LabelBuilder::new(item.label.clone()).spawn(tc, theme, font);
+                        });
+                        trigger = trigger_cmd.id();
+                    });
+                    entries.push((item_entity, trigger, item.links));
+                }
+
+                indicator = lc
+                    .spawn((
+                        NavigationMenuIndicatorMarker,
+                        NavigationMenuIndicatorStyle::new(theme),
+                        UiTransition::new(DEFAULT_TRANSITION_SECS).fade(),
+                        Visibility::Hidden,
+                    ))
+                    .id();
+            });
+
+            let mut viewport_cmd = rc.spawn((
+                NavigationMenuViewportMarker,
+                NavigationMenuViewportStyle::new(theme),
+                Floating::new(list).align(FloatingAlign::Start),
+                Portal::global(),
+                Layer(UiLayer::Dropdown),
+                DismissableLayer::new().branch(list),
+                UiTransition::new(DEFAULT_TRANSITION_SECS)
+                    .fade()
+                    .scale(0.96),
+                Visibility::Hidden,
+                Name::new("NavigationMenuViewport"),
+            ));
+            let viewport = viewport_cmd.id();
+            viewport_cmd.with_children(|vc| {
+                for (item, trigger, links) in entries {
+                    let content = (!links.is_empty()).then(|| {
+                        vc.spawn((
+                            NavigationMenuContentMarker,
+                            NavigationMenuContentStyle::new(theme),
+                            UiTransition::new(CONTENT_SWAP_SECS).slide(Vec2::ZERO),
+                            Visibility::Hidden,
+                        ))
+                        .with_children(|cc| {
+                            for (text, action) in links {
+                                let mut link_cmd = cc.spawn((
+                                    NavigationMenuLinkMarker,
+                                    NavigationMenuLinkLabel(text.clone()),
+                                    NavigationMenuLinkStyle::new(theme),
+                                    Interaction::default(),
+                                    TabIndex(0),
+                                ));
+                                if let Some(action) = action {
+                                    link_cmd.insert(action);
+                                }
+                                link_cmd.with_children(|lc| {
+                                    let _ = LabelBuilder::new(text).spawn(lc, theme, font);
+                                });
+                            }
+                        })
+                        .id()
+                    });
+
+                    let mut item_cmd = vc.commands_mut().entity(item);
+                    item_cmd.insert(NavigationMenuItemState {
+                        open: false,
+                        trigger,
+                        content,
+                        suppressed: false,
                     });
-                });
-            }
+                    if let Some(content) = content {
+                        item_cmd.insert(
+                            HoverIntent::new(trigger, content)
+                                .open_delay(open_delay)
+                                .close_delay(close_delay)
+                                .group(group.clone(), close_delay),
+                        );
+                    }
+                }
+            });
+
+            state = Some(NavigationMenuState::new(list, indicator, viewport));
         });
+
+        if let Some(state) = state {
+            root.insert(state);
+        }
         root.id()
     }
 }
@@ -1,8 +1,14 @@
 use bevy::prelude::*;
 
+use crate::components::direction_provider::RuntimePosition;
+
 #[derive(Component, Debug, Clone, Copy, Default)]
 pub struct NavigationMenuMarker;
 
+/// Row holding the triggers and the active indicator.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct NavigationMenuListMarker;
+
 #[derive(Component, Debug, Clone, Copy, Default)]
 pub struct NavigationMenuItemMarker;
 
@@ -15,7 +21,58 @@ pub struct NavigationMenuContentMarker;
 #[derive(Component, Debug, Clone, Copy, Default)]
 pub struct NavigationMenuLinkMarker;
 
+/// Bar that slides under the trigger of the open item.
+#[derive(Component, Debug, Clone, Copy, Default)]
+#[require(RuntimePosition)]
+pub struct NavigationMenuIndicatorMarker;
+
+/// Floating panel shared by all items. It shows the content of the open
+/// item and resizes to it.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct NavigationMenuViewportMarker;
+
+/// Text of a link, reported in
+/// [`NavigationMenuLinkSelected`](super::NavigationMenuLinkSelected).
+#[derive(Component, Debug, Clone)]
+pub struct NavigationMenuLinkLabel(pub String);
+
+/// State of a navigation menu root.
+#[derive(Component, Debug, Clone)]
+pub struct NavigationMenuState {
+    /// Item whose content is shown, `None` while the menu is closed.
+    pub active: Option<Entity>,
+    pub(crate) list: Entity,
+    pub(crate) indicator: Entity,
+    pub(crate) viewport: Entity,
+    /// Item shown last, to slide new content in from the right side.
+    pub(crate) shown: Option<Entity>,
+    /// Jump to the new size instead of animating, set when opening.
+    pub(crate) snap: bool,
+    /// Link chosen this frame.
+    pub(crate) chosen: Option<Entity>,
+}
+
+impl NavigationMenuState {
+    pub(crate) fn new(list: Entity, indicator: Entity, viewport: Entity) -> Self {
+        Self {
+            active: None,
+            list,
+            indicator,
+            viewport,
+            shown: None,
+            snap: false,
+            chosen: None,
+        }
+    }
+}
+
+/// State of a single item.
 #[derive(Component, Debug, Clone)]
 pub struct NavigationMenuItemState {
     pub open: bool,
+    pub trigger: Entity,
+    /// Content shown in the viewport, `None` for items that are plain links.
+    pub content: Option<Entity>,
+    /// Closed by click while hovered; stays closed until the pointer leaves.
+    pub(crate) suppressed: bool,
 }
@@ -0,0 +1,33 @@
+use bevy::prelude::{Component, Entity, Event};
+use std::fmt::Debug;
+
+/// Sent when a link of a navigation menu is clicked or activated with Enter
+/// or Space.
+///
+/// Generic over the action type `A` set with
+/// [`NavigationMenuItemBuilder::action`](super::NavigationMenuItemBuilder::action);
+/// each type needs its own
+/// [`NavigationMenuActionPlugin`](super::NavigationMenuActionPlugin).
+#[derive(Event, Clone)]
+pub struct NavigationMenuLinkSelected<A: Component + Clone + Send + Sync> {
+    /// The chosen link, or the trigger of an item without content.
+    pub source_entity: Entity,
+    /// Root entity of the navigation menu.
+    pub menu: Entity,
+    /// Text of the link.
+    pub label: String,
+    /// Clone of the link's action, `None` if it has none.
+    pub action_id: Option<A>,
+}
+
+// Wie beim ButtonClickedEvent: A muss kein Debug implementieren
+impl<A: Component + Clone + Send + Sync> Debug for NavigationMenuLinkSelected<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NavigationMenuLinkSelected")
+            .field("source_entity", &self.source_entity)
+            .field("menu", &self.menu)
+            .field("label", &self.label)
+            .field("action_id", &self.action_id.as_ref().map(|_| "Some<A>"))
+            .finish()
+    }
+}
@@ -1,16 +1,21 @@
 mod builder;
 mod components;
+mod events;
 mod plugin;
 mod style;
 mod systems;
 
 pub use builder::{NavigationMenuBuilder, NavigationMenuItemBuilder};
 pub use components::{
-    NavigationMenuContentMarker, NavigationMenuItemMarker, NavigationMenuItemState,
-    NavigationMenuLinkMarker, NavigationMenuMarker, NavigationMenuTriggerMarker,
+    NavigationMenuContentMarker, NavigationMenuIndicatorMarker, NavigationMenuItemMarker,
+    NavigationMenuItemState, NavigationMenuLinkLabel, NavigationMenuLinkMarker,
+    NavigationMenuListMarker, NavigationMenuMarker, NavigationMenuState,
+    NavigationMenuTriggerMarker, NavigationMenuViewportMarker,
 };
-pub use plugin::NavigationMenuPlugin;
+pub use events::NavigationMenuLinkSelected;
+pub use plugin::{NavigationMenuActionPlugin, NavigationMenuPlugin};
 pub use style::{
-    NavigationMenuContentStyle, NavigationMenuItemStyle, NavigationMenuLinkStyle,
-    NavigationMenuStyle,
+    NavigationMenuContentStyle, NavigationMenuIndicatorStyle, NavigationMenuItemStyle,
+    NavigationMenuLinkStyle, NavigationMenuListStyle, NavigationMenuStyle,
+    NavigationMenuTriggerStyle, NavigationMenuViewportStyle,
 };
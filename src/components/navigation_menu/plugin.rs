@@ -1,9 +1,14 @@
 use bevy::prelude::*;
+use std::marker::PhantomData;
 
+use super::events::NavigationMenuLinkSelected;
+use super::systems::*;
+use crate::components::helper::NoAction;
 use crate::plugin::UiState;
 
-use super::systems::handle_navigation_menu_interaction;
-
+/// Opens and closes navigation menu contents and animates the shared
+/// viewport and the active indicator. The hover delays come from
+/// [`HoverIntent`](crate::components::hover_intent::HoverIntent).
 pub struct NavigationMenuPlugin;
 
 impl Default for NavigationMenuPlugin {
@@ -14,6 +19,45 @@ impl Default for NavigationMenuPlugin {
 
 impl Plugin for NavigationMenuPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, handle_navigation_menu_interaction.run_if(in_state(UiState::Ready)));
+        app.add_systems(
+            Update,
+            (
+                handle_navigation_menu_trigger_press,
+                handle_navigation_menu_keyboard,
+                handle_navigation_menu_link_press,
+                apply_navigation_menu_selection,
+                update_navigation_menu_state,
+                animate_navigation_menu,
+                update_navigation_menu_visuals,
+            )
+                .chain()
+                .run_if(in_state(UiState::Ready)),
+        );
+    }
+}
+
+/// Registers [`NavigationMenuLinkSelected<A>`] for the action type `A`.
+/// Add one per action type used with
+/// [`NavigationMenuBuilder`](super::NavigationMenuBuilder).
+pub struct NavigationMenuActionPlugin<A: Component + Clone + Send + Sync = NoAction>(
+    PhantomData<A>,
+);
+
+impl<A: Component + Clone + Send + Sync> Default for NavigationMenuActionPlugin<A> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A: Component + Clone + Send + Sync> Plugin for NavigationMenuActionPlugin<A> {
+    fn build(&self, app: &mut App) {
+        app.add_event::<NavigationMenuLinkSelected<A>>()
+            .add_systems(
+                Update,
+                send_navigation_menu_selection::<A>
+                    .after(handle_navigation_menu_link_press)
+                    .before(apply_navigation_menu_selection)
+                    .run_if(in_state(UiState::Ready)),
+            );
     }
 }
@@ -1,20 +1,43 @@
 use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
 
 use crate::theme::UiTheme;
 
 #[derive(Bundle, Clone, Debug)]
 pub struct NavigationMenuStyle {
     pub node: Node,
-    pub background_color: BackgroundColor,
-    pub border_radius: BorderRadius,
 }
 
 impl NavigationMenuStyle {
-    pub fn new(theme: &UiTheme) -> Self {
+    pub fn new(_theme: &UiTheme) -> Self {
         NavigationMenuStyle {
             node: Node {
                 display: Display::Flex,
                 flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Start,
+                ..default()
+            },
+        }
+    }
+}
+
+/// Style for the row of triggers.
+#[derive(Bundle, Clone, Debug)]
+pub struct NavigationMenuListStyle {
+    pub node: Node,
+    pub background_color: BackgroundColor,
+    pub border_radius: BorderRadius,
+}
+
+impl NavigationMenuListStyle {
+    pub fn new(theme: &UiTheme) -> Self {
+        NavigationMenuListStyle {
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(theme.layout.gap.xs),
+                padding: UiRect::all(Val::Px(theme.layout.padding.xs)),
                 ..default()
             },
             background_color: BackgroundColor(theme.color.gray.step02),
@@ -26,43 +49,130 @@ impl NavigationMenuStyle {
 #[derive(Bundle, Clone, Debug)]
 pub struct NavigationMenuItemStyle {
     pub node: Node,
-    pub background_color: BackgroundColor,
-    pub border_radius: BorderRadius,
 }
 
 impl NavigationMenuItemStyle {
-    pub fn new(theme: &UiTheme) -> Self {
+    pub fn new(_theme: &UiTheme) -> Self {
         NavigationMenuItemStyle {
             node: Node {
                 display: Display::Flex,
                 flex_direction: FlexDirection::Column,
                 ..default()
             },
-            background_color: BackgroundColor(theme.color.gray.step02),
+        }
+    }
+}
+
+/// Style for the trigger of an item.
+#[derive(Bundle, Clone, Debug)]
+pub struct NavigationMenuTriggerStyle {
+    pub node: Node,
+    pub background_color: BackgroundColor,
+    pub border_radius: BorderRadius,
+}
+
+impl NavigationMenuTriggerStyle {
+    pub fn new(theme: &UiTheme) -> Self {
+        NavigationMenuTriggerStyle {
+            node: Node {
+                display: Display::Flex,
+                align_items: AlignItems::Center,
+                padding: UiRect::axes(
+                    Val::Px(theme.layout.padding.sm),
+                    Val::Px(theme.layout.padding.xs),
+                ),
+                ..default()
+            },
+            background_color: Self::background(theme, false),
             border_radius: BorderRadius::all(Val::Px(theme.layout.radius.xs)),
         }
     }
+
+    /// Background of the trigger, highlighted while hovered or open.
+    pub fn background(theme: &UiTheme, highlighted: bool) -> BackgroundColor {
+        if highlighted {
+            BackgroundColor(theme.color.gray.step04)
+        } else {
+            BackgroundColor(Color::NONE)
+        }
+    }
 }
 
+/// Style for the bar under the trigger of the open item.
 #[derive(Bundle, Clone, Debug)]
-pub struct NavigationMenuContentStyle {
+pub struct NavigationMenuIndicatorStyle {
+    pub node: Node,
+    pub background_color: BackgroundColor,
+    pub border_radius: BorderRadius,
+}
+
+impl NavigationMenuIndicatorStyle {
+    pub fn new(theme: &UiTheme) -> Self {
+        NavigationMenuIndicatorStyle {
+            node: Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(0.0),
+                left: Val::Px(0.0),
+                width: Val::Px(0.0),
+                height: Val::Px(2.0),
+                ..default()
+            },
+            background_color: BackgroundColor(theme.accent.step09),
+            border_radius: BorderRadius::all(Val::Px(1.0)),
+        }
+    }
+}
+
+/// Style for the shared viewport. Its size is set by the navigation menu
+/// systems; content that does not fit yet is clipped.
+#[derive(Bundle, Clone, Debug)]
+pub struct NavigationMenuViewportStyle {
     pub node: Node,
     pub background_color: BackgroundColor,
     pub border_color: BorderColor,
     pub border_radius: BorderRadius,
+    pub focus_policy: FocusPolicy,
+}
+
+impl NavigationMenuViewportStyle {
+    pub fn new(theme: &UiTheme) -> Self {
+        NavigationMenuViewportStyle {
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::FlexStart,
+                width: Val::Px(0.0),
+                height: Val::Px(0.0),
+                border: UiRect::all(Val::Px(1.0)),
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            background_color: BackgroundColor(theme.color.gray.step02),
+            border_color: BorderColor(theme.color.gray.step06),
+            border_radius: BorderRadius::all(Val::Px(theme.layout.radius.sm)),
+            focus_policy: FocusPolicy::Block,
+        }
+    }
+}
+
+#[derive(Bundle, Clone, Debug)]
+pub struct NavigationMenuContentStyle {
+    pub node: Node,
 }
 
 impl NavigationMenuContentStyle {
     pub fn new(theme: &UiTheme) -> Self {
         NavigationMenuContentStyle {
             node: Node {
-                display: Display::Flex,
+                display: Display::None,
                 flex_direction: FlexDirection::Column,
+                // Eigene Größe behalten, auch wenn der Viewport noch kleiner ist
+                flex_shrink: 0.0,
+                row_gap: Val::Px(theme.layout.gap.xs),
+                padding: UiRect::all(Val::Px(theme.layout.padding.sm)),
+                min_width: Val::Px(180.0),
                 ..default()
             },
-            background_color: BackgroundColor(theme.color.gray.step03),
-            border_color: BorderColor(theme.color.gray.step06),
-            border_radius: BorderRadius::all(Val::Px(theme.layout.radius.xs)),
         }
     }
 }
@@ -83,8 +193,17 @@ impl NavigationMenuLinkStyle {
                 padding: UiRect::all(Val::Px(theme.layout.padding.xs)),
                 ..default()
             },
-            background_color: BackgroundColor(theme.color.gray.step04),
+            background_color: Self::background(theme, false),
             border_radius: BorderRadius::all(Val::Px(theme.layout.radius.xs)),
         }
     }
+
+    /// Background of a link, highlighted while hovered or focused.
+    pub fn background(theme: &UiTheme, highlighted: bool) -> BackgroundColor {
+        if highlighted {
+            BackgroundColor(theme.color.gray.step04)
+        } else {
+            BackgroundColor(Color::NONE)
+        }
+    }
 }
@@ -1,25 +1,451 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::input_focus::InputFocus;
 use bevy::prelude::*;
 
-use super::{
-    NavigationMenuContentMarker, NavigationMenuItemMarker, NavigationMenuItemState,
-    NavigationMenuTriggerMarker,
-};
+use super::*;
+use crate::components::disabled::InheritedDisabled;
+use crate::components::dismissable_layer::LayerDismissedEvent;
+use crate::components::hover_intent::HoverIntent;
+use crate::components::portal::PortalHierarchy;
+use crate::components::transition::UiTransition;
+use crate::plugin::UiConfig;
+use crate::theme::UiTheme;
 
-pub fn handle_navigation_menu_interaction(
-    mut item_q: Query<(&mut NavigationMenuItemState, &Children), With<NavigationMenuItemMarker>>,
-    trigger_q: Query<&Interaction, With<NavigationMenuTriggerMarker>>,
-    mut content_q: Query<&mut Visibility, With<NavigationMenuContentMarker>>,
+/// How fast viewport and indicator follow their target, per second.
+const FOLLOW_RATE: f32 = 18.0;
+/// Distance in logical pixels new content slides in from.
+const CONTENT_SLIDE: f32 = 24.0;
+
+/// Screen rectangle of a UI node in logical pixels.
+fn logical_rect(node: &ComputedNode, transform: &GlobalTransform) -> Rect {
+    let scale = node.inverse_scale_factor();
+    let center = transform.translation().truncate();
+    Rect::from_center_size(center * scale, node.size() * scale)
+}
+
+fn px_or_zero(val: Val) -> f32 {
+    match val {
+        Val::Px(v) => v,
+        _ => 0.0,
+    }
+}
+
+/// Moves `val` towards `target` by `t` and returns whether it changed.
+fn follow(val: &mut Val, target: f32, t: f32) -> bool {
+    let current = px_or_zero(*val);
+    let next = if (target - current).abs() < 0.5 {
+        target
+    } else {
+        current + (target - current) * t
+    };
+    if next == current && matches!(*val, Val::Px(_)) {
+        return false;
+    }
+    *val = Val::Px(next);
+    true
+}
+
+/// Opens a closed item right away and closes an open one. A closed item
+/// stays closed until the pointer left its trigger.
+fn toggle_item(state: &mut NavigationMenuItemState, intent: &mut HoverIntent) {
+    let open = !intent.is_open();
+    intent.set_open(open);
+    state.suppressed = !open;
+}
+
+/// Clicking a trigger toggles its content without waiting for the hover
+/// delay.
+pub fn handle_navigation_menu_trigger_press(
+    mut items: Query<(&mut NavigationMenuItemState, &mut HoverIntent)>,
+    triggers: Query<
+        Ref<Interaction>,
+        (
+            With<NavigationMenuTriggerMarker>,
+            Without<InheritedDisabled>,
+        ),
+    >,
 ) {
-    for (mut state, children) in item_q.iter_mut() {
-        let mut open = state.open;
-        for child in children.iter() {
-            if let Ok(interaction) = trigger_q.get(child) {
-                open = *interaction == Interaction::Hovered;
+    for (mut state, mut intent) in items.iter_mut() {
+        let Ok(interaction) = triggers.get(state.trigger) else {
+            continue;
+        };
+        if interaction.is_changed() && *interaction == Interaction::Pressed {
+            toggle_item(&mut state, &mut intent);
+        } else if state.suppressed {
+            if *interaction == Interaction::None {
+                state.suppressed = false;
+            } else if intent.is_open() {
+                intent.set_open(false);
             }
-            if let Ok(mut vis) = content_q.get_mut(child) {
-                *vis = if open { Visibility::Inherited } else { Visibility::Hidden };
+        }
+    }
+}
+
+/// Enter and Space on a focused trigger toggle its content, Arrow Down
+/// opens it. On a focused link they choose the link.
+pub fn handle_navigation_menu_keyboard(
+    focus: Res<InputFocus>,
+    mut key_events: EventReader<KeyboardInput>,
+    links: Query<(), (With<NavigationMenuLinkMarker>, Without<InheritedDisabled>)>,
+    triggers: Query<
+        &ChildOf,
+        (
+            With<NavigationMenuTriggerMarker>,
+            Without<InheritedDisabled>,
+        ),
+    >,
+    hierarchy: PortalHierarchy,
+    mut menus: Query<&mut NavigationMenuState>,
+    mut items: Query<(&mut NavigationMenuItemState, &mut HoverIntent)>,
+) {
+    let Some(focused) = focus.get() else {
+        key_events.clear();
+        return;
+    };
+
+    for event in key_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        let confirm = matches!(event.logical_key, Key::Enter | Key::Space);
+        if links.contains(focused) {
+            if !confirm {
+                continue;
+            }
+            let Some(menu) = hierarchy.ancestors(focused).find(|e| menus.contains(*e)) else {
+                continue;
+            };
+            if let Ok(mut state) = menus.get_mut(menu) {
+                state.chosen = Some(focused);
+            }
+        } else if let Ok(child_of) = triggers.get(focused) {
+            let Ok((mut state, mut intent)) = items.get_mut(child_of.parent()) else {
+                continue;
+            };
+            if confirm {
+                toggle_item(&mut state, &mut intent);
+            } else if event.logical_key == Key::ArrowDown && !intent.is_open() {
+                intent.set_open(true);
             }
         }
-        state.open = open;
+    }
+}
+
+/// Chooses links when they are pressed.
+pub fn handle_navigation_menu_link_press(
+    links: Query<
+        (Entity, &Interaction),
+        (
+            With<NavigationMenuLinkMarker>,
+            Changed<Interaction>,
+            Without<InheritedDisabled>,
+        ),
+    >,
+    hierarchy: PortalHierarchy,
+    mut menus: Query<&mut NavigationMenuState>,
+) {
+    for (link, interaction) in links.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(menu) = hierarchy.ancestors(link).find(|e| menus.contains(*e)) else {
+            continue;
+        };
+        if let Ok(mut state) = menus.get_mut(menu) {
+            state.chosen = Some(link);
+        }
+    }
+}
+
+/// Closes the menu once a link was chosen.
+pub fn apply_navigation_menu_selection(
+    mut menus: Query<&mut NavigationMenuState>,
+    children: Query<&Children>,
+    mut intents: Query<&mut HoverIntent, With<NavigationMenuItemMarker>>,
+) {
+    for mut menu in menus.iter_mut() {
+        if menu.chosen.is_none() {
+            continue;
+        }
+        menu.chosen = None;
+        for item in children.get(menu.list).into_iter().flat_map(|c| c.iter()) {
+            if let Ok(mut intent) = intents.get_mut(item) {
+                if intent.is_open() {
+                    intent.set_open(false);
+                }
+            }
+        }
+    }
+}
+
+/// Keeps at most one item open per menu: a newly opened item replaces the
+/// active one right away. Swaps the content shown in the viewport, sliding
+/// it in from the side of the previous item, and shows or hides viewport
+/// and indicator.
+pub fn update_navigation_menu_state(
+    mut dismissed: EventReader<LayerDismissedEvent>,
+    mut focus: ResMut<InputFocus>,
+    mut menus: Query<&mut NavigationMenuState>,
+    mut items: Query<(&mut NavigationMenuItemState, &mut HoverIntent)>,
+    children: Query<&Children>,
+    parents: Query<&ChildOf>,
+    mut contents: Query<
+        (&mut Node, &mut Visibility, &mut UiTransition),
+        With<NavigationMenuContentMarker>,
+    >,
+    mut overlays: Query<
+        &mut Visibility,
+        (
+            Or<(
+                With<NavigationMenuViewportMarker>,
+                With<NavigationMenuIndicatorMarker>,
+            )>,
+            Without<NavigationMenuContentMarker>,
+        ),
+    >,
+) {
+    let dismissed: Vec<Entity> = dismissed.read().map(|e| e.layer).collect();
+
+    for mut menu in menus.iter_mut() {
+        let list: Vec<Entity> = children
+            .get(menu.list)
+            .map(|c| c.iter().filter(|e| items.contains(*e)).collect())
+            .unwrap_or_default();
+
+        // Escape, Klick oder Fokus außerhalb schließen das ganze Menü
+        let close_all = dismissed.contains(&menu.viewport);
+        let mut open = Vec::new();
+        for item in &list {
+            let Ok((_, mut intent)) = items.get_mut(*item) else {
+                continue;
+            };
+            if close_all && intent.is_open() {
+                intent.set_open(false);
+            }
+            if intent.is_open() {
+                open.push(*item);
+            }
+        }
+
+        // Neu geöffnete Einträge verdrängen den aktiven ohne Schließverzögerung
+        let active = open
+            .iter()
+            .copied()
+            .find(|e| Some(*e) != menu.active)
+            .or_else(|| menu.active.filter(|a| open.contains(a)));
+        for item in &list {
+            let Ok((mut state, mut intent)) = items.get_mut(*item) else {
+                continue;
+            };
+            let is_active = Some(*item) == active;
+            if intent.is_open() && !is_active {
+                intent.set_open(false);
+            }
+            if state.open != is_active {
+                state.open = is_active;
+            }
+        }
+
+        if active == menu.active {
+            continue;
+        }
+        let previous = menu.active;
+        menu.active = active;
+        if previous.is_none() {
+            menu.snap = true;
+        }
+
+        for entity in [menu.viewport, menu.indicator] {
+            if let Ok(mut visibility) = overlays.get_mut(entity) {
+                *visibility = if active.is_some() {
+                    Visibility::Inherited
+                } else {
+                    Visibility::Hidden
+                };
+            }
+        }
+
+        // Fokus aus dem geschlossenen Inhalt zurück auf den Auslöser
+        if active.is_none() {
+            let in_content = focus
+                .get()
+                .and_then(|f| parents.get(f).ok())
+                .is_some_and(|p| contents.contains(p.parent()));
+            if in_content {
+                if let Some((state, _)) = previous.and_then(|p| items.get(p).ok()) {
+                    focus.set(state.trigger);
+                }
+            }
+            continue;
+        }
+
+        let Some(content) = active
+            .and_then(|a| items.get(a).ok())
+            .and_then(|(state, _)| state.content)
+        else {
+            continue;
+        };
+        if menu.shown == Some(content) {
+            continue;
+        }
+        // Nur beim Wechsel zwischen Einträgen seitlich hereinschieben
+        let offset = match (previous, active) {
+            (Some(from), Some(to)) => {
+                let from = list.iter().position(|e| *e == from);
+                let to = list.iter().position(|e| *e == to);
+                let dir = if to > from { 1.0 } else { -1.0 };
+                Vec2::new(CONTENT_SLIDE * dir, 0.0)
+            }
+            _ => Vec2::ZERO,
+        };
+        if let Some(old) = menu.shown {
+            if let Ok((mut node, mut visibility, _)) = contents.get_mut(old) {
+                node.display = Display::None;
+                *visibility = Visibility::Hidden;
+            }
+        }
+        if let Ok((mut node, mut visibility, mut transition)) = contents.get_mut(content) {
+            node.display = Display::Flex;
+            transition.set_slide_offset(offset);
+            *visibility = Visibility::Inherited;
+        }
+        menu.shown = Some(content);
+    }
+}
+
+/// Resizes the viewport to the shown content and moves the indicator under
+/// the active trigger. Both ease towards their target; right after opening
+/// or with `UiConfig::reduced_motion` they jump there.
+pub fn animate_navigation_menu(
+    time: Res<Time>,
+    config: Res<UiConfig>,
+    mut menus: Query<&mut NavigationMenuState>,
+    items: Query<&NavigationMenuItemState>,
+    layout: Query<(&ComputedNode, &GlobalTransform)>,
+    mut nodes: Query<
+        &mut Node,
+        Or<(
+            With<NavigationMenuViewportMarker>,
+            With<NavigationMenuIndicatorMarker>,
+        )>,
+    >,
+) {
+    let eased = 1.0 - (-FOLLOW_RATE * time.delta_secs()).exp();
+
+    for mut menu in menus.iter_mut() {
+        let Some(item) = menu.active.and_then(|a| items.get(a).ok()) else {
+            continue;
+        };
+        let rect = |e: Entity| layout.get(e).ok().map(|(n, t)| logical_rect(n, t));
+        let (Some(list), Some(trigger)) = (rect(menu.list), rect(item.trigger)) else {
+            continue;
+        };
+        let content = menu
+            .shown
+            .and_then(|c| layout.get(c).ok())
+            .map(|(n, _)| n.size() * n.inverse_scale_factor());
+        let viewport_border = layout
+            .get(menu.viewport)
+            .map(|(n, _)| {
+                let border = n.border();
+                Vec2::new(border.left + border.right, border.top + border.bottom)
+                    * n.inverse_scale_factor()
+            })
+            .unwrap_or_default();
+
+        // Neu sichtbarer Inhalt hat erst nach dem nächsten Layout eine Größe
+        let Some(content) = content.filter(|size| size.x > 0.0 && size.y > 0.0) else {
+            continue;
+        };
+        let t = if config.reduced_motion || menu.snap {
+            1.0
+        } else {
+            eased
+        };
+        if menu.snap {
+            menu.snap = false;
+        }
+
+        if let Ok(mut node) = nodes.get_mut(menu.indicator) {
+            let (mut left, mut width) = (node.left, node.width);
+            let moved = follow(&mut left, trigger.min.x - list.min.x, t)
+                | follow(&mut width, trigger.width(), t);
+            if moved {
+                node.left = left;
+                node.width = width;
+            }
+        }
+        if let Ok(mut node) = nodes.get_mut(menu.viewport) {
+            let target = content + viewport_border;
+            let (mut width, mut height) = (node.width, node.height);
+            let moved = follow(&mut width, target.x, t) | follow(&mut height, target.y, t);
+            if moved {
+                node.width = width;
+                node.height = height;
+            }
+        }
+    }
+}
+
+/// Highlights hovered, focused and open triggers as well as hovered and
+/// focused links.
+pub fn update_navigation_menu_visuals(
+    theme: Option<Res<UiTheme>>,
+    focus: Res<InputFocus>,
+    items: Query<&NavigationMenuItemState>,
+    mut triggers: Query<
+        (Entity, &Interaction, &ChildOf, &mut BackgroundColor),
+        With<NavigationMenuTriggerMarker>,
+    >,
+    mut links: Query<
+        (Entity, &Interaction, &mut BackgroundColor),
+        (
+            With<NavigationMenuLinkMarker>,
+            Without<NavigationMenuTriggerMarker>,
+        ),
+    >,
+) {
+    let Some(theme) = theme else {
+        return;
+    };
+    let focused = focus.get();
+
+    for (entity, interaction, child_of, mut background) in triggers.iter_mut() {
+        let open = items.get(child_of.parent()).is_ok_and(|s| s.open);
+        let highlighted = open || *interaction != Interaction::None || focused == Some(entity);
+        let wanted = NavigationMenuTriggerStyle::background(&theme, highlighted);
+        if background.0 != wanted.0 {
+            *background = wanted;
+        }
+    }
+    for (entity, interaction, mut background) in links.iter_mut() {
+        let highlighted = *interaction != Interaction::None || focused == Some(entity);
+        let wanted = NavigationMenuLinkStyle::background(&theme, highlighted);
+        if background.0 != wanted.0 {
+            *background = wanted;
+        }
+    }
+}
+
+/// Turns chosen links into typed [`NavigationMenuLinkSelected<A>`] events.
+pub fn send_navigation_menu_selection<A: Component + Clone + Send + Sync>(
+    menus: Query<(Entity, &NavigationMenuState)>,
+    links: Query<(&NavigationMenuLinkLabel, Option<&A>)>,
+    mut selected: EventWriter<NavigationMenuLinkSelected<A>>,
+) {
+    for (menu, state) in menus.iter() {
+        let Some(link) = state.chosen else {
+            continue;
+        };
+        let Ok((label, action)) = links.get(link) else {
+            continue;
+        };
+        selected.write(NavigationMenuLinkSelected {
+            source_entity: link,
+            menu,
+            label: label.0.clone(),
+            action_id: action.cloned(),
+        });
     }
 }
@@ -0,0 +1,174 @@
+use bevy::input_focus::tab_navigation::TabIndex;
+use bevy::prelude::*;
+use bevy::text::LineBreak;
+use bevy::ui::{FocusPolicy, RelativeCursorPosition};
+
+use super::*;
+use crate::components::button::{ButtonBuilder, ButtonSize, ButtonVariant};
+use crate::components::disabled::Disabled;
+use crate::components::helper::{NoAction, UiBuilder};
+use crate::components::text_field::{spawn_text_field_viewport, TextFieldState, TextFieldStyle};
+use crate::theme::UiTheme;
+
+/// Builder for a numeric input with increment and decrement buttons.
+///
+/// The value is stepped with the buttons, the mouse wheel (while focused and
+/// hovered), ArrowUp/ArrowDown (Shift for ten steps, PageUp/PageDown as
+/// well) or by dragging the optional label. Typed input is validated on the
+/// fly and committed on Enter or when the field loses focus. Every change is
+/// reported through [`NumberChangedEvent`].
+pub struct NumberFieldBuilder {
+    state: NumberFieldState,
+    label: Option<String>,
+    width: Option<Val>,
+    disabled: bool,
+}
+
+impl NumberFieldBuilder {
+    pub fn new() -> Self {
+        Self {
+            state: NumberFieldState::default(),
+            label: None,
+            width: None,
+            disabled: false,
+        }
+    }
+
+    /// Initial value, clamped to the range when spawned.
+    pub fn value(mut self, value: f64) -> Self {
+        self.state.value = value;
+        self
+    }
+
+    /// Lower bound; `NaN` means unbounded.
+    pub fn min(mut self, min: f64) -> Self {
+        self.state.min = if min.is_nan() { f64::NEG_INFINITY } else { min };
+        self
+    }
+
+    /// Upper bound; `NaN` means unbounded.
+    pub fn max(mut self, max: f64) -> Self {
+        self.state.max = if max.is_nan() { f64::INFINITY } else { max };
+        self
+    }
+
+    /// Amount added or removed per step, `1.0` by default.
+    pub fn step(mut self, step: f64) -> Self {
+        self.state.step = step.abs();
+        self
+    }
+
+    /// Number of decimal places, `0` by default.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.state.precision = precision;
+        self
+    }
+
+    /// Label in front of the input; dragging it scrubs the value.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Width of the input itself, buttons and label come on top.
+    pub fn width(mut self, width: Val) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl Default for NumberFieldBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'w, 's> UiBuilder<'w, 's> for NumberFieldBuilder {
+    type Output = Entity;
+
+    fn spawn(
+        self,
+        parent: &'s mut ChildSpawnerCommands<'w>,
+        theme: &UiTheme,
+        font: &Handle<Font>,
+    ) -> Self::Output {
+        let mut state = self.state;
+        // Vertauschte Grenzen wie `min(10.0).max(5.0)` richtigstellen
+        if state.min > state.max {
+            std::mem::swap(&mut state.min, &mut state.max);
+        }
+        state.value = state.constrain(state.value);
+
+        let mut input_style = TextFieldStyle::new(theme);
+        input_style.node.min_width = Val::Px(64.0);
+        if let Some(width) = self.width {
+            input_style.node.width = width;
+        }
+        let input_state = TextFieldState::new(state.format());
+
+        let mut root = parent.spawn((
+            NumberFieldMarker,
+            NumberFieldStyle::new(theme),
+            Name::new("NumberField"),
+        ));
+
+        root.with_children(|rc| {
+            if let Some(label) = &self.label {
+                rc.spawn((
+                    NumberFieldScrubMarker,
+                    NumberFieldScrubStyle::new(theme),
+                    Interaction::default(),
+                    FocusPolicy::Block,
+                ))
+                .with_child((
+                    Text::new(label.clone()),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: theme.font.size.sm,
+                        ..default()
+                    },
+                    TextColor(theme.color.gray.step11),
+                    FocusPolicy::Pass,
+                ));
+            }
+
+            rc.spawn((
+                NumberFieldInputMarker,
+                input_style,
+                input_state,
+                Interaction::default(),
+                RelativeCursorPosition::default(),
+                FocusPolicy::Block,
+                TabIndex(0),
+            ))
+            .with_children(|ic| {
+                spawn_text_field_viewport(ic, theme, font, LineBreak::NoWrap);
+            });
+
+            for (direction, name, text) in [
+                (StepDirection::Decrement, "Decrement", "\u{2212}"),
+                (StepDirection::Increment, "Increment", "+"),
+            ] {
+                rc.spawn((
+                    ButtonBuilder::<NoAction>::new(name)
+                        .text(text)
+                        .variant(ButtonVariant::Outline)
+                        .size(ButtonSize::Small)
+                        .build(theme, font),
+                    NumberFieldStepButton(direction),
+                ));
+            }
+        });
+
+        root.insert(state);
+        if self.disabled {
+            root.insert(Disabled);
+        }
+        root.id()
+    }
+}
@@ -0,0 +1,161 @@
+use bevy::prelude::*;
+
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct NumberFieldMarker;
+
+/// The editable input; carries a
+/// [`TextFieldState`](crate::components::text_field::TextFieldState) with
+/// the formatted value.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct NumberFieldInputMarker;
+
+/// Label that changes the value when dragged horizontally.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct NumberFieldScrubMarker;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepDirection {
+    Increment,
+    Decrement,
+}
+
+impl StepDirection {
+    pub fn sign(self) -> f64 {
+        match self {
+            StepDirection::Increment => 1.0,
+            StepDirection::Decrement => -1.0,
+        }
+    }
+}
+
+/// Increment or decrement button next to the input.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct NumberFieldStepButton(pub StepDirection);
+
+/// Value and constraints of a number field.
+///
+/// `value` always lies within `min..=max` and is rounded to `precision`
+/// decimal places.
+#[derive(Component, Debug, Clone)]
+pub struct NumberFieldState {
+    pub value: f64,
+    pub min: f64,
+    pub max: f64,
+    pub step: f64,
+    /// Number of decimal places shown and kept.
+    pub precision: usize,
+    /// Pixels of horizontal drag per step while scrubbing.
+    pub scrub_pixels: f32,
+    pub(crate) scrubbing: bool,
+    pub(crate) scrub_rest: f32,
+}
+
+impl Default for NumberFieldState {
+    fn default() -> Self {
+        Self {
+            value: 0.0,
+            min: f64::NEG_INFINITY,
+            max: f64::INFINITY,
+            step: 1.0,
+            precision: 0,
+            scrub_pixels: 4.0,
+            scrubbing: false,
+            scrub_rest: 0.0,
+        }
+    }
+}
+
+impl NumberFieldState {
+    /// Rounds `value` to the precision and clamps it to the range.
+    ///
+    /// Never panics: swapped bounds are ordered first and `NaN` bounds are
+    /// ignored.
+    pub fn constrain(&self, value: f64) -> f64 {
+        let factor = 10f64.powi(self.precision as i32);
+        let rounded = (value * factor).round() / factor;
+        let (lo, hi) = if self.min > self.max {
+            (self.max, self.min)
+        } else {
+            (self.min, self.max)
+        };
+        // `f64::max`/`min` übergehen NaN, `clamp` würde panicken
+        rounded.max(lo).min(hi)
+    }
+
+    /// Sets the constrained value, returns `true` if it changed.
+    pub fn set_value(&mut self, value: f64) -> bool {
+        let value = self.constrain(value);
+        if value == self.value {
+            return false;
+        }
+        self.value = value;
+        true
+    }
+
+    /// Moves the value by `count` steps, returns `true` if it changed.
+    pub fn step_by(&mut self, count: f64) -> bool {
+        self.set_value(self.value + self.step * count)
+    }
+
+    /// Value as shown in the input.
+    pub fn format(&self) -> String {
+        format!("{:.*}", self.precision, self.value)
+    }
+
+    /// Parses user input; accepts `,` as decimal separator.
+    /// Returns `None` for anything that is not a finite number.
+    pub fn parse(text: &str) -> Option<f64> {
+        let text = text.trim().replace(',', ".").replace('\u{2212}', "-");
+        text.parse::<f64>().ok().filter(|v| v.is_finite())
+    }
+
+    /// Whether `value` is accepted without clamping.
+    pub fn in_range(&self, value: f64) -> bool {
+        value >= self.min && value <= self.max
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(min: f64, max: f64, precision: usize) -> NumberFieldState {
+        NumberFieldState {
+            min,
+            max,
+            precision,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn constrain_orders_swapped_bounds() {
+        let state = state(10.0, 0.0, 0);
+        assert_eq!(state.constrain(-5.0), 0.0);
+        assert_eq!(state.constrain(5.0), 5.0);
+        assert_eq!(state.constrain(15.0), 10.0);
+    }
+
+    #[test]
+    fn constrain_ignores_nan_bounds() {
+        assert_eq!(state(f64::NAN, 10.0, 0).constrain(-5.0), -5.0);
+        assert_eq!(state(f64::NAN, 10.0, 0).constrain(15.0), 10.0);
+        assert_eq!(state(0.0, f64::NAN, 0).constrain(15.0), 15.0);
+        assert_eq!(state(f64::NAN, f64::NAN, 0).constrain(3.0), 3.0);
+    }
+
+    #[test]
+    fn step_rounds_to_precision() {
+        let mut state = NumberFieldState {
+            step: 0.1,
+            ..state(0.0, 1.0, 1)
+        };
+        for _ in 0..3 {
+            state.step_by(1.0);
+        }
+        assert_eq!(state.value, 0.3);
+        assert!(state.step_by(10.0));
+        assert_eq!(state.value, 1.0);
+        assert_eq!(state.constrain(0.26), 0.3);
+    }
+}
@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+
+/// Sent whenever the value of a number field changes, either by stepping,
+/// scrubbing or by committing typed input.
+///
+/// `value` is already clamped to the field's range and rounded to its
+/// precision.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct NumberChangedEvent {
+    pub field: Entity,
+    pub value: f64,
+}
@@ -0,0 +1,19 @@
+mod builder;
+mod components;
+mod events;
+mod plugin;
+mod style;
+mod systems;
+
+pub use builder::NumberFieldBuilder;
+pub use components::{
+    NumberFieldInputMarker, NumberFieldMarker, NumberFieldScrubMarker, NumberFieldState,
+    NumberFieldStepButton, StepDirection,
+};
+pub use events::NumberChangedEvent;
+pub use plugin::NumberFieldPlugin;
+pub use style::{NumberFieldScrubStyle, NumberFieldStyle};
+pub use systems::{
+    handle_number_field_keyboard, handle_number_field_scrub, handle_number_field_step_buttons,
+    handle_number_field_wheel, sync_number_field_text, validate_number_field_input,
+};
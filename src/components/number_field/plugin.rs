@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+
+use super::events::NumberChangedEvent;
+use super::systems::*;
+use crate::components::button::handle_button_release;
+use crate::components::helper::NoAction;
+use crate::components::text_field::{handle_text_field_keyboard, update_text_field_visuals};
+use crate::plugin::UiState;
+
+/// Plugin for [`NumberFieldBuilder`](super::NumberFieldBuilder).
+pub struct NumberFieldPlugin;
+
+impl Plugin for NumberFieldPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<NumberChangedEvent>().add_systems(
+            Update,
+            (
+                handle_number_field_step_buttons.after(handle_button_release::<NoAction>),
+                handle_number_field_keyboard,
+                handle_number_field_wheel,
+                handle_number_field_scrub,
+                validate_number_field_input.after(handle_text_field_keyboard),
+                sync_number_field_text,
+            )
+                .chain()
+                .before(update_text_field_visuals)
+                .run_if(in_state(UiState::Ready)),
+        );
+    }
+}
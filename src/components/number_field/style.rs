@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+
+use crate::theme::UiTheme;
+
+/// Row holding label, input and step buttons.
+#[derive(Bundle, Clone, Debug)]
+pub struct NumberFieldStyle {
+    pub node: Node,
+}
+
+impl NumberFieldStyle {
+    pub fn new(theme: &UiTheme) -> Self {
+        Self {
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(theme.layout.gap.xs),
+                ..default()
+            },
+        }
+    }
+}
+
+/// Padding around the scrub label so it is easy to grab.
+#[derive(Bundle, Clone, Debug)]
+pub struct NumberFieldScrubStyle {
+    pub node: Node,
+}
+
+impl NumberFieldScrubStyle {
+    pub fn new(theme: &UiTheme) -> Self {
+        Self {
+            node: Node {
+                display: Display::Flex,
+                align_items: AlignItems::Center,
+                padding: UiRect::horizontal(Val::Px(theme.layout.padding.xs)),
+                ..default()
+            },
+        }
+    }
+}
@@ -0,0 +1,245 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::mouse::{AccumulatedMouseMotion, MouseWheel};
+use bevy::input::ButtonState;
+use bevy::input_focus::InputFocus;
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+
+use super::{
+    NumberChangedEvent, NumberFieldInputMarker, NumberFieldScrubMarker, NumberFieldState,
+    NumberFieldStepButton,
+};
+use crate::components::button::ButtonClickedEvent;
+use crate::components::disabled::InheritedDisabled;
+use crate::components::helper::NoAction;
+use crate::components::text_field::{
+    TextChangedEvent, TextFieldInvalid, TextFieldState, TextSubmittedEvent,
+};
+
+/// Steps per key press while Shift is held and for PageUp/PageDown.
+const LARGE_STEP: f64 = 10.0;
+
+/// Steps the value when an increment or decrement button is clicked.
+pub fn handle_number_field_step_buttons(
+    mut clicks: EventReader<ButtonClickedEvent<NoAction>>,
+    buttons: Query<(&NumberFieldStepButton, &ChildOf)>,
+    mut fields: Query<&mut NumberFieldState, Without<InheritedDisabled>>,
+    mut changed: EventWriter<NumberChangedEvent>,
+) {
+    for click in clicks.read() {
+        let Ok((button, parent)) = buttons.get(click.source_entity) else {
+            continue;
+        };
+        let Ok(mut state) = fields.get_mut(parent.parent()) else {
+            continue;
+        };
+        if state.step_by(button.0.sign()) {
+            changed.write(NumberChangedEvent {
+                field: parent.parent(),
+                value: state.value,
+            });
+        }
+    }
+}
+
+/// ArrowUp/ArrowDown step the focused field, Shift and PageUp/PageDown
+/// take ten steps at once.
+pub fn handle_number_field_keyboard(
+    focus: Res<InputFocus>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut key_events: EventReader<KeyboardInput>,
+    inputs: Query<&ChildOf, With<NumberFieldInputMarker>>,
+    mut fields: Query<&mut NumberFieldState, Without<InheritedDisabled>>,
+    mut changed: EventWriter<NumberChangedEvent>,
+) {
+    let Some(field) = focus
+        .get()
+        .and_then(|e| inputs.get(e).ok())
+        .map(|parent| parent.parent())
+    else {
+        key_events.clear();
+        return;
+    };
+    let Ok(mut state) = fields.get_mut(field) else {
+        key_events.clear();
+        return;
+    };
+
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let small = if shift { LARGE_STEP } else { 1.0 };
+    let mut moved = false;
+    for event in key_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        let steps = match event.logical_key {
+            Key::ArrowUp => small,
+            Key::ArrowDown => -small,
+            Key::PageUp => LARGE_STEP,
+            Key::PageDown => -LARGE_STEP,
+            _ => continue,
+        };
+        moved |= state.step_by(steps);
+    }
+    if moved {
+        changed.write(NumberChangedEvent {
+            field,
+            value: state.value,
+        });
+    }
+}
+
+/// Mouse wheel over the focused input steps its value.
+pub fn handle_number_field_wheel(
+    focus: Res<InputFocus>,
+    mut wheel: EventReader<MouseWheel>,
+    inputs: Query<(&RelativeCursorPosition, &ChildOf), With<NumberFieldInputMarker>>,
+    mut fields: Query<&mut NumberFieldState, Without<InheritedDisabled>>,
+    mut changed: EventWriter<NumberChangedEvent>,
+) {
+    let steps: f64 = wheel
+        .read()
+        .filter(|event| event.y != 0.0)
+        .map(|event| event.y.signum() as f64)
+        .sum();
+    if steps == 0.0 {
+        return;
+    }
+    let Some((cursor, parent)) = focus.get().and_then(|e| inputs.get(e).ok()) else {
+        return;
+    };
+    if !cursor.mouse_over() {
+        return;
+    }
+    let Ok(mut state) = fields.get_mut(parent.parent()) else {
+        return;
+    };
+    if state.step_by(steps) {
+        changed.write(NumberChangedEvent {
+            field: parent.parent(),
+            value: state.value,
+        });
+    }
+}
+
+/// Dragging the label horizontally changes the value by one step per
+/// `scrub_pixels`.
+pub fn handle_number_field_scrub(
+    mouse: Res<ButtonInput<MouseButton>>,
+    motion: Res<AccumulatedMouseMotion>,
+    labels: Query<(&Interaction, &ChildOf), With<NumberFieldScrubMarker>>,
+    mut fields: Query<(Entity, &mut NumberFieldState), Without<InheritedDisabled>>,
+    mut changed: EventWriter<NumberChangedEvent>,
+) {
+    if mouse.just_pressed(MouseButton::Left) {
+        for (interaction, parent) in labels.iter() {
+            if *interaction != Interaction::Pressed {
+                continue;
+            }
+            if let Ok((_, mut state)) = fields.get_mut(parent.parent()) {
+                state.scrubbing = true;
+                state.scrub_rest = 0.0;
+            }
+        }
+    }
+
+    let held = mouse.pressed(MouseButton::Left);
+    for (entity, mut state) in fields.iter_mut() {
+        if !state.scrubbing {
+            continue;
+        }
+        if !held {
+            state.scrubbing = false;
+            continue;
+        }
+        if motion.delta.x == 0.0 {
+            continue;
+        }
+        let rest = state.scrub_rest + motion.delta.x;
+        let pixels = state.scrub_pixels.max(1.0);
+        let steps = (rest / pixels).trunc();
+        state.scrub_rest = rest - steps * pixels;
+        if steps != 0.0 && state.step_by(steps as f64) {
+            changed.write(NumberChangedEvent {
+                field: entity,
+                value: state.value,
+            });
+        }
+    }
+}
+
+/// Marks typed input as invalid while it is no number or out of range and
+/// commits it on Enter or when the input loses focus.
+///
+/// Committed values are clamped and rounded; unparsable text is replaced by
+/// the last valid value.
+pub fn validate_number_field_input(
+    mut commands: Commands,
+    focus: Res<InputFocus>,
+    mut last_focus: Local<Option<Entity>>,
+    mut edits: EventReader<TextChangedEvent>,
+    mut submits: EventReader<TextSubmittedEvent>,
+    inputs: Query<(&TextFieldState, &ChildOf), With<NumberFieldInputMarker>>,
+    mut fields: Query<&mut NumberFieldState, Without<InheritedDisabled>>,
+    mut changed: EventWriter<NumberChangedEvent>,
+) {
+    for edit in edits.read() {
+        let Ok((_, parent)) = inputs.get(edit.entity) else {
+            continue;
+        };
+        let Ok(state) = fields.get(parent.parent()) else {
+            continue;
+        };
+        let valid = NumberFieldState::parse(&edit.value).is_some_and(|v| state.in_range(v));
+        if valid {
+            commands.entity(edit.entity).remove::<TextFieldInvalid>();
+        } else {
+            commands.entity(edit.entity).insert(TextFieldInvalid);
+        }
+    }
+
+    let mut commits: Vec<Entity> = submits.read().map(|e| e.entity).collect();
+    let current = focus.get();
+    if *last_focus != current {
+        commits.extend(*last_focus);
+        *last_focus = current;
+    }
+
+    for input in commits {
+        let Ok((text, parent)) = inputs.get(input) else {
+            continue;
+        };
+        let field = parent.parent();
+        let Ok(mut state) = fields.get_mut(field) else {
+            continue;
+        };
+        if let Some(value) = NumberFieldState::parse(&text.value) {
+            if state.set_value(value) {
+                changed.write(NumberChangedEvent {
+                    field,
+                    value: state.value,
+                });
+            }
+        }
+        // Auch ohne Wertänderung neu formatieren (z. B. "007" -> "7")
+        state.set_changed();
+        commands.entity(input).remove::<TextFieldInvalid>();
+    }
+}
+
+/// Writes the formatted value into the input whenever the state changes.
+pub fn sync_number_field_text(
+    fields: Query<(&NumberFieldState, &Children), Changed<NumberFieldState>>,
+    mut inputs: Query<&mut TextFieldState, With<NumberFieldInputMarker>>,
+) {
+    for (state, children) in fields.iter() {
+        let text = state.format();
+        for child in children.iter() {
+            if let Ok(mut input) = inputs.get_mut(child) {
+                if input.value != text {
+                    input.set_value(text.clone());
+                }
+            }
+        }
+    }
+}
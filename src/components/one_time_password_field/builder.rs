@@ -1,25 +1,50 @@
+use bevy::input_focus::tab_navigation::TabIndex;
 use bevy::prelude::*;
 
 use crate::components::helper::UiBuilder;
-use crate::components::label::LabelBuilder;
+use crate::components::roving_focus::{RovingFocusGroup, RovingFocusItem, RovingFocusOrientation};
 use crate::theme::UiTheme;
 
-use super::{OneTimePasswordFieldMarker, OtpFieldStyle, OtpInputMarker, OtpInputStyle};
+use super::{
+    OneTimePasswordFieldMarker, OtpCell, OtpCellTextMarker, OtpFieldState, OtpFieldStyle,
+    OtpInputMarker, OtpInputStyle, OtpMode,
+};
 
-/// Builder for a simple one-time password field.
+/// Builder for a one-time password field with one cell per character.
+///
+/// Typing advances to the next cell, Backspace on an empty cell goes back,
+/// pasting a code fills the following cells. An
+/// [`OtpCompletedEvent`](super::OtpCompletedEvent) is sent once all cells
+/// are filled.
 pub struct OneTimePasswordFieldBuilder {
     length: usize,
+    mode: OtpMode,
 }
 
 impl OneTimePasswordFieldBuilder {
     pub fn new() -> Self {
-        Self { length: 6 }
+        Self {
+            length: 6,
+            mode: OtpMode::Numeric,
+        }
     }
 
     pub fn length(mut self, len: usize) -> Self {
-        self.length = len;
+        self.length = len.max(1);
         self
     }
+
+    /// Accepted characters, numeric by default.
+    pub fn mode(mut self, mode: OtpMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+impl Default for OneTimePasswordFieldBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<'w, 's> UiBuilder<'w, 's> for OneTimePasswordFieldBuilder {
@@ -34,17 +59,31 @@ impl<'w, 's> UiBuilder<'w, 's> for OneTimePasswordFieldBuilder {
         let mut root = parent.spawn((
             OneTimePasswordFieldMarker,
             OtpFieldStyle::new(theme),
+            OtpFieldState::new(self.length, self.mode),
+            RovingFocusGroup::new(RovingFocusOrientation::Horizontal, false),
             Name::new("OTPField"),
         ));
         root.with_children(|rc| {
-            for _ in 0..self.length {
-                let mut input_cmd = rc.spawn((
+            for index in 0..self.length {
+                rc.spawn((
                     OtpInputMarker,
+                    OtpCell(index),
                     OtpInputStyle::new(theme),
                     Interaction::default(),
-                ));
-                input_cmd.with_children(|ic| {
-                    let _ = LabelBuilder::new("\u{00A0}").spawn(ic, theme, font);
+                    RovingFocusItem,
+                    TabIndex(0),
+                ))
+                .with_children(|ic| {
+                    ic.spawn((
+                        OtpCellTextMarker,
+                        Text::new(""),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: theme.font.size.base,
+                            ..default()
+                        },
+                        TextColor(theme.color.gray.step12),
+                    ));
                 });
             }
         });
@@ -5,3 +5,78 @@ pub struct OneTimePasswordFieldMarker;
 
 #[derive(Component, Debug, Clone, Copy, Default)]
 pub struct OtpInputMarker;
+
+/// Position of an input cell inside its field.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OtpCell(pub usize);
+
+/// Text node inside an input cell.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct OtpCellTextMarker;
+
+/// Characters accepted by a one-time password field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OtpMode {
+    /// Digits only.
+    #[default]
+    Numeric,
+    /// Letters and digits; letters are upper-cased.
+    Alphanumeric,
+}
+
+impl OtpMode {
+    /// Normalised character if it is accepted in this mode.
+    pub fn accept(self, c: char) -> Option<char> {
+        match self {
+            OtpMode::Numeric => c.is_ascii_digit().then_some(c),
+            OtpMode::Alphanumeric => c.is_ascii_alphanumeric().then(|| c.to_ascii_uppercase()),
+        }
+    }
+}
+
+/// Value of a one-time password field, one entry per cell.
+#[derive(Component, Debug, Clone)]
+pub struct OtpFieldState {
+    pub cells: Vec<Option<char>>,
+    pub mode: OtpMode,
+    pub(crate) completed: bool,
+}
+
+impl OtpFieldState {
+    pub fn new(length: usize, mode: OtpMode) -> Self {
+        Self {
+            cells: vec![None; length],
+            mode,
+            completed: false,
+        }
+    }
+
+    /// Entered characters in cell order (empty cells are skipped).
+    pub fn value(&self) -> String {
+        self.cells.iter().flatten().collect()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.cells.iter().all(Option::is_some)
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.iter_mut().for_each(|c| *c = None);
+    }
+
+    /// Writes the accepted characters of `text` into the cells starting at
+    /// `start`. Returns the index of the last written cell.
+    pub fn fill_from(&mut self, start: usize, text: &str) -> Option<usize> {
+        let mut index = start;
+        let mut last = None;
+        for c in text.chars().filter_map(|c| self.mode.accept(c)) {
+            let Some(cell) = self.cells.get_mut(index) else {
+                break;
+            };
+            *cell = Some(c);
+            last = Some(index);
+            index += 1;
+        }
+        last
+    }
+}
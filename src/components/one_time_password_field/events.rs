@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+/// Sent once when every cell of a one-time password field is filled.
+#[derive(Event, Debug, Clone)]
+pub struct OtpCompletedEvent {
+    pub field: Entity,
+    pub value: String,
+}
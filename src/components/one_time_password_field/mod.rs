@@ -1,10 +1,15 @@
 mod builder;
 mod components;
+mod events;
 mod plugin;
 mod style;
 mod systems;
 
 pub use builder::OneTimePasswordFieldBuilder;
-pub use components::{OneTimePasswordFieldMarker, OtpInputMarker};
+pub use components::{
+    OneTimePasswordFieldMarker, OtpCell, OtpCellTextMarker, OtpFieldState, OtpInputMarker, OtpMode,
+};
+pub use events::OtpCompletedEvent;
 pub use plugin::OneTimePasswordFieldPlugin;
 pub use style::{OtpFieldStyle, OtpInputStyle};
+pub use systems::{handle_otp_input_hover, handle_otp_keyboard, update_otp_visuals};
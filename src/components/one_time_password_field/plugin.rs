@@ -2,12 +2,21 @@ use bevy::prelude::*;
 
 use crate::plugin::UiState;
 
-use super::systems::handle_otp_input_hover;
+use super::events::OtpCompletedEvent;
+use super::systems::{handle_otp_input_hover, handle_otp_keyboard, update_otp_visuals};
 
 pub struct OneTimePasswordFieldPlugin;
 
 impl Plugin for OneTimePasswordFieldPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, handle_otp_input_hover.run_if(in_state(UiState::Ready)));
+        app.add_event::<OtpCompletedEvent>().add_systems(
+            Update,
+            (
+                handle_otp_input_hover,
+                handle_otp_keyboard,
+                update_otp_visuals.after(handle_otp_keyboard),
+            )
+                .run_if(in_state(UiState::Ready)),
+        );
     }
 }
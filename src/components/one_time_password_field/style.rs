@@ -39,6 +39,7 @@ impl OtpInputStyle {
                 justify_content: JustifyContent::Center,
                 width: Val::Px(32.0),
                 height: Val::Px(32.0),
+                border: UiRect::all(Val::Px(1.0)),
                 ..default()
             },
             background_color: BackgroundColor(theme.color.gray.step03),
@@ -1,6 +1,13 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::input_focus::InputFocus;
 use bevy::prelude::*;
+use bevy::window::Ime;
 
-use super::OtpInputMarker;
+use super::{OtpCell, OtpCellTextMarker, OtpCompletedEvent, OtpFieldState, OtpInputMarker};
+use crate::components::clipboard::Clipboard;
+use crate::components::disabled::InheritedDisabled;
+use crate::components::shortcut::{editing_command, is_printable, Modifiers};
 use crate::theme::UiTheme;
 
 /// Highlights OTP inputs on hover.
@@ -16,3 +23,142 @@ pub fn handle_otp_input_hover(
         };
     }
 }
+
+/// Typing, Backspace/Delete and paste in the focused OTP cell.
+pub fn handle_otp_keyboard(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut focus: ResMut<InputFocus>,
+    mut clipboard: ResMut<Clipboard>,
+    mut key_events: EventReader<KeyboardInput>,
+    mut ime_events: EventReader<Ime>,
+    cells: Query<(&OtpCell, &ChildOf), Without<InheritedDisabled>>,
+    mut fields: Query<(&mut OtpFieldState, &Children)>,
+    mut completed: EventWriter<OtpCompletedEvent>,
+) {
+    let Some((cell, field_entity)) = focus
+        .get()
+        .and_then(|f| cells.get(f).ok())
+        .map(|(cell, parent)| (cell.0, parent.parent()))
+    else {
+        key_events.clear();
+        ime_events.clear();
+        return;
+    };
+    let Ok((mut state, children)) = fields.get_mut(field_entity) else {
+        key_events.clear();
+        ime_events.clear();
+        return;
+    };
+
+    let modifiers = Modifiers::pressed(&keys);
+    let mut current = cell;
+    let mut input = String::new();
+
+    for event in key_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        if let Some(command) = editing_command(&event.logical_key, modifiers) {
+            if command == 'v' {
+                let text = clipboard.get_text().unwrap_or_default();
+                paste_text(&mut state, &mut current, &text);
+            }
+            continue;
+        }
+        match &event.logical_key {
+            Key::Backspace => {
+                if state.cells[current].is_some() {
+                    state.cells[current] = None;
+                } else if current > 0 {
+                    current -= 1;
+                    state.cells[current] = None;
+                }
+            }
+            Key::Delete => state.cells[current] = None,
+            _ if modifiers.super_key => {}
+            _ => {
+                if let Some(text) = event.text.as_deref().filter(|t| is_printable(t)) {
+                    input.push_str(text);
+                }
+            }
+        }
+    }
+    for event in ime_events.read() {
+        if let Ime::Commit { value, .. } = event {
+            input.push_str(value);
+        }
+    }
+    if !input.is_empty() {
+        apply_text(&mut state, &mut current, &input);
+    }
+
+    if current != cell {
+        if let Some(target) = children
+            .iter()
+            .find(|c| cells.get(*c).is_ok_and(|(cell, _)| cell.0 == current))
+        {
+            focus.set(target);
+        }
+    }
+
+    let complete = state.is_complete();
+    if complete && !state.completed {
+        completed.write(OtpCompletedEvent {
+            field: field_entity,
+            value: state.value(),
+        });
+    }
+    if state.completed != complete {
+        state.completed = complete;
+    }
+}
+
+/// Pastes `text`. A complete code replaces all cells, shorter fragments are
+/// inserted at `current`.
+fn paste_text(state: &mut OtpFieldState, current: &mut usize, text: &str) {
+    let accepted = text.chars().filter_map(|c| state.mode.accept(c)).count();
+    if accepted >= state.cells.len() {
+        state.clear();
+        *current = 0;
+    }
+    apply_text(state, current, text);
+}
+
+/// Fills cells from `current` on and moves `current` behind the last one.
+fn apply_text(state: &mut OtpFieldState, current: &mut usize, text: &str) {
+    if let Some(last) = state.fill_from(*current, text) {
+        *current = (last + 1).min(state.cells.len() - 1);
+    }
+}
+
+/// Shows the cell values and highlights the focused cell.
+pub fn update_otp_visuals(
+    theme: Res<UiTheme>,
+    focus: Res<InputFocus>,
+    fields: Query<&OtpFieldState>,
+    mut cells: Query<(Entity, &OtpCell, &ChildOf, &Children, &mut BorderColor)>,
+    mut texts: Query<&mut Text, With<OtpCellTextMarker>>,
+) {
+    for (entity, cell, parent, children, mut border) in cells.iter_mut() {
+        let Ok(state) = fields.get(parent.parent()) else {
+            continue;
+        };
+        let wanted = if focus.get() == Some(entity) {
+            theme.accent.step08
+        } else {
+            theme.color.gray.step06
+        };
+        if border.0 != wanted {
+            border.0 = wanted;
+        }
+        let value = state.cells.get(cell.0).copied().flatten();
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                let shown = value.map(String::from).unwrap_or_default();
+                if text.0 != shown {
+                    text.0 = shown;
+                }
+            }
+        }
+    }
+}
@@ -1,38 +1,78 @@
+use bevy::input_focus::tab_navigation::TabIndex;
 use bevy::prelude::*;
+use bevy::text::LineBreak;
+use bevy::ui::FocusPolicy;
 
 use crate::components::helper::UiBuilder;
 use crate::components::label::LabelBuilder;
+use crate::components::text_field::{spawn_text_field_viewport, TextFieldState, TextFieldStyle};
+use crate::components::visually_hidden::VisuallyHiddenBuilder;
 use crate::theme::UiTheme;
 
 use super::{
-    PasswordHiddenTextMarker, PasswordInputMarker, PasswordInputStyle, PasswordToggleFieldMarker,
-    PasswordToggleFieldState, PasswordToggleFieldStyle, PasswordToggleMarker, PasswordToggleStyle,
-    PasswordVisibleTextMarker,
+    password_toggle_label, PasswordInputMarker, PasswordToggleFieldMarker,
+    PasswordToggleFieldState, PasswordToggleFieldStyle, PasswordToggleIconMarker,
+    PasswordToggleIcons, PasswordToggleLabelMarker, PasswordToggleMarker, PasswordToggleStyle,
+    PASSWORD_MASK,
 };
 
-/// Builder for a simple password toggle field.
+/// Builder for a masked password input with a show/hide toggle.
+///
+/// Typed characters are stored in [`PasswordToggleFieldState`]; Enter sends a
+/// [`PasswordSubmittedEvent`](super::PasswordSubmittedEvent).
 pub struct PasswordToggleFieldBuilder {
     password: String,
+    placeholder: String,
+    max_length: Option<usize>,
     visible: bool,
+    icons: Option<PasswordToggleIcons>,
 }
 
 impl PasswordToggleFieldBuilder {
     pub fn new() -> Self {
         Self {
-            password: "secret".into(),
+            password: String::new(),
+            placeholder: String::new(),
+            max_length: None,
             visible: false,
+            icons: None,
         }
     }
 
+    /// Initial password.
     pub fn password(mut self, text: impl Into<String>) -> Self {
         self.password = text.into();
         self
     }
 
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
     pub fn visible(mut self, visible: bool) -> Self {
         self.visible = visible;
         self
     }
+
+    /// Eye icons for the toggle, e.g. `eye-open` and `eye-closed` from
+    /// [`IconAssets`](crate::assets::IconAssets). Without icons the toggle
+    /// shows its label as text.
+    pub fn icons(mut self, show: Handle<Image>, hide: Handle<Image>) -> Self {
+        self.icons = Some(PasswordToggleIcons { show, hide });
+        self
+    }
+}
+
+impl Default for PasswordToggleFieldBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<'w, 's> UiBuilder<'w, 's> for PasswordToggleFieldBuilder {
@@ -44,51 +84,81 @@ impl<'w, 's> UiBuilder<'w, 's> for PasswordToggleFieldBuilder {
         theme: &UiTheme,
         font: &Handle<Font>,
     ) -> Self::Output {
-        let bullet = "\u{2022}".repeat(self.password.len());
+        let mut input_state = TextFieldState::new(self.password.clone());
+        input_state.placeholder = self.placeholder;
+        input_state.max_length = self.max_length;
+        input_state.secret = true;
+        input_state.mask = (!self.visible).then_some(PASSWORD_MASK);
+
+        let mut input_style = TextFieldStyle::new(theme);
+        input_style.node.flex_grow = 1.0;
+
+        let label = password_toggle_label(self.visible);
 
         let mut root = parent.spawn((
             PasswordToggleFieldMarker,
             PasswordToggleFieldStyle::new(theme),
+            PasswordToggleFieldState {
+                visible: self.visible,
+                password: self.password,
+            },
             Name::new("PasswordToggleField"),
         ));
 
         root.with_children(|rc| {
-            let mut input_cmd = rc.spawn((PasswordInputMarker, PasswordInputStyle::new(theme)));
-            input_cmd.with_children(|ic| {
-                let visible_label = LabelBuilder::new(self.password.clone()).spawn(ic, theme, font);
-                ic.commands()
-                    .entity(visible_label)
-                    .insert(PasswordVisibleTextMarker);
-                let hidden_label = LabelBuilder::new(bullet.clone()).spawn(ic, theme, font);
-                ic.commands()
-                    .entity(hidden_label)
-                    .insert(PasswordHiddenTextMarker);
-                ic.commands().entity(visible_label).insert(if self.visible {
-                    Visibility::Inherited
-                } else {
-                    Visibility::Hidden
-                });
-                ic.commands().entity(hidden_label).insert(if self.visible {
-                    Visibility::Hidden
-                } else {
-                    Visibility::Inherited
-                });
+            rc.spawn((
+                PasswordInputMarker,
+                input_style,
+                input_state,
+                Interaction::default(),
+                FocusPolicy::Block,
+                TabIndex(0),
+            ))
+            .with_children(|ic| {
+                spawn_text_field_viewport(ic, theme, font, LineBreak::NoWrap);
             });
 
             rc.spawn((
                 PasswordToggleMarker,
                 PasswordToggleStyle::new(theme),
+                Button,
                 Interaction::default(),
+                TabIndex(0),
+                Name::new(label),
             ))
-            .with_children(|tc| {
-                let _ = LabelBuilder::new("Toggle").spawn(tc, theme, font);
+            .with_children(|tc| match &self.icons {
+                Some(icons) => {
+                    let icon = if self.visible {
+                        &icons.hide
+                    } else {
+                        &icons.show
+                    };
+                    tc.spawn((
+                        PasswordToggleIconMarker,
+                        ImageNode::new(icon.clone()).with_color(theme.color.gray.step11),
+                        Node {
+                            width: Val::Px(16.0),
+                            height: Val::Px(16.0),
+                            ..default()
+                        },
+                    ));
+                    let hidden = VisuallyHiddenBuilder::new(label).spawn(tc, theme, font);
+                    tc.commands()
+                        .entity(hidden)
+                        .insert(PasswordToggleLabelMarker);
+                }
+                None => {
+                    let text = LabelBuilder::new(label)
+                        .font_size(theme.font.size.sm)
+                        .spawn(tc, theme, font);
+                    tc.commands().entity(text).insert(PasswordToggleLabelMarker);
+                }
             });
         });
 
-        root.insert(PasswordToggleFieldState {
-            visible: self.visible,
-            password: self.password,
-        });
+        if let Some(icons) = self.icons {
+            root.insert(icons);
+        }
 
         root.id()
     }
@@ -3,21 +3,56 @@ use bevy::prelude::*;
 #[derive(Component, Debug, Clone, Copy, Default)]
 pub struct PasswordToggleFieldMarker;
 
+/// The editable input; carries a secret, masked
+/// [`TextFieldState`](crate::components::text_field::TextFieldState).
 #[derive(Component, Debug, Clone, Copy, Default)]
 pub struct PasswordInputMarker;
 
 #[derive(Component, Debug, Clone, Copy, Default)]
 pub struct PasswordToggleMarker;
 
+/// Eye icon inside the toggle button.
 #[derive(Component, Debug, Clone, Copy, Default)]
-pub struct PasswordVisibleTextMarker;
+pub struct PasswordToggleIconMarker;
 
+/// Hidden accessible label of the toggle button ("Show password"/"Hide password").
 #[derive(Component, Debug, Clone, Copy, Default)]
-pub struct PasswordHiddenTextMarker;
+pub struct PasswordToggleLabelMarker;
 
+/// Icons for the toggle button: `show` while the password is masked,
+/// `hide` while it is visible.
 #[derive(Component, Debug, Clone)]
+pub struct PasswordToggleIcons {
+    pub show: Handle<Image>,
+    pub hide: Handle<Image>,
+}
+
+/// Visibility and current value of a password field.
+///
+/// `Debug` never prints the password.
+#[derive(Component, Clone, Default)]
 pub struct PasswordToggleFieldState {
     pub visible: bool,
     pub password: String,
 }
 
+impl std::fmt::Debug for PasswordToggleFieldState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PasswordToggleFieldState")
+            .field("visible", &self.visible)
+            .field("password", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Character used to mask the password.
+pub const PASSWORD_MASK: char = '\u{2022}';
+
+/// Accessible label of the toggle button.
+pub fn password_toggle_label(visible: bool) -> &'static str {
+    if visible {
+        "Hide password"
+    } else {
+        "Show password"
+    }
+}
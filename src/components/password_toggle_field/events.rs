@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+/// Sent when Enter is pressed in a password field.
+///
+/// `Debug` never prints the password.
+#[derive(Event, Clone)]
+pub struct PasswordSubmittedEvent {
+    pub field: Entity,
+    pub password: String,
+}
+
+impl std::fmt::Debug for PasswordSubmittedEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PasswordSubmittedEvent")
+            .field("field", &self.field)
+            .field("password", &"<redacted>")
+            .finish()
+    }
+}
@@ -1,13 +1,16 @@
 mod builder;
 mod components;
+mod events;
 mod plugin;
 mod style;
 mod systems;
 
 pub use builder::PasswordToggleFieldBuilder;
 pub use components::{
-    PasswordToggleFieldMarker, PasswordInputMarker, PasswordToggleMarker,
-    PasswordVisibleTextMarker, PasswordHiddenTextMarker, PasswordToggleFieldState,
+    password_toggle_label, PasswordInputMarker, PasswordToggleFieldMarker,
+    PasswordToggleFieldState, PasswordToggleIconMarker, PasswordToggleIcons,
+    PasswordToggleLabelMarker, PasswordToggleMarker, PASSWORD_MASK,
 };
+pub use events::PasswordSubmittedEvent;
 pub use plugin::PasswordToggleFieldPlugin;
-pub use style::{PasswordToggleFieldStyle, PasswordInputStyle, PasswordToggleStyle};
+pub use style::{PasswordToggleFieldStyle, PasswordToggleStyle};
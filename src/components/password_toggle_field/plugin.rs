@@ -1,14 +1,24 @@
 use bevy::prelude::*;
 
+use crate::components::roving_focus::focus_roving_item_on_press;
 use crate::plugin::UiState;
 
-use super::systems::handle_password_toggle;
+use super::events::PasswordSubmittedEvent;
+use super::systems::*;
 
 pub struct PasswordToggleFieldPlugin;
 
 impl Plugin for PasswordToggleFieldPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, handle_password_toggle.run_if(in_state(UiState::Ready)));
+        app.add_event::<PasswordSubmittedEvent>().add_systems(
+            Update,
+            (
+                handle_password_toggle,
+                refocus_password_input.after(focus_roving_item_on_press),
+                sync_password_value,
+                handle_password_submit,
+            )
+                .run_if(in_state(UiState::Ready)),
+        );
     }
 }
-
@@ -5,9 +5,6 @@ use crate::theme::UiTheme;
 #[derive(Bundle, Clone, Debug)]
 pub struct PasswordToggleFieldStyle {
     pub node: Node,
-    pub background_color: BackgroundColor,
-    pub border_color: BorderColor,
-    pub border_radius: BorderRadius,
 }
 
 impl PasswordToggleFieldStyle {
@@ -16,32 +13,10 @@ impl PasswordToggleFieldStyle {
             node: Node {
                 display: Display::Flex,
                 flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Stretch,
                 column_gap: Val::Px(theme.layout.padding.xs),
                 ..default()
             },
-            background_color: BackgroundColor(theme.color.gray.step02),
-            border_color: BorderColor(theme.color.gray.step06),
-            border_radius: BorderRadius::all(Val::Px(theme.layout.radius.sm)),
-        }
-    }
-}
-
-#[derive(Bundle, Clone, Debug)]
-pub struct PasswordInputStyle {
-    pub node: Node,
-    pub background_color: BackgroundColor,
-}
-
-impl PasswordInputStyle {
-    pub fn new(theme: &UiTheme) -> Self {
-        PasswordInputStyle {
-            node: Node {
-                display: Display::Flex,
-                align_items: AlignItems::Center,
-                padding: UiRect::horizontal(Val::Px(theme.layout.padding.xs)),
-                ..default()
-            },
-            background_color: BackgroundColor(theme.color.gray.step03),
         }
     }
 }
@@ -50,6 +25,8 @@ impl PasswordInputStyle {
 pub struct PasswordToggleStyle {
     pub node: Node,
     pub background_color: BackgroundColor,
+    pub border_color: BorderColor,
+    pub border_radius: BorderRadius,
 }
 
 impl PasswordToggleStyle {
@@ -58,11 +35,15 @@ impl PasswordToggleStyle {
             node: Node {
                 display: Display::Flex,
                 align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
                 padding: UiRect::horizontal(Val::Px(theme.layout.padding.xs)),
+                min_width: Val::Px(28.0),
+                border: UiRect::all(Val::Px(1.0)),
                 ..default()
             },
-            background_color: BackgroundColor(theme.color.gray.step04),
+            background_color: BackgroundColor(theme.color.gray.step03),
+            border_color: BorderColor(theme.color.gray.step07),
+            border_radius: BorderRadius::all(Val::Px(theme.layout.radius.sm)),
         }
     }
 }
-
@@ -1,39 +1,61 @@
+use bevy::input_focus::InputFocus;
 use bevy::prelude::*;
 
 use super::{
-    PasswordHiddenTextMarker, PasswordToggleFieldMarker, PasswordToggleFieldState,
-    PasswordToggleMarker, PasswordVisibleTextMarker,
+    password_toggle_label, PasswordInputMarker, PasswordSubmittedEvent, PasswordToggleFieldMarker,
+    PasswordToggleFieldState, PasswordToggleIconMarker, PasswordToggleIcons,
+    PasswordToggleLabelMarker, PasswordToggleMarker, PASSWORD_MASK,
 };
+use crate::components::text_field::{TextFieldState, TextSubmittedEvent};
 
 /// Toggles password visibility when the toggle button is pressed.
 pub fn handle_password_toggle(
-    mut toggle_q: Query<(&Interaction, &bevy::prelude::ChildOf), (Changed<Interaction>, With<PasswordToggleMarker>)>,
-    mut field_q: Query<(&mut PasswordToggleFieldState, &Children), With<PasswordToggleFieldMarker>>,
-    mut visible_q: Query<
-        &mut Visibility,
+    toggle_q: Query<(&Interaction, &ChildOf), (Changed<Interaction>, With<PasswordToggleMarker>)>,
+    mut field_q: Query<
         (
-            With<PasswordVisibleTextMarker>,
-            Without<PasswordHiddenTextMarker>,
-        ),
-    >,
-    mut hidden_q: Query<
-        &mut Visibility,
-        (
-            With<PasswordHiddenTextMarker>,
-            Without<PasswordVisibleTextMarker>,
+            &mut PasswordToggleFieldState,
+            &Children,
+            Option<&PasswordToggleIcons>,
         ),
+        With<PasswordToggleFieldMarker>,
     >,
+    mut input_q: Query<&mut TextFieldState, With<PasswordInputMarker>>,
+    mut toggle_names: Query<(&mut Name, &Children), With<PasswordToggleMarker>>,
+    mut icon_q: Query<&mut ImageNode, With<PasswordToggleIconMarker>>,
+    label_q: Query<&Children, With<PasswordToggleLabelMarker>>,
+    mut text_q: Query<&mut Text>,
 ) {
-    for (interaction, parent) in toggle_q.iter_mut() {
-        if *interaction == Interaction::Pressed {
-            if let Ok((mut state, children)) = field_q.get_mut(parent.parent()) {
-                state.visible = !state.visible;
-                for &child in children.into_iter() {
-                    if let Ok(mut vis) = visible_q.get_mut(child) {
-                        *vis = if state.visible { Visibility::Inherited } else { Visibility::Hidden };
-                    }
-                    if let Ok(mut vis) = hidden_q.get_mut(child) {
-                        *vis = if state.visible { Visibility::Hidden } else { Visibility::Inherited };
+    for (interaction, parent) in toggle_q.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok((mut state, children, icons)) = field_q.get_mut(parent.parent()) else {
+            continue;
+        };
+        state.visible = !state.visible;
+        let label = password_toggle_label(state.visible);
+
+        for child in children.iter() {
+            if let Ok(mut input) = input_q.get_mut(child) {
+                input.mask = (!state.visible).then_some(PASSWORD_MASK);
+            }
+            let Ok((mut name, toggle_children)) = toggle_names.get_mut(child) else {
+                continue;
+            };
+            name.set(label);
+            for part in toggle_children.iter() {
+                if let (Ok(mut image), Some(icons)) = (icon_q.get_mut(part), icons) {
+                    image.image = if state.visible {
+                        icons.hide.clone()
+                    } else {
+                        icons.show.clone()
+                    };
+                }
+                if let Ok(label_children) = label_q.get(part) {
+                    for text_entity in label_children.iter() {
+                        if let Ok(mut text) = text_q.get_mut(text_entity) {
+                            text.0 = label.to_string();
+                        }
                     }
                 }
             }
@@ -41,3 +63,55 @@ pub fn handle_password_toggle(
     }
 }
 
+/// Mirrors the typed value into [`PasswordToggleFieldState`].
+pub fn sync_password_value(
+    input_q: Query<
+        (&TextFieldState, &ChildOf),
+        (Changed<TextFieldState>, With<PasswordInputMarker>),
+    >,
+    mut field_q: Query<&mut PasswordToggleFieldState, With<PasswordToggleFieldMarker>>,
+) {
+    for (input, parent) in input_q.iter() {
+        if let Ok(mut state) = field_q.get_mut(parent.parent()) {
+            if state.password != input.value {
+                state.password.clone_from(&input.value);
+            }
+        }
+    }
+}
+
+/// Turns Enter in a password input into a [`PasswordSubmittedEvent`].
+pub fn handle_password_submit(
+    mut submitted: EventReader<TextSubmittedEvent>,
+    input_q: Query<(&TextFieldState, &ChildOf), With<PasswordInputMarker>>,
+    mut writer: EventWriter<PasswordSubmittedEvent>,
+) {
+    for event in submitted.read() {
+        if let Ok((input, parent)) = input_q.get(event.entity) {
+            writer.write(PasswordSubmittedEvent {
+                field: parent.parent(),
+                password: input.value.clone(),
+            });
+        }
+    }
+}
+
+/// Keeps keyboard focus in the input after the toggle was clicked.
+pub fn refocus_password_input(
+    toggle_q: Query<(&Interaction, &ChildOf), (Changed<Interaction>, With<PasswordToggleMarker>)>,
+    field_q: Query<&Children, With<PasswordToggleFieldMarker>>,
+    input_q: Query<(), With<PasswordInputMarker>>,
+    mut focus: ResMut<InputFocus>,
+) {
+    for (interaction, parent) in toggle_q.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok(children) = field_q.get(parent.parent()) else {
+            continue;
+        };
+        if let Some(input) = children.iter().find(|c| input_q.contains(*c)) {
+            focus.set(input);
+        }
+    }
+}
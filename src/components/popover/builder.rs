@@ -1,8 +1,12 @@
 use bevy::prelude::*;
 use bevy::ui::FocusPolicy;
 
+use crate::components::dismissable_layer::DismissableLayer;
+use crate::components::floating::{Floating, FloatingAlign, FloatingSide};
 use crate::components::helper::UiBuilder;
 use crate::components::label::LabelBuilder;
+use crate::components::layer::{Layer, UiLayer};
+use crate::components::portal::Portal;
 use crate::theme::UiTheme;
 
 use super::{
@@ -10,11 +14,16 @@ use super::{
 };
 
 /// Builder for a simple popover component.
+///
+/// The content floats next to the trigger (see [`Floating`]) and is rendered
+/// through a [`Portal`], so clipping ancestors do not cut it off.
 pub struct PopoverBuilder {
     trigger: String,
     content:
         Option<Box<dyn FnOnce(&mut ChildSpawnerCommands, &UiTheme, &Handle<Font>) + Send + Sync>>,
     open: bool,
+    side: FloatingSide,
+    align: FloatingAlign,
 }
 
 impl PopoverBuilder {
@@ -23,6 +32,8 @@ impl PopoverBuilder {
             trigger: trigger.into(),
             content: None,
             open: false,
+            side: FloatingSide::Bottom,
+            align: FloatingAlign::Center,
         }
     }
 
@@ -38,6 +49,17 @@ impl PopoverBuilder {
         self.open = open;
         self
     }
+
+    /// Preferred side of the trigger.
+    pub fn side(mut self, side: FloatingSide) -> Self {
+        self.side = side;
+        self
+    }
+
+    pub fn align(mut self, align: FloatingAlign) -> Self {
+        self.align = align;
+        self
+    }
 }
 
 impl<'w, 's> UiBuilder<'w, 's> for PopoverBuilder {
@@ -55,7 +77,7 @@ impl<'w, 's> UiBuilder<'w, 's> for PopoverBuilder {
             Name::new("Popover"),
         ));
         root.with_children(|rc| {
-            rc.spawn((
+            let trigger = rc.spawn((
                 PopoverTriggerMarker,
                 Node {
                     display: Display::Flex,
@@ -67,11 +89,16 @@ impl<'w, 's> UiBuilder<'w, 's> for PopoverBuilder {
             ))
             .with_children(|tc| {
                 let _ = LabelBuilder::new(self.trigger.clone()).spawn(tc, theme, font);
-            });
+            })
+            .id();
 
             let mut content_cmd = rc.spawn((
                 PopoverContentMarker,
                 PopoverContentStyle::new(theme),
+                Floating::new(trigger).side(self.side).align(self.align),
+                Portal::global(),
+                Layer(UiLayer::Popover),
+                DismissableLayer::new().branch(trigger),
                 if self.open {
                     Visibility::Inherited
                 } else {
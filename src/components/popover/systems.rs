@@ -1,18 +1,21 @@
 use bevy::prelude::*;
 
 use super::{PopoverContentMarker, PopoverMarker, PopoverState, PopoverTriggerMarker};
+use crate::components::dismissable_layer::LayerDismissedEvent;
+use crate::components::portal::PortalHierarchy;
 
 /// Toggles popover visibility when the trigger is pressed.
 pub fn handle_popover_toggle(
     mut trigger_q: Query<(&Interaction, &bevy::prelude::ChildOf), (Changed<Interaction>, With<PopoverTriggerMarker>)>,
-    mut root_q: Query<(&mut PopoverState, &Children), With<PopoverMarker>>,
+    mut root_q: Query<&mut PopoverState, With<PopoverMarker>>,
     mut content_q: Query<&mut Visibility, With<PopoverContentMarker>>,
+    hierarchy: PortalHierarchy,
 ) {
     for (interaction, parent) in trigger_q.iter_mut() {
         if *interaction == Interaction::Pressed {
-            if let Ok((mut state, children)) = root_q.get_mut(parent.parent()) {
+            if let Ok(mut state) = root_q.get_mut(parent.parent()) {
                 state.open = !state.open;
-                for child in children.iter() {
+                for child in hierarchy.children(parent.parent()) {
                     if let Ok(mut vis) = content_q.get_mut(child) {
                         *vis = if state.open { Visibility::Inherited } else { Visibility::Hidden };
                     }
@@ -22,3 +25,20 @@ pub fn handle_popover_toggle(
     }
 }
 
+/// Closes the popover when its content was dismissed (Escape, click or focus outside).
+pub fn handle_popover_dismiss(
+    mut dismissed: EventReader<LayerDismissedEvent>,
+    mut root_q: Query<&mut PopoverState, With<PopoverMarker>>,
+    mut content_q: Query<&mut Visibility, With<PopoverContentMarker>>,
+    hierarchy: PortalHierarchy,
+) {
+    for event in dismissed.read() {
+        let Ok(mut vis) = content_q.get_mut(event.layer) else {
+            continue;
+        };
+        *vis = Visibility::Hidden;
+        if let Some(mut state) = hierarchy.parent(event.layer).and_then(|root| root_q.get_mut(root).ok()) {
+            state.open = false;
+        }
+    }
+}
@@ -1,48 +1,56 @@
 // src/components/portal/builder.rs
-use super::components::ForgeUiPortalRoot;
+use super::components::{Portal, PortalOwner};
 use crate::theme::UiTheme;
 use bevy::prelude::*;
 
-pub(crate) struct PortalContentBuilder;
+/// Spawnt Inhalte, die außerhalb ihres Parents gerendert werden.
+pub struct PortalContentBuilder;
 
 impl PortalContentBuilder {
-    /// Spawnt dynamischen UI‑Inhalt unter `target_container_entity`
-    /// und gibt die obersten neu erzeugten Kinder zurück.
-    pub(crate) fn spawn_at_target<'w>(
+    /// Spawnt dynamischen UI‑Inhalt über das angegebene [`Portal`]
+    /// und gibt die Root‑Entity des Inhalts zurück.
+    pub fn spawn<'w>(
         parent: &mut ChildSpawnerCommands<'w>,
-        target_container_entity: Entity,
+        portal: Portal,
         theme: &UiTheme,
         font: &Handle<Font>,
-        content_builder: impl FnOnce(&mut ChildSpawnerCommands, &UiTheme, &Handle<Font>)
-            + Send
-            + Sync
-            + 'static,
-    ) -> Vec<Entity> {
-        // ‑‑> Root‑Node für diesen Content anlegen
-        let mut root_cmd = parent.spawn_empty(); // neues leeres Entity
-        root_cmd.insert(ChildOf(target_container_entity));
-
-        let root_id = root_cmd.id();
-
-        // jetzt die eigentlichen Widgets darunter bauen
-        root_cmd.with_children(|builder| {
-            content_builder(builder, theme, font);
-        });
+        content_builder: impl FnOnce(&mut ChildSpawnerCommands, &UiTheme, &Handle<Font>),
+    ) -> Entity {
+        // Das Umhängen übernimmt `apply_portals`, der Parent bleibt Owner
+        parent
+            .spawn((Node::default(), portal))
+            .with_children(|builder| content_builder(builder, theme, font))
+            .id()
+    }
 
-        vec![root_id] // oder sammle mehr, wenn nötig
+    /// Spawnt dynamischen UI‑Inhalt direkt unter `target_container_entity`;
+    /// der aktuelle Parent bleibt als [`PortalOwner`] verknüpft.
+    pub fn spawn_at_target<'w>(
+        parent: &mut ChildSpawnerCommands<'w>,
+        target_container_entity: Entity,
+        theme: &UiTheme,
+        font: &Handle<Font>,
+        content_builder: impl FnOnce(&mut ChildSpawnerCommands, &UiTheme, &Handle<Font>),
+    ) -> Entity {
+        let owner = parent.target_entity();
+        parent
+            .commands_mut()
+            .spawn((
+                Node::default(),
+                PortalOwner(owner),
+                ChildOf(target_container_entity),
+            ))
+            .with_children(|builder| content_builder(builder, theme, font))
+            .id()
     }
 
     /// Variante: hängt an das globale Portal‑Root an.
-    pub(crate) fn spawn_in_global_root<'w>(
+    pub fn spawn_in_global_root<'w>(
         parent: &mut ChildSpawnerCommands<'w>,
-        global_root: Res<ForgeUiPortalRoot>,
         theme: &UiTheme,
         font: &Handle<Font>,
-        content_builder: impl FnOnce(&mut ChildSpawnerCommands, &UiTheme, &Handle<Font>)
-            + Send
-            + Sync
-            + 'static,
-    ) -> Vec<Entity> {
-        Self::spawn_at_target(parent, global_root.0, theme, font, content_builder)
+        content_builder: impl FnOnce(&mut ChildSpawnerCommands, &UiTheme, &Handle<Font>),
+    ) -> Entity {
+        Self::spawn(parent, Portal::global(), theme, font, content_builder)
     }
 }
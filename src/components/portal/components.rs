@@ -10,3 +10,44 @@ impl Default for ForgeUiPortalRoot {
         ForgeUiPortalRoot(Entity::from_raw(u32::MAX)) // Oder eine andere ungültige Standard-Entity
     }
 }
+
+/// Renders an overlay outside of its place in the hierarchy.
+///
+/// The entity is moved under the global portal root (or under the
+/// [`PortalTarget`] with the given name) so that `Overflow::clip()` on its
+/// ancestors no longer cuts it off. The original parent stays reachable as
+/// [`PortalOwner`] and despawning it also despawns the portaled content.
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Portal {
+    /// Name of the [`PortalTarget`] to render into, `None` for the global root.
+    pub target: Option<String>,
+}
+
+impl Portal {
+    /// Renders into the global portal root.
+    pub fn global() -> Self {
+        Self::default()
+    }
+
+    /// Renders into the [`PortalTarget`] with the given name.
+    pub fn to(target: impl Into<String>) -> Self {
+        Self {
+            target: Some(target.into()),
+        }
+    }
+}
+
+/// Named container that [`Portal`]s can render into.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct PortalTarget(pub String);
+
+/// Logical owner of portaled content, i.e. the parent it was spawned under.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[relationship(relationship_target = PortalContents)]
+pub struct PortalOwner(pub Entity);
+
+/// Content this entity renders through a [`Portal`]. Despawned together with
+/// the owner.
+#[derive(Component, Debug, Default, PartialEq, Eq)]
+#[relationship_target(relationship = PortalOwner, linked_spawn)]
+pub struct PortalContents(Vec<Entity>);
@@ -0,0 +1,61 @@
+// src/components/portal/hierarchy.rs
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use super::components::{PortalContents, PortalOwner};
+
+/// Walks the UI hierarchy as the user sees it: portaled content counts as a
+/// child of its [`PortalOwner`] instead of the portal root.
+#[derive(SystemParam)]
+pub struct PortalHierarchy<'w, 's> {
+    parents: Query<'w, 's, &'static ChildOf>,
+    owners: Query<'w, 's, &'static PortalOwner>,
+    children: Query<'w, 's, &'static Children>,
+    contents: Query<'w, 's, &'static PortalContents>,
+}
+
+impl PortalHierarchy<'_, '_> {
+    /// Logical parent: the portal owner if there is one, else the `ChildOf` parent.
+    pub fn parent(&self, entity: Entity) -> Option<Entity> {
+        match self.owners.get(entity) {
+            Ok(owner) => Some(owner.0),
+            Err(_) => self.parents.get(entity).ok().map(ChildOf::parent),
+        }
+    }
+
+    /// Logical children: regular children followed by portaled content.
+    pub fn children(&self, entity: Entity) -> impl Iterator<Item = Entity> + '_ {
+        let children = self
+            .children
+            .get(entity)
+            .ok()
+            .into_iter()
+            .flat_map(|c| c.iter());
+        let contents = self
+            .contents
+            .get(entity)
+            .ok()
+            .into_iter()
+            .flat_map(|c| c.iter());
+        children.chain(contents)
+    }
+
+    /// Logical ancestors, starting with the parent.
+    pub fn ancestors(&self, entity: Entity) -> impl Iterator<Item = Entity> + '_ {
+        std::iter::successors(self.parent(entity), |e| self.parent(*e))
+    }
+
+    /// All logical descendants, depth first.
+    pub fn descendants(&self, entity: Entity) -> Vec<Entity> {
+        let mut found = Vec::new();
+        let mut stack: Vec<Entity> = self.children(entity).collect();
+        stack.reverse();
+        while let Some(next) = stack.pop() {
+            found.push(next);
+            let start = stack.len();
+            stack.extend(self.children(next));
+            stack[start..].reverse();
+        }
+        found
+    }
+}
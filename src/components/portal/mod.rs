@@ -2,9 +2,11 @@
 
 mod builder;
 mod components;
+mod hierarchy;
 mod plugin;
 mod systems;
 
-pub(crate) use builder::PortalContentBuilder; // Öffentlich zugänglich
-pub use components::ForgeUiPortalRoot; // Öffentlich zugänglich
+pub use builder::PortalContentBuilder;
+pub use components::{ForgeUiPortalRoot, Portal, PortalContents, PortalOwner, PortalTarget};
+pub use hierarchy::PortalHierarchy;
 pub use plugin::PortalPlugin;
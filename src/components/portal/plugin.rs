@@ -1,17 +1,24 @@
 use bevy::prelude::*;
+use bevy::ui::UiSystem;
 
-use super::{components::ForgeUiPortalRoot, systems::setup_global_portal_root};
+use super::{
+    components::ForgeUiPortalRoot,
+    systems::{apply_portals, setup_global_portal_root},
+};
 use crate::plugin::UiState;
 
 /// Plugin for portal support.
 ///
 /// This plugin initializes a [`ForgeUiPortalRoot`] resource and spawns
 /// the global UI portal root once the UI enters the [`UiState::Ready`] state.
+/// Entities with a [`Portal`](super::Portal) are moved to their target
+/// before each layout pass.
 pub struct PortalPlugin;
 
 impl Plugin for PortalPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(ForgeUiPortalRoot::default())
-            .add_systems(OnEnter(UiState::Ready), setup_global_portal_root);
+            .add_systems(OnEnter(UiState::Ready), setup_global_portal_root)
+            .add_systems(PostUpdate, apply_portals.before(UiSystem::Layout));
     }
 }
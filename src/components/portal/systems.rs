@@ -1,5 +1,5 @@
 // In forge_ui/src/components/portal/systems.rs
-use super::components::ForgeUiPortalRoot;
+use super::components::{ForgeUiPortalRoot, Portal, PortalOwner, PortalTarget};
 use bevy::prelude::*; // Pfad anpassen
 
 pub(crate) fn setup_global_portal_root(mut commands: Commands) {
@@ -16,11 +16,43 @@ pub(crate) fn setup_global_portal_root(mut commands: Commands) {
                 // Für reine "Anhängepunkte" ist keine spezifische Darstellung nötig.
                 ..default()
             },
-            // background_color: Color::NONE.into(), // Unsichtbar
-            // z_index: GlobalZIndex::new(1000), // Sehr hoher Z-Index, falls nötig
+            // Über allen anderen UI-Wurzeln zeichnen
+            GlobalZIndex(1000),
             Name::new("GlobalUIPortalRoot"),
         ))
         .id();
     commands.insert_resource(ForgeUiPortalRoot(root_entity));
     info!("GlobalUIPortalRoot {:?} wurde initialisiert.", root_entity);
 }
+
+/// Moves every [`Portal`] under its target and remembers the original parent
+/// as [`PortalOwner`]. Targets that do not exist yet are retried next frame.
+pub(crate) fn apply_portals(
+    mut commands: Commands,
+    root: Res<ForgeUiPortalRoot>,
+    portals: Query<(Entity, &Portal, Option<&ChildOf>, Has<PortalOwner>)>,
+    targets: Query<(Entity, &PortalTarget)>,
+    nodes: Query<(), With<Node>>,
+) {
+    for (entity, portal, child_of, has_owner) in portals.iter() {
+        let target = match &portal.target {
+            None => Some(root.0).filter(|e| nodes.contains(*e)),
+            Some(name) => targets
+                .iter()
+                .find(|(_, target)| &target.0 == name)
+                .map(|(e, _)| e),
+        };
+        let Some(target) = target else {
+            continue;
+        };
+        let parent = child_of.map(ChildOf::parent);
+        if parent == Some(target) {
+            continue;
+        }
+        let mut entity_commands = commands.entity(entity);
+        if let (Some(parent), false) = (parent, has_owner) {
+            entity_commands.insert(PortalOwner(parent));
+        }
+        entity_commands.insert(ChildOf(target));
+    }
+}
@@ -2,6 +2,7 @@
 use bevy::prelude::*;
 
 use super::{RadioSize, RadioVariant};
+use crate::components::disabled::DisabledFlag;
 
 /// Marker component for the inner indicator dot.
 #[derive(Component)]
@@ -21,6 +22,16 @@ pub struct RadioState {
     pub size: RadioSize,
 }
 
+impl DisabledFlag for RadioState {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+}
+
 /// Wrapper component carrying the callback for a radio selection.
 #[derive(Component)]
 pub struct OnSelect(pub Box<dyn Fn(String) + Send + Sync + 'static>);
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 
 use crate::components::{
+    disabled::Disabled,
     label::LabelBuilder,
     radio::{RadioBuilder, RadioSize, RadioVariant},
 };
@@ -8,7 +9,7 @@ use crate::theme::UiTheme;
 
 use super::{
     components::RadioCardMarker,
-    style::{spawn_disabled_overlay, RadioCardStyle},
+    style::RadioCardStyle,
 };
 
 /// Fluent builder for a single radio card with label.
@@ -81,7 +82,6 @@ impl RadioCardBuilder {
                 .size(RadioSize::Medium)
                 .group(self.group.clone())
                 .checked(self.checked)
-                .disabled(self.disabled)
                 .spawn(p, theme, font);
 
             let _ = LabelBuilder::new(self.label.clone())
@@ -90,7 +90,7 @@ impl RadioCardBuilder {
         });
 
         if self.disabled {
-            spawn_disabled_overlay(&mut cmd, theme, style.border_radius);
+            cmd.insert(Disabled);
         }
 
         cmd
@@ -3,6 +3,7 @@ use bevy::prelude::*;
 use crate::components::{
     label::LabelBuilder, // already exists in forge_ui
     radio::{RadioBuilder, RadioSize, RadioVariant},
+    roving_focus::{RovingFocusGroup, RovingFocusItem, RovingFocusOrientation},
 };
 use crate::theme::UiTheme;
 
@@ -23,6 +24,7 @@ pub struct RadioGroupBuilder {
     orientation: RadioGroupOrientation,
     options: Vec<(String, String)>, // (value, label)
     selected: Option<String>,
+    loop_navigation: bool,
 }
 
 impl RadioGroupBuilder {
@@ -36,6 +38,7 @@ impl RadioGroupBuilder {
             orientation: RadioGroupOrientation::Vertical,
             options: Vec::new(),
             selected: None,
+            loop_navigation: true,
         }
     }
 
@@ -49,6 +52,12 @@ impl RadioGroupBuilder {
         self
     }
 
+    /// Whether arrow-key navigation wraps around at the ends (default: `true`).
+    pub fn loop_navigation(mut self, flag: bool) -> Self {
+        self.loop_navigation = flag;
+        self
+    }
+
     /// Pre‑select the radio whose `value` matches the given string.
     pub fn selected(mut self, value: impl Into<String>) -> Self {
         self.selected = Some(value.into());
@@ -89,6 +98,13 @@ impl RadioGroupBuilder {
                 selected_value: self.selected.clone(),
                 disabled: self.disabled,
             },
+            RovingFocusGroup::new(
+                match self.orientation {
+                    RadioGroupOrientation::Horizontal => RovingFocusOrientation::Horizontal,
+                    RadioGroupOrientation::Vertical => RovingFocusOrientation::Vertical,
+                },
+                self.loop_navigation,
+            ),
         ));
 
         // ── Child radios + labels ─────────────────────────────────────────────
@@ -111,13 +127,14 @@ impl RadioGroupBuilder {
                             ..default()
                         })
                         .with_children(|parent| {
-                            let _ = RadioBuilder::new(&value)
+                            RadioBuilder::new(&value)
                                 .variant(RadioVariant::Primary)
                                 .size(RadioSize::Medium)
                                 .group(name.clone())
                                 .disabled(disabled)
                                 .checked(is_checked)
-                                .spawn(parent, theme, font);
+                                .spawn(parent, theme, font)
+                                .insert(RovingFocusItem);
 
                             let _ = LabelBuilder::new(label_text).spawn(parent, theme, font);
                         });
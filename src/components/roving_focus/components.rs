@@ -0,0 +1,56 @@
+use bevy::input_focus::tab_navigation::TabGroup;
+use bevy::prelude::*;
+
+/// Marker placed on the entity that currently holds keyboard focus.
+///
+/// Mirrors bevy's [`InputFocus`](bevy::input_focus::InputFocus) resource so
+/// widgets can filter queries with `With<FocusState>`.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FocusState;
+
+/// Axis along which arrow keys move focus inside a [`RovingFocusGroup`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RovingFocusOrientation {
+    /// Left/Right move focus (mirrored under a right-to-left provider).
+    #[default]
+    Horizontal,
+    /// Up/Down move focus.
+    Vertical,
+    /// Both arrow axes move focus.
+    Both,
+}
+
+/// Composite widget root whose [`RovingFocusItem`] descendants share a single
+/// tab stop.
+///
+/// Only one item is reachable with Tab at a time (`TabIndex(0)`), all others
+/// carry `TabIndex(-1)`. Arrow keys, Home and End move focus between items.
+#[derive(Component, Debug, Clone, Copy)]
+#[require(TabGroup)]
+pub struct RovingFocusGroup {
+    pub orientation: RovingFocusOrientation,
+    /// Wrap from the last item to the first (and vice versa).
+    pub loop_navigation: bool,
+}
+
+impl Default for RovingFocusGroup {
+    fn default() -> Self {
+        Self {
+            orientation: RovingFocusOrientation::Horizontal,
+            loop_navigation: true,
+        }
+    }
+}
+
+impl RovingFocusGroup {
+    pub fn new(orientation: RovingFocusOrientation, loop_navigation: bool) -> Self {
+        Self {
+            orientation,
+            loop_navigation,
+        }
+    }
+}
+
+/// Focusable item inside a [`RovingFocusGroup`].
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct RovingFocusItem;
@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+/// Request to move keyboard focus programmatically.
+#[derive(Event, Debug, Clone)]
+pub enum FocusEvent {
+    Focus { entity: Entity },
+    Blur { entity: Entity },
+}
@@ -0,0 +1,35 @@
+use bevy::input_focus::{tab_navigation::TabNavigationPlugin, InputDispatchPlugin};
+use bevy::prelude::*;
+
+use super::{events::FocusEvent, systems::*};
+use crate::plugin::UiState;
+
+/// Plugin for keyboard focus and roving-tabindex navigation.
+///
+/// Adds bevy's input-focus and tab-navigation plugins if the app has not
+/// registered them already.
+pub struct RovingFocusPlugin;
+
+impl Plugin for RovingFocusPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<InputDispatchPlugin>() {
+            app.add_plugins(InputDispatchPlugin);
+        }
+        if !app.is_plugin_added::<TabNavigationPlugin>() {
+            app.add_plugins(TabNavigationPlugin);
+        }
+
+        app.add_event::<FocusEvent>().add_systems(
+            Update,
+            (
+                apply_focus_events,
+                focus_roving_item_on_press,
+                handle_roving_focus_keyboard,
+                sync_roving_tab_indices,
+                sync_focus_state,
+            )
+                .chain()
+                .run_if(in_state(UiState::Ready)),
+        );
+    }
+}
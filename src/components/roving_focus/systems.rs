@@ -0,0 +1,275 @@
+use bevy::input_focus::{tab_navigation::TabIndex, InputFocus, InputFocusVisible};
+use bevy::prelude::*;
+
+use super::{FocusEvent, FocusState, RovingFocusGroup, RovingFocusItem, RovingFocusOrientation};
+use crate::components::direction_provider::{
+    resolve_reading_direction, DirectionProvider, ReadingDirection,
+};
+use crate::components::disabled::InheritedDisabled;
+
+/// Collects the items of `group` in tree order. Nested groups are skipped,
+/// their items belong to the inner group.
+pub(crate) fn collect_roving_items(
+    group: Entity,
+    children_q: &Query<&Children>,
+    is_item: &impl Fn(Entity) -> bool,
+    is_group: &impl Fn(Entity) -> bool,
+) -> Vec<Entity> {
+    fn walk(
+        entity: Entity,
+        children_q: &Query<&Children>,
+        is_item: &impl Fn(Entity) -> bool,
+        is_group: &impl Fn(Entity) -> bool,
+        out: &mut Vec<Entity>,
+    ) {
+        let Ok(children) = children_q.get(entity) else {
+            return;
+        };
+        for child in children.iter() {
+            if is_group(child) {
+                continue;
+            }
+            if is_item(child) {
+                out.push(child);
+            }
+            walk(child, children_q, is_item, is_group, out);
+        }
+    }
+
+    let mut out = Vec::new();
+    walk(group, children_q, is_item, is_group, &mut out);
+    out
+}
+
+/// Walks up from `entity` to the nearest [`RovingFocusGroup`].
+pub(crate) fn find_roving_group(
+    entity: Entity,
+    parents: &Query<&ChildOf>,
+    is_group: &impl Fn(Entity) -> bool,
+) -> Option<Entity> {
+    let mut current = entity;
+    while let Ok(parent) = parents.get(current) {
+        current = parent.parent();
+        if is_group(current) {
+            return Some(current);
+        }
+    }
+    None
+}
+
+/// Keys that move to the previous / next item for the given orientation.
+/// Left and Right swap under a right-to-left reading direction.
+fn navigation_keys(
+    orientation: RovingFocusOrientation,
+    direction: ReadingDirection,
+) -> (&'static [KeyCode], &'static [KeyCode]) {
+    let rtl = direction == ReadingDirection::Rtl;
+    match (orientation, rtl) {
+        (RovingFocusOrientation::Horizontal, false) => {
+            (&[KeyCode::ArrowLeft], &[KeyCode::ArrowRight])
+        }
+        (RovingFocusOrientation::Horizontal, true) => {
+            (&[KeyCode::ArrowRight], &[KeyCode::ArrowLeft])
+        }
+        (RovingFocusOrientation::Vertical, _) => (&[KeyCode::ArrowUp], &[KeyCode::ArrowDown]),
+        (RovingFocusOrientation::Both, false) => (
+            &[KeyCode::ArrowLeft, KeyCode::ArrowUp],
+            &[KeyCode::ArrowRight, KeyCode::ArrowDown],
+        ),
+        (RovingFocusOrientation::Both, true) => (
+            &[KeyCode::ArrowRight, KeyCode::ArrowUp],
+            &[KeyCode::ArrowLeft, KeyCode::ArrowDown],
+        ),
+    }
+}
+
+/// Applies [`FocusEvent`]s to the [`InputFocus`] resource.
+pub fn apply_focus_events(
+    mut focus_events: EventReader<FocusEvent>,
+    mut focus: ResMut<InputFocus>,
+    mut visible: ResMut<InputFocusVisible>,
+) {
+    for event in focus_events.read() {
+        match event {
+            FocusEvent::Focus { entity } => {
+                focus.set(*entity);
+                visible.0 = true;
+            }
+            FocusEvent::Blur { entity } => {
+                if focus.get() == Some(*entity) {
+                    focus.clear();
+                }
+            }
+        }
+    }
+}
+
+/// Moves focus to the nearest focusable entity (one with a [`TabIndex`]) when
+/// it or one of its children is pressed.
+pub fn focus_roving_item_on_press(
+    pressed: Query<(Entity, &Interaction), Changed<Interaction>>,
+    focusable: Query<(), With<TabIndex>>,
+    parents: Query<&ChildOf>,
+    mut focus: ResMut<InputFocus>,
+    mut visible: ResMut<InputFocusVisible>,
+) {
+    for (entity, interaction) in pressed.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let mut current = entity;
+        loop {
+            if focusable.contains(current) {
+                if focus.get() != Some(current) {
+                    focus.set(current);
+                }
+                // Pointer focus does not show a focus ring.
+                visible.0 = false;
+                break;
+            }
+            match parents.get(current) {
+                Ok(parent) => current = parent.parent(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+/// Arrow keys, Home and End move focus between the items of the focused
+/// item's [`RovingFocusGroup`].
+pub fn handle_roving_focus_keyboard(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut focus: ResMut<InputFocus>,
+    mut visible: ResMut<InputFocusVisible>,
+    groups: Query<&RovingFocusGroup>,
+    items: Query<(), (With<RovingFocusItem>, Without<InheritedDisabled>)>,
+    children_q: Query<&Children>,
+    parents: Query<&ChildOf>,
+    providers: Query<&DirectionProvider>,
+) {
+    let Some(focused) = focus.get() else {
+        return;
+    };
+    if !items.contains(focused) {
+        return;
+    }
+
+    let is_group = |e: Entity| groups.contains(e);
+    let is_item = |e: Entity| items.contains(e);
+    let Some(group_entity) = find_roving_group(focused, &parents, &is_group) else {
+        return;
+    };
+    let Ok(group) = groups.get(group_entity) else {
+        return;
+    };
+
+    let list = collect_roving_items(group_entity, &children_q, &is_item, &is_group);
+    let Some(current) = list.iter().position(|e| *e == focused) else {
+        return;
+    };
+    let last = list.len() - 1;
+
+    let direction = resolve_reading_direction(group_entity, &parents, &providers);
+    let (prev_keys, next_keys) = navigation_keys(group.orientation, direction);
+
+    let target = if keyboard.any_just_pressed(prev_keys.iter().copied()) {
+        if current > 0 {
+            Some(current - 1)
+        } else if group.loop_navigation {
+            Some(last)
+        } else {
+            None
+        }
+    } else if keyboard.any_just_pressed(next_keys.iter().copied()) {
+        if current < last {
+            Some(current + 1)
+        } else if group.loop_navigation {
+            Some(0)
+        } else {
+            None
+        }
+    } else if keyboard.just_pressed(KeyCode::Home) {
+        Some(0)
+    } else if keyboard.just_pressed(KeyCode::End) {
+        Some(last)
+    } else {
+        None
+    };
+
+    if let Some(index) = target {
+        if index != current {
+            focus.set(list[index]);
+            visible.0 = true;
+        }
+    }
+}
+
+/// Keeps exactly one tab stop per [`RovingFocusGroup`].
+///
+/// New groups make their first item tabbable; afterwards the most recently
+/// focused item is the one Tab returns to.
+pub fn sync_roving_tab_indices(
+    mut commands: Commands,
+    focus: Res<InputFocus>,
+    added_groups: Query<Entity, Added<RovingFocusGroup>>,
+    groups: Query<(), With<RovingFocusGroup>>,
+    items: Query<Option<&TabIndex>, (With<RovingFocusItem>, Without<InheritedDisabled>)>,
+    children_q: Query<&Children>,
+    parents: Query<&ChildOf>,
+) {
+    let is_group = |e: Entity| groups.contains(e);
+    let is_item = |e: Entity| items.contains(e);
+
+    for group in added_groups.iter() {
+        let list = collect_roving_items(group, &children_q, &is_item, &is_group);
+        for (i, item) in list.into_iter().enumerate() {
+            commands
+                .entity(item)
+                .insert(TabIndex(if i == 0 { 0 } else { -1 }));
+        }
+    }
+
+    if !focus.is_changed() {
+        return;
+    }
+    let Some(focused) = focus.get() else {
+        return;
+    };
+    if !items.contains(focused) {
+        return;
+    }
+    let Some(group) = find_roving_group(focused, &parents, &is_group) else {
+        return;
+    };
+
+    for item in collect_roving_items(group, &children_q, &is_item, &is_group) {
+        let index = if item == focused { 0 } else { -1 };
+        let current = items.get(item).ok().flatten().map(|t| t.0);
+        if current != Some(index) {
+            commands.entity(item).insert(TabIndex(index));
+        }
+    }
+}
+
+/// Mirrors [`InputFocus`] onto the [`FocusState`] marker.
+pub fn sync_focus_state(
+    mut commands: Commands,
+    focus: Res<InputFocus>,
+    focused: Query<Entity, With<FocusState>>,
+) {
+    if !focus.is_changed() {
+        return;
+    }
+    for entity in focused.iter() {
+        if Some(entity) != focus.get() {
+            commands.entity(entity).try_remove::<FocusState>();
+        }
+    }
+    if let Some(entity) = focus.get() {
+        if !focused.contains(entity) {
+            if let Ok(mut cmd) = commands.get_entity(entity) {
+                cmd.try_insert(FocusState);
+            }
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
 
 use crate::components::helper::UiBuilder;
 use crate::theme::UiTheme;
@@ -10,12 +11,13 @@ use super::style::{ScrollAreaStyle, ScrollAreaViewportStyle};
 pub struct ScrollAreaBuilder {
     width: Option<Val>,
     height: Option<Val>,
+    max_height: Option<Val>,
     content: Option<Box<dyn FnOnce(&mut ChildSpawnerCommands, &UiTheme, &Handle<Font>) + Send + Sync>>,
 }
 
 impl Default for ScrollAreaBuilder {
     fn default() -> Self {
-        Self { width: None, height: None, content: None }
+        Self { width: None, height: None, max_height: None, content: None }
     }
 }
 
@@ -34,6 +36,13 @@ impl ScrollAreaBuilder {
         self
     }
 
+    /// Lets the area grow with its content up to `max_height`. Use together
+    /// with `height(Val::Auto)`.
+    pub fn max_height(mut self, max_height: Val) -> Self {
+        self.max_height = Some(max_height);
+        self
+    }
+
     pub fn content<F>(mut self, f: F) -> Self
     where
         F: FnOnce(&mut ChildSpawnerCommands, &UiTheme, &Handle<Font>) + Send + Sync + 'static,
@@ -50,7 +59,13 @@ impl<'w, 's> UiBuilder<'w, 's> for ScrollAreaBuilder {
         let mut style = ScrollAreaStyle::new(theme);
         if let Some(w) = self.width { style.node.width = w; }
         if let Some(h) = self.height { style.node.height = h; }
-        let mut root = parent.spawn((ScrollAreaMarker, style, Name::new("ScrollArea")));
+        if let Some(h) = self.max_height { style.node.max_height = h; }
+        let mut root = parent.spawn((
+            ScrollAreaMarker,
+            style,
+            RelativeCursorPosition::default(),
+            Name::new("ScrollArea"),
+        ));
         root.with_children(|rc| {
             let mut v_style = ScrollAreaViewportStyle::new(theme);
             if let Some(w) = self.width { v_style.node.width = w; }
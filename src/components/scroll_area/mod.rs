@@ -1,7 +1,11 @@
 mod builder;
 mod components;
+mod plugin;
 mod style;
+mod systems;
 
 pub use builder::ScrollAreaBuilder;
 pub use components::{ScrollAreaMarker, ScrollAreaViewportMarker};
+pub use plugin::ScrollAreaPlugin;
 pub use style::{ScrollAreaStyle, ScrollAreaViewportStyle};
+pub use systems::scroll_area_mouse_wheel;
@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+
+use super::systems::scroll_area_mouse_wheel;
+use crate::plugin::UiState;
+
+/// Plugin for mouse-wheel scrolling of [`ScrollAreaBuilder`](super::ScrollAreaBuilder) areas.
+pub struct ScrollAreaPlugin;
+
+impl Plugin for ScrollAreaPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            scroll_area_mouse_wheel.run_if(in_state(UiState::Ready)),
+        );
+    }
+}
@@ -14,7 +14,7 @@ impl ScrollAreaStyle {
             node: Node {
                 display: Display::Flex,
                 flex_direction: FlexDirection::Column,
-                overflow: Overflow::clip_y(),
+                overflow: Overflow::scroll_y(),
                 width: Val::Px(200.0),
                 height: Val::Px(120.0),
                 ..default()
@@ -0,0 +1,40 @@
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+
+use super::ScrollAreaMarker;
+
+/// Pixels scrolled per wheel line.
+const LINE_HEIGHT: f32 = 20.0;
+
+/// Scrolls the innermost hovered scroll area with the mouse wheel.
+pub fn scroll_area_mouse_wheel(
+    mut wheel: EventReader<MouseWheel>,
+    mut areas: Query<
+        (&RelativeCursorPosition, &ComputedNode, &mut ScrollPosition),
+        With<ScrollAreaMarker>,
+    >,
+) {
+    let mut delta = 0.0;
+    for event in wheel.read() {
+        delta += match event.unit {
+            MouseScrollUnit::Line => event.y * LINE_HEIGHT,
+            MouseScrollUnit::Pixel => event.y,
+        };
+    }
+    if delta == 0.0 {
+        return;
+    }
+
+    // Verschachtelte Bereiche: der oberste (zuletzt gezeichnete) gewinnt
+    if let Some((_, node, mut position)) = areas
+        .iter_mut()
+        .filter(|(cursor, ..)| cursor.mouse_over())
+        .max_by_key(|(_, node, _)| node.stack_index())
+    {
+        // Wie das Layout: höchstens bis zum Ende des Inhalts scrollen
+        let max_offset =
+            ((node.content_size().y - node.size().y) * node.inverse_scale_factor()).max(0.0);
+        position.offset_y = (position.offset_y - delta).clamp(0.0, max_offset);
+    }
+}
@@ -1,13 +1,18 @@
 use bevy::prelude::*;
 use bevy::ui::FocusPolicy;
 
+use crate::components::direction_provider::MirrorInRtl;
+use crate::components::dismissable_layer::DismissableLayer;
+use crate::components::floating::{Floating, FloatingAlign};
 use crate::components::helper::UiBuilder;
 use crate::components::label::LabelBuilder;
+use crate::components::layer::{Layer, UiLayer};
+use crate::components::portal::Portal;
 use crate::theme::UiTheme;
 
 use super::{
     components::{
-        SelectContentMarker, SelectMarker, SelectOptionMarker, SelectState, SelectTriggerMarker,
+        SelectChevronMarker, SelectContentMarker, SelectMarker, SelectOptionMarker, SelectState, SelectTriggerMarker,
         SelectTriggerTextMarker,
     },
     style::{SelectContentStyle, SelectOptionStyle, SelectRootStyle, SelectTriggerStyle},
@@ -19,6 +24,7 @@ pub struct SelectBuilder {
     selected: Option<String>,
     width: Option<Val>,
     open: bool,
+    chevron: Option<Handle<Image>>,
 }
 
 impl Default for SelectBuilder {
@@ -28,6 +34,7 @@ impl Default for SelectBuilder {
             selected: None,
             width: None,
             open: false,
+            chevron: None,
         }
     }
 }
@@ -56,6 +63,13 @@ impl SelectBuilder {
         self.open = flag;
         self
     }
+
+    /// Show a chevron icon at the end of the trigger.
+    /// The icon is mirrored automatically below an RTL `DirectionProvider`.
+    pub fn chevron(mut self, icon: Handle<Image>) -> Self {
+        self.chevron = Some(icon);
+        self
+    }
 }
 
 impl<'w, 's> UiBuilder<'w, 's> for SelectBuilder {
@@ -83,9 +97,13 @@ impl<'w, 's> UiBuilder<'w, 's> for SelectBuilder {
 
         root.with_children(|rc| {
             // Trigger
-            rc.spawn((
+            let mut trigger_style = SelectTriggerStyle::new(theme);
+            if self.chevron.is_some() {
+                trigger_style.node.justify_content = JustifyContent::SpaceBetween;
+            }
+            let trigger = rc.spawn((
                 SelectTriggerMarker,
-                SelectTriggerStyle::new(theme),
+                trigger_style,
                 Interaction::default(),
                 FocusPolicy::Block,
             ))
@@ -104,12 +122,31 @@ impl<'w, 's> UiBuilder<'w, 's> for SelectBuilder {
                 tc.commands()
                     .entity(text_entity)
                     .insert(SelectTriggerTextMarker);
-            });
+                if let Some(icon) = self.chevron.clone() {
+                    tc.spawn((
+                        SelectChevronMarker,
+                        MirrorInRtl,
+                        ImageNode::new(icon),
+                        Node {
+                            width: Val::Px(theme.font.size.base),
+                            height: Val::Px(theme.font.size.base),
+                            ..default()
+                        },
+                    ));
+                }
+            })
+            .id();
 
-            // Content with options
+            // Content with options, floating below the trigger
             let mut content = rc.spawn((
                 SelectContentMarker,
                 SelectContentStyle::new(theme),
+                Floating::new(trigger)
+                    .align(FloatingAlign::Start)
+                    .match_anchor_width(true),
+                Portal::global(),
+                Layer(UiLayer::Dropdown),
+                DismissableLayer::new().branch(trigger),
                 if self.open {
                     Visibility::Inherited
                 } else {
@@ -9,6 +9,9 @@ pub struct SelectTriggerMarker;
 #[derive(Component)]
 pub struct SelectTriggerTextMarker;
 
+#[derive(Component)]
+pub struct SelectChevronMarker;
+
 #[derive(Component)]
 pub struct SelectContentMarker;
 
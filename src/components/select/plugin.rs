@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 
-use super::systems::{handle_select_option_interaction, handle_select_trigger_interaction};
+use super::systems::{
+    handle_select_dismiss, handle_select_option_interaction, handle_select_trigger_interaction,
+};
 use crate::plugin::UiState;
 
 pub struct SelectPlugin;
@@ -10,6 +12,7 @@ impl Plugin for SelectPlugin {
         app.add_systems(
             Update,
             (
+                handle_select_dismiss,
                 handle_select_trigger_interaction,
                 handle_select_option_interaction,
             )
@@ -1,20 +1,22 @@
 use bevy::prelude::*;
-use bevy::prelude::ChildOf;
 
 use super::components::{
     SelectContentMarker, SelectMarker, SelectOptionMarker, SelectState,
     SelectTriggerMarker,
 };
+use crate::components::dismissable_layer::LayerDismissedEvent;
+use crate::components::portal::PortalHierarchy;
 
 /// Toggle select dropdown visibility.
 pub fn handle_select_trigger_interaction(
-    mut root_q: Query<(&mut SelectState, &Children), With<SelectMarker>>,
+    mut root_q: Query<(Entity, &mut SelectState), With<SelectMarker>>,
     trigger_q: Query<&Interaction, With<SelectTriggerMarker>>,
     mut content_q: Query<&mut Visibility, With<SelectContentMarker>>,
+    hierarchy: PortalHierarchy,
 ) {
-    for (mut state, children) in &mut root_q {
+    for (root, mut state) in &mut root_q {
         let mut toggled = false;
-        for child in children.iter() {
+        for child in hierarchy.children(root) {
             if let Ok(interaction) = trigger_q.get(child) {
                 if *interaction == Interaction::Pressed {
                     toggled = true;
@@ -32,32 +34,45 @@ pub fn handle_select_trigger_interaction(
 
 /// Handle option selection and update state and trigger text.
 pub fn handle_select_option_interaction(
-    mut root_q: Query<(&mut SelectState, &Children), With<SelectMarker>>,
-    option_q: Query<(&Interaction, &SelectOptionMarker, &ChildOf)>,
-    parents: Query<&ChildOf>,
+    mut root_q: Query<&mut SelectState, With<SelectMarker>>,
+    option_q: Query<(Entity, &Interaction, &SelectOptionMarker)>,
+    hierarchy: PortalHierarchy,
     mut content_q: Query<&mut Visibility, With<SelectContentMarker>>,
 ) {
-    for (interaction, option, parent) in &option_q {
+    for (entity, interaction, option) in &option_q {
         if *interaction != Interaction::Pressed {
             continue;
         }
 
-        let mut current = parent.parent();
-        loop {
-            if let Ok((mut state, children)) = root_q.get_mut(current) {
-                state.selected = Some(option.value.clone());
-                state.open = false;
-                for child in children.iter() {
-                    if let Ok(mut vis) = content_q.get_mut(child) {
-                        *vis = Visibility::Hidden;
-                    }
+        // Der Inhalt hängt im Portal, daher über den logischen Owner suchen
+        let Some(root) = hierarchy.ancestors(entity).find(|e| root_q.contains(*e)) else {
+            continue;
+        };
+        if let Ok(mut state) = root_q.get_mut(root) {
+            state.selected = Some(option.value.clone());
+            state.open = false;
+            for child in hierarchy.children(root) {
+                if let Ok(mut vis) = content_q.get_mut(child) {
+                    *vis = Visibility::Hidden;
                 }
-                break;
-            } else if let Ok(p) = parents.get(current) {
-                current = p.parent();
-            } else {
-                break;
             }
         }
     }
 }
+
+/// Closes the dropdown when its content was dismissed (Escape, click or focus outside).
+pub fn handle_select_dismiss(
+    mut dismissed: EventReader<LayerDismissedEvent>,
+    mut root_q: Query<&mut SelectState, With<SelectMarker>>,
+    content_q: Query<(), With<SelectContentMarker>>,
+    hierarchy: PortalHierarchy,
+) {
+    for event in dismissed.read() {
+        if !content_q.contains(event.layer) {
+            continue;
+        }
+        if let Some(mut state) = hierarchy.parent(event.layer).and_then(|root| root_q.get_mut(root).ok()) {
+            state.open = false;
+        }
+    }
+}
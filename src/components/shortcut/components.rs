@@ -0,0 +1,253 @@
+use std::fmt;
+
+use bevy::prelude::*;
+
+/// Modifier keys that have to be held for an [`Accelerator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Modifiers {
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+    pub super_key: bool,
+}
+
+impl Modifiers {
+    /// Reads the currently held modifier keys (left or right variant).
+    pub fn pressed(keys: &ButtonInput<KeyCode>) -> Self {
+        Self {
+            ctrl: keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]),
+            alt: keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]),
+            shift: keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]),
+            super_key: keys.any_pressed([KeyCode::SuperLeft, KeyCode::SuperRight]),
+        }
+    }
+}
+
+/// A key combination such as `Ctrl+S` or `Alt+F`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Accelerator {
+    pub key: KeyCode,
+    pub modifiers: Modifiers,
+}
+
+impl Accelerator {
+    /// Accelerator without modifiers.
+    pub fn new(key: KeyCode) -> Self {
+        Self {
+            key,
+            modifiers: Modifiers::default(),
+        }
+    }
+
+    /// `Ctrl+<key>`.
+    pub fn ctrl(key: KeyCode) -> Self {
+        Self::new(key).with_ctrl()
+    }
+
+    /// `Alt+<key>`.
+    pub fn alt(key: KeyCode) -> Self {
+        Self::new(key).with_alt()
+    }
+
+    /// Platform primary modifier: `Cmd` on macOS, `Ctrl` everywhere else.
+    pub fn primary(key: KeyCode) -> Self {
+        if cfg!(target_os = "macos") {
+            Self::new(key).with_super()
+        } else {
+            Self::ctrl(key)
+        }
+    }
+
+    pub fn with_ctrl(mut self) -> Self {
+        self.modifiers.ctrl = true;
+        self
+    }
+
+    pub fn with_alt(mut self) -> Self {
+        self.modifiers.alt = true;
+        self
+    }
+
+    pub fn with_shift(mut self) -> Self {
+        self.modifiers.shift = true;
+        self
+    }
+
+    pub fn with_super(mut self) -> Self {
+        self.modifiers.super_key = true;
+        self
+    }
+
+    /// Whether `key` together with the held `modifiers` triggers this accelerator.
+    /// Modifiers have to match exactly, so `Ctrl+Shift+S` does not fire `Ctrl+S`.
+    pub fn matches(&self, key: KeyCode, modifiers: Modifiers) -> bool {
+        self.key == key && self.modifiers == modifiers
+    }
+}
+
+impl fmt::Display for Accelerator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let m = self.modifiers;
+        if m.ctrl {
+            f.write_str("Ctrl+")?;
+        }
+        if m.alt {
+            f.write_str("Alt+")?;
+        }
+        if m.shift {
+            f.write_str("Shift+")?;
+        }
+        if m.super_key {
+            f.write_str(if cfg!(target_os = "macos") {
+                "Cmd+"
+            } else {
+                "Super+"
+            })?;
+        }
+        f.write_str(&key_label(self.key))
+    }
+}
+
+/// Human readable name of a key as shown in shortcut hints.
+///
+/// Keys without a label (media keys, IME keys, ...) are shown as `?`.
+pub fn key_label(key: KeyCode) -> String {
+    let label = match key {
+        KeyCode::KeyA => "A",
+        KeyCode::KeyB => "B",
+        KeyCode::KeyC => "C",
+        KeyCode::KeyD => "D",
+        KeyCode::KeyE => "E",
+        KeyCode::KeyF => "F",
+        KeyCode::KeyG => "G",
+        KeyCode::KeyH => "H",
+        KeyCode::KeyI => "I",
+        KeyCode::KeyJ => "J",
+        KeyCode::KeyK => "K",
+        KeyCode::KeyL => "L",
+        KeyCode::KeyM => "M",
+        KeyCode::KeyN => "N",
+        KeyCode::KeyO => "O",
+        KeyCode::KeyP => "P",
+        KeyCode::KeyQ => "Q",
+        KeyCode::KeyR => "R",
+        KeyCode::KeyS => "S",
+        KeyCode::KeyT => "T",
+        KeyCode::KeyU => "U",
+        KeyCode::KeyV => "V",
+        KeyCode::KeyW => "W",
+        KeyCode::KeyX => "X",
+        KeyCode::KeyY => "Y",
+        KeyCode::KeyZ => "Z",
+        KeyCode::Digit0 => "0",
+        KeyCode::Digit1 => "1",
+        KeyCode::Digit2 => "2",
+        KeyCode::Digit3 => "3",
+        KeyCode::Digit4 => "4",
+        KeyCode::Digit5 => "5",
+        KeyCode::Digit6 => "6",
+        KeyCode::Digit7 => "7",
+        KeyCode::Digit8 => "8",
+        KeyCode::Digit9 => "9",
+        KeyCode::F1 => "F1",
+        KeyCode::F2 => "F2",
+        KeyCode::F3 => "F3",
+        KeyCode::F4 => "F4",
+        KeyCode::F5 => "F5",
+        KeyCode::F6 => "F6",
+        KeyCode::F7 => "F7",
+        KeyCode::F8 => "F8",
+        KeyCode::F9 => "F9",
+        KeyCode::F10 => "F10",
+        KeyCode::F11 => "F11",
+        KeyCode::F12 => "F12",
+        KeyCode::F13 => "F13",
+        KeyCode::F14 => "F14",
+        KeyCode::F15 => "F15",
+        KeyCode::F16 => "F16",
+        KeyCode::F17 => "F17",
+        KeyCode::F18 => "F18",
+        KeyCode::F19 => "F19",
+        KeyCode::F20 => "F20",
+        KeyCode::F21 => "F21",
+        KeyCode::F22 => "F22",
+        KeyCode::F23 => "F23",
+        KeyCode::F24 => "F24",
+        KeyCode::Numpad0 => "Num 0",
+        KeyCode::Numpad1 => "Num 1",
+        KeyCode::Numpad2 => "Num 2",
+        KeyCode::Numpad3 => "Num 3",
+        KeyCode::Numpad4 => "Num 4",
+        KeyCode::Numpad5 => "Num 5",
+        KeyCode::Numpad6 => "Num 6",
+        KeyCode::Numpad7 => "Num 7",
+        KeyCode::Numpad8 => "Num 8",
+        KeyCode::Numpad9 => "Num 9",
+        KeyCode::Escape => "Esc",
+        KeyCode::Enter => "Enter",
+        KeyCode::Space => "Space",
+        KeyCode::Tab => "Tab",
+        KeyCode::Backspace => "Backspace",
+        KeyCode::Delete => "Del",
+        KeyCode::Insert => "Ins",
+        KeyCode::Home => "Home",
+        KeyCode::End => "End",
+        KeyCode::PageUp => "PgUp",
+        KeyCode::PageDown => "PgDn",
+        KeyCode::ArrowUp => "↑",
+        KeyCode::ArrowDown => "↓",
+        KeyCode::ArrowLeft => "←",
+        KeyCode::ArrowRight => "→",
+        KeyCode::Comma => ",",
+        KeyCode::Period => ".",
+        KeyCode::Slash => "/",
+        KeyCode::Backslash => "\\",
+        KeyCode::Semicolon => ";",
+        KeyCode::Quote => "'",
+        KeyCode::Backquote => "`",
+        KeyCode::BracketLeft => "[",
+        KeyCode::BracketRight => "]",
+        KeyCode::Minus => "-",
+        KeyCode::Equal => "=",
+        KeyCode::NumpadAdd => "Num +",
+        KeyCode::NumpadSubtract => "Num -",
+        KeyCode::NumpadMultiply => "Num *",
+        KeyCode::NumpadDivide => "Num /",
+        KeyCode::NumpadDecimal => "Num .",
+        KeyCode::NumpadEnter => "Num Enter",
+        KeyCode::PrintScreen => "PrtSc",
+        KeyCode::Pause => "Pause",
+        KeyCode::CapsLock => "Caps Lock",
+        KeyCode::ContextMenu => "Menu",
+        _ => "?",
+    };
+    label.to_string()
+}
+
+/// Attaches an [`Accelerator`] to a widget.
+///
+/// When the accelerator is pressed while the widget's scope is active, a
+/// [`ShortcutTriggeredEvent`](super::ShortcutTriggeredEvent) is sent and the
+/// widget plugin turns it into its regular activation event.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shortcut(pub Accelerator);
+
+/// Limits the [`Shortcut`]s below this entity to the time the scope is active.
+///
+/// A scope is active while keyboard focus is inside it or inside one of its
+/// nested scopes. A `modal` scope additionally blocks all shortcuts outside of
+/// it while it is visible (e.g. an open dialog).
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct ShortcutScope {
+    pub modal: bool,
+}
+
+impl ShortcutScope {
+    pub fn modal() -> Self {
+        Self { modal: true }
+    }
+}
+
+/// Text node that displays the accelerator of a menu item.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct ShortcutHintMarker;
@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+use super::Accelerator;
+
+/// Sent when the accelerator of a [`Shortcut`](super::Shortcut) was pressed.
+#[derive(Event, Debug, Clone)]
+pub struct ShortcutTriggeredEvent {
+    /// Entity carrying the `Shortcut` component.
+    pub entity: Entity,
+    pub accelerator: Accelerator,
+}
+
+/// Sent once when two or more shortcuts in the same scope share an accelerator.
+#[derive(Event, Debug, Clone)]
+pub struct ShortcutConflictEvent {
+    pub accelerator: Accelerator,
+    /// Scope the conflict happens in, `None` for the global scope.
+    pub scope: Option<Entity>,
+    pub entities: Vec<Entity>,
+}
@@ -0,0 +1,9 @@
+mod components;
+mod events;
+mod plugin;
+mod systems;
+
+pub use components::*;
+pub use events::*;
+pub use plugin::*;
+pub use systems::*;
@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+use super::{events::*, systems::*};
+use crate::plugin::UiState;
+
+/// Plugin for keyboard shortcuts / accelerators.
+pub struct ShortcutPlugin;
+
+impl Plugin for ShortcutPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ShortcutTriggeredEvent>()
+            .add_event::<ShortcutConflictEvent>()
+            .add_systems(
+                Update,
+                (detect_shortcut_conflicts, dispatch_shortcuts)
+                    .chain()
+                    .run_if(in_state(UiState::Ready)),
+            );
+    }
+}
@@ -0,0 +1,254 @@
+use std::collections::{HashMap, HashSet};
+
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::input_focus::InputFocus;
+use bevy::prelude::*;
+
+use super::*;
+use crate::components::disabled::InheritedDisabled;
+use crate::components::one_time_password_field::OtpCell;
+use crate::components::portal::PortalHierarchy;
+use crate::components::text_field::TextFieldState;
+
+/// Text inputs in which plain key presses are typed text.
+pub type TextInputFilter = Or<(With<TextFieldState>, With<OtpCell>)>;
+
+/// Whether the focused entity is a text input.
+pub fn is_typing(focus: Option<&InputFocus>, text_inputs: &Query<(), TextInputFilter>) -> bool {
+    focus
+        .and_then(InputFocus::get)
+        .is_some_and(|focused| text_inputs.contains(focused))
+}
+
+/// Run condition that is `false` while a text input has focus. Handlers
+/// bound to plain keys use it so that typing does not trigger them.
+pub fn not_typing(focus: Option<Res<InputFocus>>, text_inputs: Query<(), TextInputFilter>) -> bool {
+    !is_typing(focus.as_deref(), &text_inputs)
+}
+
+/// The editing command a text input handles itself: Ctrl or Super with A,
+/// C, V, X or Z. Never with Alt, because AltGr (Ctrl+Alt on Windows) and the
+/// macOS Option layer produce characters such as `@`, `€` or `{`.
+pub fn editing_command(key: &Key, modifiers: Modifiers) -> Option<char> {
+    if !(modifiers.ctrl || modifiers.super_key) || modifiers.alt {
+        return None;
+    }
+    let Key::Character(c) = key else {
+        return None;
+    };
+    let mut chars = c.chars().flat_map(char::to_lowercase);
+    match (chars.next(), chars.next()) {
+        (Some(c @ ('a' | 'c' | 'v' | 'x' | 'z')), None) => Some(c),
+        _ => None,
+    }
+}
+
+/// Whether `text` from a [`KeyboardInput`] should be typed into a text input.
+pub fn is_printable(text: &str) -> bool {
+    !text.is_empty() && !text.chars().any(char::is_control)
+}
+
+/// Returns the nearest [`ShortcutScope`] on `entity` or one of its logical
+/// ancestors (portaled content belongs to its owner's scope). `None` means
+/// the shortcut lives in the global scope.
+pub fn find_shortcut_scope(
+    entity: Entity,
+    hierarchy: &PortalHierarchy,
+    scopes: &Query<&ShortcutScope>,
+) -> Option<Entity> {
+    std::iter::once(entity)
+        .chain(hierarchy.ancestors(entity))
+        .find(|e| scopes.contains(*e))
+}
+
+/// Scopes from `start` outwards. Stops after the first modal scope because
+/// nothing outside of it may fire while it is active.
+fn scope_chain(
+    start: Entity,
+    hierarchy: &PortalHierarchy,
+    scopes: &Query<&ShortcutScope>,
+) -> Vec<Entity> {
+    let mut chain = Vec::new();
+    let mut current = Some(start);
+    while let Some(entity) = current {
+        if let Ok(scope) = scopes.get(entity) {
+            chain.push(entity);
+            if scope.modal {
+                break;
+            }
+        }
+        current = hierarchy.parent(entity);
+    }
+    chain
+}
+
+fn is_modifier(key: KeyCode) -> bool {
+    matches!(
+        key,
+        KeyCode::ControlLeft
+            | KeyCode::ControlRight
+            | KeyCode::AltLeft
+            | KeyCode::AltRight
+            | KeyCode::ShiftLeft
+            | KeyCode::ShiftRight
+            | KeyCode::SuperLeft
+            | KeyCode::SuperRight
+    )
+}
+
+/// Reports accelerators that are registered more than once in the same scope.
+///
+/// Every conflict is reported once (as warning and [`ShortcutConflictEvent`])
+/// until it is resolved.
+pub fn detect_shortcut_conflicts(
+    changed: Query<(), Changed<Shortcut>>,
+    mut removed: RemovedComponents<Shortcut>,
+    shortcuts: Query<(Entity, &Shortcut)>,
+    hierarchy: PortalHierarchy,
+    scopes: Query<&ShortcutScope>,
+    mut reported: Local<HashSet<(Option<Entity>, Accelerator)>>,
+    mut writer: EventWriter<ShortcutConflictEvent>,
+) {
+    let removed_any = removed.read().count() > 0;
+    if changed.is_empty() && !removed_any {
+        return;
+    }
+
+    let mut groups: HashMap<(Option<Entity>, Accelerator), Vec<Entity>> = HashMap::new();
+    for (entity, shortcut) in shortcuts.iter() {
+        let scope = find_shortcut_scope(entity, &hierarchy, &scopes);
+        groups.entry((scope, shortcut.0)).or_default().push(entity);
+    }
+
+    reported.retain(|key| groups.get(key).is_some_and(|entities| entities.len() > 1));
+    for ((scope, accelerator), entities) in groups {
+        if entities.len() > 1 && reported.insert((scope, accelerator)) {
+            warn!(
+                "Shortcut {} is registered {} times in scope {:?}: {:?}",
+                accelerator,
+                entities.len(),
+                scope,
+                entities
+            );
+            writer.write(ShortcutConflictEvent {
+                accelerator,
+                scope,
+                entities,
+            });
+        }
+    }
+}
+
+/// Matches pressed keys against all [`Shortcut`]s and sends a
+/// [`ShortcutTriggeredEvent`] for the innermost active match.
+///
+/// Disabled widgets never fire, and disabled or inert scopes (e.g. dialogs
+/// below the topmost modal) are never active. Shortcuts in the global scope always fire,
+/// even if the widget itself is hidden (e.g. an item of a closed menu).
+/// Shortcuts inside a scope only fire while the scope is visible and active.
+/// The active scopes are those around the focused entity. If the focus is not
+/// inside a modal scope but a modal scope is visible, the topmost modal scope
+/// is used instead, so an open dialog always captures shortcuts.
+/// While a text input has focus, shortcuts without Ctrl, Alt or Super are
+/// ignored so that typing does not trigger them. The same goes for the
+/// field's own editing chords (see [`editing_command`]) and for chords that
+/// typed a character, e.g. AltGr+E (Ctrl+Alt+E on Windows) for `€`.
+pub fn dispatch_shortcuts(
+    keys: Res<ButtonInput<KeyCode>>,
+    focus: Option<Res<InputFocus>>,
+    shortcuts: Query<(Entity, &Shortcut), Without<InheritedDisabled>>,
+    scope_nodes: Query<
+        (
+            Entity,
+            &ShortcutScope,
+            &InheritedVisibility,
+            Option<&ComputedNode>,
+        ),
+        Without<InheritedDisabled>,
+    >,
+    hierarchy: PortalHierarchy,
+    scopes: Query<&ShortcutScope>,
+    text_inputs: Query<(), TextInputFilter>,
+    mut key_events: EventReader<KeyboardInput>,
+    mut writer: EventWriter<ShortcutTriggeredEvent>,
+) {
+    let modifiers = Modifiers::pressed(&keys);
+    // Beim Tippen in ein Textfeld feuern nur Shortcuts mit Ctrl/Alt/Super
+    let typing = is_typing(focus.as_deref(), &text_inputs);
+    if typing && !(modifiers.ctrl || modifiers.alt || modifiers.super_key) {
+        key_events.clear();
+        return;
+    }
+    // Tasten, die im Textfeld bearbeiten oder ein Zeichen tippen, gehören dem Feld
+    let consumed: Vec<KeyCode> = key_events
+        .read()
+        .filter(|event| typing && event.state == ButtonState::Pressed)
+        .filter(|event| {
+            editing_command(&event.logical_key, modifiers).is_some()
+                || (!modifiers.super_key && event.text.as_deref().is_some_and(is_printable))
+        })
+        .map(|event| event.key_code)
+        .collect();
+    let mut active: Option<(Vec<Entity>, bool)> = None;
+
+    for &key in keys.get_just_pressed() {
+        if is_modifier(key) || consumed.contains(&key) {
+            continue;
+        }
+        let candidates: Vec<(Entity, Accelerator)> = shortcuts
+            .iter()
+            .filter(|(_, shortcut)| shortcut.0.matches(key, modifiers))
+            .map(|(entity, shortcut)| (entity, shortcut.0))
+            .collect();
+        if candidates.is_empty() {
+            continue;
+        }
+
+        let (chain, modal) = active.get_or_insert_with(|| {
+            let is_modal = |e: &Entity| scopes.get(*e).is_ok_and(|s| s.modal);
+            let mut chain = focus
+                .as_ref()
+                .and_then(|f| f.get())
+                .map(|focused| scope_chain(focused, &hierarchy, &scopes))
+                .unwrap_or_default();
+            if !chain.last().is_some_and(is_modal) {
+                let topmost_modal = scope_nodes
+                    .iter()
+                    .filter(|(_, scope, visible, _)| scope.modal && visible.get())
+                    .max_by_key(|(_, _, _, node)| node.map(|n| n.stack_index()).unwrap_or(0))
+                    .map(|(entity, ..)| entity);
+                if let Some(modal_scope) = topmost_modal {
+                    chain = scope_chain(modal_scope, &hierarchy, &scopes);
+                }
+            }
+            let modal = chain.last().is_some_and(is_modal);
+            (chain, modal)
+        });
+
+        let best = candidates
+            .into_iter()
+            .filter_map(|(entity, accelerator)| {
+                let rank = match find_shortcut_scope(entity, &hierarchy, &scopes) {
+                    Some(scope) => {
+                        let (_, _, visible, _) = scope_nodes.get(scope).ok()?;
+                        if !visible.get() {
+                            return None;
+                        }
+                        chain.iter().position(|c| *c == scope)?
+                    }
+                    None if *modal => return None,
+                    None => chain.len(),
+                };
+                Some((rank, entity, accelerator))
+            })
+            .min_by_key(|(rank, ..)| *rank);
+
+        if let Some((_, entity, accelerator)) = best {
+            writer.write(ShortcutTriggeredEvent {
+                entity,
+                accelerator,
+            });
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use bevy::{ecs::system::EntityCommands, prelude::*, ui::FocusPolicy};
 
 use super::*;
+use crate::components::disabled::Disabled;
+use crate::components::transition::UiTransition;
 use crate::theme::UiTheme;
 
 pub struct SwitchBuilder {
@@ -143,30 +145,16 @@ impl SwitchBuilder {
                 // BorderRadius::all(Val::Px(thumb_radius)),
                 BackgroundColor(theme.color.white.step12.into()),
                 FocusPolicy::Pass,
+                UiTransition::default().slide(Vec2::ZERO),
             ));
 
-            // Overlay für disabled
-            p.spawn((
-                SwitchOverlayMarker,
-                Node {
-                    position_type: PositionType::Absolute,
-                    left: Val::Px(0.),
-                    top: Val::Px(0.),
-                    width: Val::Percent(100.),
-                    height: Val::Percent(100.),
-                    ..default()
-                },
-                BorderRadius::all(Val::Px(track_radius)),
-                BackgroundColor(theme.color.black.step08.into()),
-                if self.disabled {
-                    Visibility::Visible
-                } else {
-                    Visibility::Hidden
-                },
-                FocusPolicy::Block,
-            ));
         });
 
+        // Overlay für disabled kommt aus der gemeinsamen `Disabled`-Komponente
+        if self.disabled {
+            cmd.insert(Disabled);
+        }
+
         for m in self.markers {
             m(&mut cmd);
         }
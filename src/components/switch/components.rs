@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 
+use crate::components::disabled::DisabledFlag;
+
 #[derive(Component, Clone, Copy)]
 pub struct SwitchTrackColor(pub Option<Color>);
 
@@ -11,13 +13,19 @@ pub struct SwitchMarker;
 /// Marker für den Daumen
 pub struct SwitchThumbMarker;
 
-#[derive(Component, Default, Debug, Clone, Copy)]
-/// Marker für die Overlay-Node bei disabled
-pub struct SwitchOverlayMarker;
-
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
 /// Zustand des Switches
 pub struct SwitchState {
     pub checked: bool,
     pub disabled: bool,
 }
+
+impl DisabledFlag for SwitchState {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+}
@@ -1,3 +1,5 @@
+use crate::components::direction_provider::{ReadingDirection, ResolvedDirection};
+use crate::components::transition::UiTransition;
 use crate::theme::UiTheme;
 use bevy::prelude::*;
 
@@ -10,16 +12,24 @@ pub fn update_toggle_switch_visuals(
         Query<
             (
                 Entity,
-                &SwitchState,
+                Ref<SwitchState>,
                 &Children,
                 &mut Node,
                 &mut BackgroundColor,
                 Option<&SwitchTrackColor>,
+                Option<&ComputedNode>,
+                Option<&ResolvedDirection>,
             ),
             Changed<SwitchState>,
         >,
-        Query<&mut BackgroundColor, With<SwitchThumbMarker>>,
-        Query<&mut Visibility, With<SwitchOverlayMarker>>,
+        Query<
+            (
+                &mut BackgroundColor,
+                Option<&ComputedNode>,
+                Option<&mut UiTransition>,
+            ),
+            With<SwitchThumbMarker>,
+        >,
     )>,
 ) {
     let theme = if let Some(t) = theme_opt {
@@ -29,10 +39,12 @@ pub fn update_toggle_switch_visuals(
     };
 
     // 1️⃣ Phase: Track-Query ausführen und Child-Entitäten sammeln
-    let mut thumb_entities: Vec<Entity> = Vec::new();
-    let mut overlay_entities: Vec<(Entity, bool)> = Vec::new();
+    // (Thumb, Weg des Daumens in px inkl. Richtung, falls animiert werden soll)
+    let mut thumb_entities: Vec<(Entity, Option<f32>)> = Vec::new();
 
-    for (_track_e, state, children, mut node, mut bg, track_color) in params.p0().iter_mut() {
+    for (_track_e, state, children, mut node, mut bg, track_color, computed, dir) in
+        params.p0().iter_mut()
+    {
         // Track-Farbe & Position updaten
         let color = if state.checked {
             track_color
@@ -48,28 +60,39 @@ pub fn update_toggle_switch_visuals(
             JustifyContent::FlexStart
         };
 
-        // Für jeden Child merken, ob Overlay sichtbar sein muss und Daumen aktualisiert werden muss
+        // Beim Spawnen nicht animieren, sonst vom alten Ende zum neuen gleiten
+        let slide = (!state.is_added()).then(|| {
+            let from_start = if state.checked { -1.0 } else { 1.0 };
+            let rtl = matches!(dir, Some(ResolvedDirection(ReadingDirection::Rtl)));
+            let inner_width = computed
+                .map(|c| {
+                    let inset = c.content_inset();
+                    (c.size().x - inset.left - inset.right) * c.inverse_scale_factor()
+                })
+                .unwrap_or(0.0);
+            from_start * if rtl { -1.0 } else { 1.0 } * inner_width
+        });
+
+        // Für jeden Child merken, ob der Daumen aktualisiert werden muss
         for child in children.iter() {
-            overlay_entities.push((child, state.disabled));
-            thumb_entities.push(child);
+            thumb_entities.push((child, slide));
         }
     } // <-- hier endet der p0()-Borrow
 
-    // 2️⃣ Phase: Overlay-Visibility setzen
-    for (child, disabled) in overlay_entities {
-        if let Ok(mut vis) = params.p2().get_mut(child) {
-            *vis = if disabled {
-                Visibility::Visible
-            } else {
-                Visibility::Hidden
-            };
-        }
-    }
-
-    // 3️⃣ Phase: Daumen-Farbe setzen
-    for child in thumb_entities {
-        if let Ok(mut thumb_bg) = params.p1().get_mut(child) {
+    // 2️⃣ Phase: Daumen-Farbe setzen
+    for (child, slide) in thumb_entities {
+        if let Ok((mut thumb_bg, computed, transition)) = params.p1().get_mut(child) {
             *thumb_bg = theme.color.white.step12.into();
+
+            // 3️⃣ Phase: Daumen vom alten Ende zur neuen Position gleiten lassen
+            if let (Some(travel), Some(mut transition)) = (slide, transition) {
+                let thumb_width = computed
+                    .map(|c| c.size().x * c.inverse_scale_factor())
+                    .unwrap_or(0.0);
+                let travel = travel.signum() * (travel.abs() - thumb_width).max(0.0);
+                transition.set_slide_offset(Vec2::new(travel, 0.0));
+                transition.replay();
+            }
         }
     }
 }
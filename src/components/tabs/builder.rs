@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 
 use crate::components::helper::UiBuilder;
+use crate::components::roving_focus::{RovingFocusGroup, RovingFocusItem, RovingFocusOrientation};
 use crate::theme::UiTheme;
 
 use super::components::{
@@ -36,6 +37,7 @@ impl TabDefinition {
 pub struct TabsBuilder {
     orientation: TabsOrientation,
     default_value: Option<String>,
+    loop_navigation: bool,
     tabs: Vec<TabDefinition>,
 }
 
@@ -44,6 +46,7 @@ impl TabsBuilder {
         Self {
             orientation: TabsOrientation::Horizontal,
             default_value: None,
+            loop_navigation: true,
             tabs: Vec::new(),
         }
     }
@@ -58,6 +61,12 @@ impl TabsBuilder {
         self
     }
 
+    /// Whether arrow-key navigation between triggers wraps around (default: `true`).
+    pub fn loop_navigation(mut self, flag: bool) -> Self {
+        self.loop_navigation = flag;
+        self
+    }
+
     pub fn add_tab(mut self, tab: TabDefinition) -> Self {
         self.tabs.push(tab);
         self
@@ -103,11 +112,19 @@ impl<'w, 's> UiBuilder<'w, 's> for TabsBuilder {
                     },
                     ..default()
                 },
+                RovingFocusGroup::new(
+                    match self.orientation {
+                        TabsOrientation::Horizontal => RovingFocusOrientation::Horizontal,
+                        TabsOrientation::Vertical => RovingFocusOrientation::Vertical,
+                    },
+                    self.loop_navigation,
+                ),
             ))
             .with_children(|list| {
                 for tab in &self.tabs {
                     list.spawn((
                         TabsTriggerMarker,
+                        RovingFocusItem,
                         Button,
                         TabTrigger {
                             value: tab.value.clone(),
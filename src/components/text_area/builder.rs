@@ -0,0 +1,143 @@
+use bevy::input_focus::tab_navigation::TabIndex;
+use bevy::prelude::*;
+use bevy::text::LineBreak;
+use bevy::ui::FocusPolicy;
+
+use super::{TextAreaMarker, TextAreaScrollState};
+use crate::components::disabled::Disabled;
+use crate::components::helper::UiBuilder;
+use crate::components::scroll_area::ScrollAreaBuilder;
+use crate::components::text_field::{spawn_text_field_viewport, TextFieldState, TextFieldStyle};
+use crate::theme::UiTheme;
+
+/// Builder for a multi-line text input with word wrap.
+///
+/// The text is laid out inside a [`ScrollAreaBuilder`]; the area scrolls to
+/// keep the caret visible. With [`auto_grow`](Self::auto_grow) the area grows
+/// with its content until the maximum height is reached.
+pub struct TextAreaBuilder {
+    value: String,
+    placeholder: String,
+    max_length: Option<usize>,
+    width: Option<Val>,
+    rows: usize,
+    max_height: Option<f32>,
+    disabled: bool,
+}
+
+impl TextAreaBuilder {
+    pub fn new() -> Self {
+        Self {
+            value: String::new(),
+            placeholder: String::new(),
+            max_length: None,
+            width: None,
+            rows: 4,
+            max_height: None,
+            disabled: false,
+        }
+    }
+
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    pub fn width(mut self, width: Val) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Visible lines (fixed height, or minimum height with `auto_grow`).
+    pub fn rows(mut self, rows: usize) -> Self {
+        self.rows = rows.max(1);
+        self
+    }
+
+    /// Grow with the content up to `max_height` px, then scroll.
+    pub fn auto_grow(mut self, max_height: f32) -> Self {
+        self.max_height = Some(max_height);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl Default for TextAreaBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'w, 's> UiBuilder<'w, 's> for TextAreaBuilder {
+    type Output = Entity;
+
+    fn spawn(
+        self,
+        parent: &'s mut ChildSpawnerCommands<'w>,
+        theme: &UiTheme,
+        font: &Handle<Font>,
+    ) -> Self::Output {
+        let mut style = TextFieldStyle::new(theme);
+        style.node.flex_direction = FlexDirection::Column;
+        style.node.align_items = AlignItems::Stretch;
+        style.node.width = self.width.unwrap_or(Val::Px(320.0));
+
+        let mut state = TextFieldState::new(self.value);
+        state.placeholder = self.placeholder;
+        state.max_length = self.max_length;
+        state.multiline = true;
+
+        let rows_height = self.rows as f32 * theme.font.size.base * 1.2;
+        let mut scroll_area = ScrollAreaBuilder::new().width(Val::Percent(100.0));
+        scroll_area = match self.max_height {
+            Some(max) => {
+                // Mindesthöhe über den Root, der Bereich selbst wächst mit dem Text
+                style.node.min_height =
+                    Val::Px(rows_height + 2.0 * (theme.layout.padding.xs + 1.0));
+                scroll_area
+                    .height(Val::Auto)
+                    .max_height(Val::Px(max.max(rows_height)))
+            }
+            None => scroll_area.height(Val::Px(rows_height)),
+        };
+
+        let mut cmd = parent.spawn((
+            TextAreaMarker,
+            style,
+            state,
+            TextAreaScrollState::default(),
+            Interaction::default(),
+            FocusPolicy::Block,
+            TabIndex(0),
+            Name::new("TextArea"),
+        ));
+        cmd.with_children(|root| {
+            let area = scroll_area
+                .content(|vc, theme, font| {
+                    spawn_text_field_viewport(vc, theme, font, LineBreak::WordBoundary);
+                })
+                .spawn(root, theme, font);
+            root.commands()
+                .entity(area)
+                .insert(BackgroundColor(Color::NONE));
+        });
+        if self.disabled {
+            cmd.insert(Disabled);
+        }
+        cmd.id()
+    }
+}
@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+
+/// Marker for the root node of a text area.
+///
+/// The root also carries a [`TextFieldState`](crate::components::text_field::TextFieldState)
+/// with `multiline` set, so editing, clipboard and the
+/// [`TextChangedEvent`](crate::components::text_field::TextChangedEvent) work
+/// exactly like in a single-line text field.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct TextAreaMarker;
+
+/// Caret position the scroll area was last scrolled to.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct TextAreaScrollState {
+    pub(crate) caret: usize,
+    pub(crate) top: f32,
+}
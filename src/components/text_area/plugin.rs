@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+
+use super::systems::*;
+use crate::components::text_field::{handle_text_field_keyboard, update_text_field_visuals};
+use crate::plugin::UiState;
+
+/// Plugin for [`TextAreaBuilder`](super::TextAreaBuilder).
+///
+/// Requires the `TextFieldPlugin`, which handles typing and rendering.
+pub struct TextAreaPlugin;
+
+impl Plugin for TextAreaPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                handle_text_area_navigation
+                    .after(handle_text_field_keyboard)
+                    .before(update_text_field_visuals),
+                scroll_text_area_to_caret.after(update_text_field_visuals),
+            )
+                .run_if(in_state(UiState::Ready)),
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::input_focus::InputFocus;
+use bevy::prelude::*;
+use bevy::text::ComputedTextBlock;
+
+use super::{TextAreaMarker, TextAreaScrollState};
+use crate::components::disabled::InheritedDisabled;
+use crate::components::scroll_area::ScrollAreaMarker;
+use crate::components::shortcut::Modifiers;
+use crate::components::text_field::{
+    caret_stop, find_part, index_on_line, text_stops, CaretStop, TextFieldState,
+    TextFieldTextMarker,
+};
+
+/// Up/Down move the caret by visual line, Home/End jump to the start or end
+/// of the visual line (with Ctrl: of the whole text).
+pub fn handle_text_area_navigation(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    focus: Res<InputFocus>,
+    mut key_events: EventReader<KeyboardInput>,
+    mut areas: Query<&mut TextFieldState, (With<TextAreaMarker>, Without<InheritedDisabled>)>,
+    children: Query<&Children>,
+    texts: Query<(&Text, Option<&ComputedTextBlock>, &ComputedNode), With<TextFieldTextMarker>>,
+) {
+    let Some(entity) = focus.get() else {
+        key_events.clear();
+        return;
+    };
+    let Ok(mut state) = areas.get_mut(entity) else {
+        key_events.clear();
+        return;
+    };
+    let Some((text, block, node)) =
+        find_part(entity, &children, &texts).and_then(|e| texts.get(e).ok())
+    else {
+        key_events.clear();
+        return;
+    };
+
+    let modifiers = Modifiers::pressed(&keys);
+    let extend = modifiers.shift;
+    let stops = text_stops(&state, text, block, node);
+    let mut moved = false;
+
+    for event in key_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        let current = caret_stop(&stops, state.caret);
+        match &event.logical_key {
+            Key::ArrowUp | Key::ArrowDown => {
+                let down = event.logical_key == Key::ArrowDown;
+                let Some(current) = current else {
+                    continue;
+                };
+                let goal_x = state.goal_x.unwrap_or(current.x);
+                let target = if down {
+                    index_on_line(&stops, current.line + 1, goal_x).unwrap_or(state.value.len())
+                } else {
+                    current
+                        .line
+                        .checked_sub(1)
+                        .and_then(|line| index_on_line(&stops, line, goal_x))
+                        .unwrap_or(0)
+                };
+                state.move_to(target, extend);
+                state.goal_x = Some(goal_x);
+                state.blink_reset = time.elapsed_secs();
+            }
+            Key::Home | Key::End => {
+                let end = event.logical_key == Key::End;
+                let target = if modifiers.ctrl || current.is_none() {
+                    if end {
+                        state.value.len()
+                    } else {
+                        0
+                    }
+                } else {
+                    let line = current.map(|c| c.line).unwrap_or(0);
+                    let on_line = stops.iter().filter(|s| s.line == line).map(|s| s.index);
+                    let edge = if end { on_line.max() } else { on_line.min() };
+                    edge.unwrap_or(state.caret)
+                };
+                state.move_to(target, extend);
+                moved = true;
+            }
+            _ => {}
+        }
+    }
+
+    if moved {
+        state.goal_x = None;
+        state.blink_reset = time.elapsed_secs();
+    }
+}
+
+/// Scrolls the surrounding scroll area so that the caret stays visible
+/// whenever it moves.
+pub fn scroll_text_area_to_caret(
+    mut areas: Query<(Entity, &TextFieldState, &mut TextAreaScrollState), With<TextAreaMarker>>,
+    children: Query<&Children>,
+    texts: Query<(&Text, Option<&ComputedTextBlock>, &ComputedNode), With<TextFieldTextMarker>>,
+    mut scroll_areas: Query<(&ComputedNode, &mut ScrollPosition), With<ScrollAreaMarker>>,
+) {
+    for (entity, state, mut scroll_state) in areas.iter_mut() {
+        let Some((text, block, node)) =
+            find_part(entity, &children, &texts).and_then(|e| texts.get(e).ok())
+        else {
+            continue;
+        };
+        let stops = text_stops(state, text, block, node);
+        let caret = caret_stop(&stops, state.caret).unwrap_or(CaretStop {
+            index: 0,
+            x: 0.0,
+            top: 0.0,
+            height: 0.0,
+            line: 0,
+        });
+
+        // Nur nach Bewegung oder neuem Layout scrollen, sonst bleibt das Mausrad wirkungslos
+        if scroll_state.caret == state.caret && scroll_state.top == caret.top {
+            continue;
+        }
+        scroll_state.caret = state.caret;
+        scroll_state.top = caret.top;
+
+        let Some((area_node, mut position)) =
+            find_part(entity, &children, &scroll_areas).and_then(|e| scroll_areas.get_mut(e).ok())
+        else {
+            continue;
+        };
+        let visible = area_node.size().y * area_node.inverse_scale_factor();
+        let mut offset = position.offset_y;
+        if caret.top < offset {
+            offset = caret.top;
+        } else if caret.top + caret.height > offset + visible {
+            offset = caret.top + caret.height - visible;
+        }
+        if offset != position.offset_y {
+            position.offset_y = offset;
+        }
+    }
+}
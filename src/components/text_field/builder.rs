@@ -0,0 +1,159 @@
+use bevy::input_focus::tab_navigation::TabIndex;
+use bevy::prelude::*;
+use bevy::text::LineBreak;
+use bevy::ui::{FocusPolicy, RelativeCursorPosition};
+
+use super::*;
+use crate::components::disabled::Disabled;
+use crate::components::helper::UiBuilder;
+use crate::theme::UiTheme;
+
+/// Builder for a single-line text input.
+///
+/// The field is focusable, places the caret on click, supports selection with
+/// mouse and keyboard and copy/cut/paste via the
+/// [`Clipboard`](crate::components::clipboard::Clipboard). Changes are
+/// reported through [`TextChangedEvent`], Enter sends [`TextSubmittedEvent`].
+/// IME input (Japanese, Chinese, ...) is composed inline with an underline
+/// while the candidate window follows the caret.
+pub struct TextFieldBuilder {
+    value: String,
+    placeholder: String,
+    max_length: Option<usize>,
+    width: Option<Val>,
+    disabled: bool,
+}
+
+impl TextFieldBuilder {
+    pub fn new() -> Self {
+        Self {
+            value: String::new(),
+            placeholder: String::new(),
+            max_length: None,
+            width: None,
+            disabled: false,
+        }
+    }
+
+    /// Initial value.
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    /// Text shown while the field is empty.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Maximum number of characters.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    pub fn width(mut self, width: Val) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl Default for TextFieldBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'w, 's> UiBuilder<'w, 's> for TextFieldBuilder {
+    type Output = Entity;
+
+    fn spawn(
+        self,
+        parent: &'s mut ChildSpawnerCommands<'w>,
+        theme: &UiTheme,
+        font: &Handle<Font>,
+    ) -> Self::Output {
+        let mut style = TextFieldStyle::new(theme);
+        if let Some(width) = self.width {
+            style.node.width = width;
+        }
+        let mut state = TextFieldState::new(self.value);
+        state.placeholder = self.placeholder;
+        state.max_length = self.max_length;
+
+        let mut cmd = parent.spawn((
+            TextFieldMarker,
+            style,
+            state,
+            Interaction::default(),
+            FocusPolicy::Block,
+            TabIndex(0),
+            Name::new("TextField"),
+        ));
+        cmd.with_children(|root| {
+            spawn_text_field_viewport(root, theme, font, LineBreak::NoWrap);
+        });
+        if self.disabled {
+            cmd.insert(Disabled);
+        }
+        cmd.id()
+    }
+}
+
+/// Spawns viewport, selection, text and caret below a text input root.
+///
+/// Shared by all widgets built on [`TextFieldState`].
+pub fn spawn_text_field_viewport(
+    parent: &mut ChildSpawnerCommands,
+    theme: &UiTheme,
+    font: &Handle<Font>,
+    linebreak: LineBreak,
+) -> Entity {
+    let line_height = theme.font.size.base * 1.2;
+    // Umbrechender Text nimmt die volle Breite ein, einzeiliger wächst darüber hinaus
+    let wrap = linebreak != LineBreak::NoWrap;
+    let mut viewport = text_field_viewport_node(line_height);
+    if wrap {
+        viewport.width = Val::Percent(100.0);
+    }
+    parent
+        .spawn((
+            TextFieldViewportMarker,
+            viewport,
+            RelativeCursorPosition::default(),
+            FocusPolicy::Pass,
+        ))
+        .with_children(|vp| {
+            vp.spawn((TextFieldSelectionMarker, text_field_selection()));
+            vp.spawn((
+                TextFieldTextMarker,
+                Text::new(""),
+                TextFont {
+                    font: font.clone(),
+                    font_size: theme.font.size.base,
+                    ..default()
+                },
+                TextColor(theme.color.gray.step12),
+                TextLayout::new(JustifyText::Left, linebreak),
+                Node {
+                    position_type: PositionType::Relative,
+                    width: if wrap { Val::Percent(100.0) } else { Val::Auto },
+                    flex_shrink: if wrap { 1.0 } else { 0.0 },
+                    ..default()
+                },
+            ));
+            vp.spawn((TextFieldPreeditMarker, text_field_preedit()));
+            vp.spawn((
+                TextFieldCaretMarker,
+                text_field_caret(theme, line_height),
+                Visibility::Hidden,
+            ));
+        })
+        .id()
+}
@@ -0,0 +1,368 @@
+use bevy::prelude::*;
+
+use crate::components::direction_provider::RuntimePosition;
+
+/// Marker for the root node of a text field.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct TextFieldMarker;
+
+/// Clipping node that contains text, caret and selection.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct TextFieldViewportMarker;
+
+/// Text node showing the value (or the placeholder).
+#[derive(Component, Debug, Clone, Copy, Default)]
+#[require(RuntimePosition)]
+pub struct TextFieldTextMarker;
+
+/// Blinking caret.
+#[derive(Component, Debug, Clone, Copy, Default)]
+#[require(RuntimePosition)]
+pub struct TextFieldCaretMarker;
+
+/// Highlight behind the selected text.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct TextFieldSelectionMarker;
+
+/// Container for the underline below IME composition text.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct TextFieldPreeditMarker;
+
+/// Marks a text input whose value failed validation; the border switches
+/// to the error color.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct TextFieldInvalid;
+
+/// Value and editing state of a text field.
+///
+/// `caret` and `anchor` are byte indices into `value` and always lie on a
+/// char boundary. The selection spans from `anchor` to `caret`; both are
+/// equal when nothing is selected.
+#[derive(Component, Clone, Default)]
+pub struct TextFieldState {
+    pub value: String,
+    pub caret: usize,
+    pub anchor: usize,
+    pub placeholder: String,
+    /// Maximum number of characters, `None` for unlimited.
+    pub max_length: Option<usize>,
+    /// Accepts line breaks (used by the text area).
+    pub multiline: bool,
+    /// Displays every character as this one (e.g. `•` for passwords).
+    pub mask: Option<char>,
+    /// Sensitive content: never copied to the clipboard, left out of
+    /// `Debug` output and sent as empty value in the text events.
+    pub secret: bool,
+    pub(crate) dragging: bool,
+    pub(crate) scroll: Vec2,
+    pub(crate) blink_reset: f32,
+    /// Preferred x position for vertical caret movement.
+    pub(crate) goal_x: Option<f32>,
+    /// Uncommitted IME composition, shown inline at the caret.
+    pub(crate) preedit: String,
+    /// Cursor inside `preedit` as reported by the IME, `None` hides it.
+    pub(crate) preedit_cursor: Option<(usize, usize)>,
+}
+
+impl std::fmt::Debug for TextFieldState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value: &dyn std::fmt::Debug = if self.secret {
+            &"<redacted>"
+        } else {
+            &self.value
+        };
+        f.debug_struct("TextFieldState")
+            .field("value", value)
+            .field("caret", &self.caret)
+            .field("anchor", &self.anchor)
+            .field("placeholder", &self.placeholder)
+            .field("max_length", &self.max_length)
+            .field("multiline", &self.multiline)
+            .field("mask", &self.mask)
+            .field("secret", &self.secret)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TextFieldState {
+    pub fn new(value: impl Into<String>) -> Self {
+        let value = value.into();
+        let end = value.len();
+        Self {
+            value,
+            caret: end,
+            anchor: end,
+            ..default()
+        }
+    }
+
+    /// Replaces the value and moves the caret to its end.
+    pub fn set_value(&mut self, value: impl Into<String>) {
+        self.value = value.into();
+        self.caret = self.value.len();
+        self.anchor = self.caret;
+        self.clear_preedit();
+    }
+
+    /// Text the IME is currently composing, empty outside a composition.
+    pub fn preedit(&self) -> &str {
+        &self.preedit
+    }
+
+    pub fn is_composing(&self) -> bool {
+        !self.preedit.is_empty()
+    }
+
+    /// Masked and secret fields take raw key input only, like password
+    /// fields in native toolkits.
+    pub fn accepts_ime(&self) -> bool {
+        self.mask.is_none() && !self.secret
+    }
+
+    /// Replaces the composition text. Starting a composition removes the
+    /// selection, which the committed text replaces anyway; returns `true`
+    /// if that changed the value.
+    pub fn set_preedit(&mut self, text: &str, cursor: Option<(usize, usize)>) -> bool {
+        let removed = !text.is_empty() && !self.is_composing() && self.delete_selection();
+        self.preedit = text.to_string();
+        self.preedit_cursor = cursor;
+        removed
+    }
+
+    pub fn clear_preedit(&mut self) {
+        self.preedit.clear();
+        self.preedit_cursor = None;
+    }
+
+    /// Position of the caret in [`display_text`](Self::display_text); moves
+    /// with the IME cursor while composing.
+    pub fn display_caret(&self) -> usize {
+        if !self.is_composing() {
+            return self.caret;
+        }
+        let offset = self
+            .preedit_cursor
+            .map(|(_, end)| end.min(self.preedit.len()))
+            .unwrap_or(self.preedit.len());
+        self.caret + offset
+    }
+
+    pub fn has_selection(&self) -> bool {
+        self.caret != self.anchor
+    }
+
+    /// Selected byte range, ordered.
+    pub fn selection(&self) -> std::ops::Range<usize> {
+        self.caret.min(self.anchor)..self.caret.max(self.anchor)
+    }
+
+    pub fn selected_text(&self) -> &str {
+        &self.value[self.selection()]
+    }
+
+    /// Text as it is rendered, with `mask` applied and the IME composition
+    /// inserted at the caret.
+    pub fn display_text(&self) -> std::borrow::Cow<'_, str> {
+        match self.mask {
+            Some(mask) => self.value.chars().map(|_| mask).collect::<String>().into(),
+            None if self.is_composing() => {
+                let (before, after) = self.value.split_at(self.caret);
+                format!("{before}{}{after}", self.preedit).into()
+            }
+            None => self.value.as_str().into(),
+        }
+    }
+
+    /// Converts a byte index of [`display_text`](Self::display_text) into a
+    /// byte index of `value`. Indices inside the composition map to the
+    /// caret.
+    pub fn value_index(&self, display_index: usize) -> usize {
+        match self.mask {
+            Some(mask) => self
+                .value
+                .char_indices()
+                .nth(display_index / mask.len_utf8())
+                .map(|(i, _)| i)
+                .unwrap_or(self.value.len()),
+            None if self.is_composing() && display_index > self.caret => display_index
+                .saturating_sub(self.preedit.len())
+                .max(self.caret),
+            None => display_index,
+        }
+    }
+
+    pub fn select_all(&mut self) {
+        self.anchor = 0;
+        self.caret = self.value.len();
+    }
+
+    /// Inserts `text` at the caret, replacing the selection.
+    ///
+    /// Respects `max_length` and strips line breaks from single-line fields.
+    /// Returns `true` if the value changed.
+    pub fn insert(&mut self, text: &str) -> bool {
+        let filtered: String = text
+            .chars()
+            .filter(|c| (self.multiline && *c == '\n') || !c.is_control())
+            .collect();
+        if filtered.is_empty() {
+            return false;
+        }
+        let removed = self.delete_selection();
+        let mut text = filtered.as_str();
+        if let Some(max) = self.max_length {
+            let room = max.saturating_sub(self.value.chars().count());
+            if let Some((cut, _)) = text.char_indices().nth(room) {
+                text = &text[..cut];
+            }
+        }
+        if text.is_empty() {
+            return removed;
+        }
+        self.value.insert_str(self.caret, text);
+        self.caret += text.len();
+        self.anchor = self.caret;
+        true
+    }
+
+    /// Removes the selected text. Returns `true` if something was removed.
+    pub fn delete_selection(&mut self) -> bool {
+        if !self.has_selection() {
+            return false;
+        }
+        let range = self.selection();
+        self.value.replace_range(range.clone(), "");
+        self.caret = range.start;
+        self.anchor = range.start;
+        true
+    }
+
+    /// Deletes the selection or the char (word with `word`) before the caret.
+    pub fn backspace(&mut self, word: bool) -> bool {
+        if self.delete_selection() {
+            return true;
+        }
+        let start = if word {
+            self.word_start(self.caret)
+        } else {
+            self.prev_boundary(self.caret)
+        };
+        if start == self.caret {
+            return false;
+        }
+        self.value.replace_range(start..self.caret, "");
+        self.caret = start;
+        self.anchor = start;
+        true
+    }
+
+    /// Deletes the selection or the char (word with `word`) after the caret.
+    pub fn delete_forward(&mut self, word: bool) -> bool {
+        if self.delete_selection() {
+            return true;
+        }
+        let end = if word {
+            self.word_end(self.caret)
+        } else {
+            self.next_boundary(self.caret)
+        };
+        if end == self.caret {
+            return false;
+        }
+        self.value.replace_range(self.caret..end, "");
+        true
+    }
+
+    /// Moves the caret to `index`, extending the selection with `extend`.
+    pub fn move_to(&mut self, index: usize, extend: bool) {
+        self.caret = index.min(self.value.len());
+        if !extend {
+            self.anchor = self.caret;
+        }
+    }
+
+    pub fn move_left(&mut self, word: bool, extend: bool) {
+        if self.has_selection() && !extend {
+            let start = self.selection().start;
+            self.move_to(start, false);
+            return;
+        }
+        let target = if word {
+            self.word_start(self.caret)
+        } else {
+            self.prev_boundary(self.caret)
+        };
+        self.move_to(target, extend);
+    }
+
+    pub fn move_right(&mut self, word: bool, extend: bool) {
+        if self.has_selection() && !extend {
+            let end = self.selection().end;
+            self.move_to(end, false);
+            return;
+        }
+        let target = if word {
+            self.word_end(self.caret)
+        } else {
+            self.next_boundary(self.caret)
+        };
+        self.move_to(target, extend);
+    }
+
+    pub fn prev_boundary(&self, index: usize) -> usize {
+        self.value[..index]
+            .char_indices()
+            .next_back()
+            .map(|(i, _)| i)
+            .unwrap_or(0)
+    }
+
+    pub fn next_boundary(&self, index: usize) -> usize {
+        self.value[index..]
+            .chars()
+            .next()
+            .map(|c| index + c.len_utf8())
+            .unwrap_or(index)
+    }
+
+    /// Start of the word before `index` (skipping whitespace first).
+    ///
+    /// Masked text has no visible words, so this jumps to the start.
+    pub fn word_start(&self, index: usize) -> usize {
+        if self.mask.is_some() {
+            return 0;
+        }
+        let mut chars = self.value[..index].char_indices().rev().peekable();
+        while chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+        let mut start = chars.peek().map(|(i, _)| *i + 1).unwrap_or(0);
+        for (i, c) in chars {
+            if c.is_whitespace() {
+                break;
+            }
+            start = i;
+        }
+        start.min(index)
+    }
+
+    /// End of the word after `index` (skipping whitespace first).
+    ///
+    /// Masked text has no visible words, so this jumps to the end.
+    pub fn word_end(&self, index: usize) -> usize {
+        if self.mask.is_some() {
+            return self.value.len();
+        }
+        let mut end = index;
+        let mut in_word = false;
+        for (i, c) in self.value[index..].char_indices() {
+            if c.is_whitespace() {
+                if in_word {
+                    break;
+                }
+            } else {
+                in_word = true;
+            }
+            end = index + i + c.len_utf8();
+        }
+        end
+    }
+}
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+/// Sent whenever the value of a text field changes through user input.
+///
+/// `value` is empty for secret fields (see `TextFieldState::secret`).
+#[derive(Event, Debug, Clone)]
+pub struct TextChangedEvent {
+    pub entity: Entity,
+    pub value: String,
+}
+
+/// Sent when Enter is pressed in a single-line text field.
+///
+/// `value` is empty for secret fields (see `TextFieldState::secret`).
+#[derive(Event, Debug, Clone)]
+pub struct TextSubmittedEvent {
+    pub entity: Entity,
+    pub value: String,
+}
@@ -0,0 +1,128 @@
+use bevy::prelude::*;
+use bevy::text::ComputedTextBlock;
+
+/// A position the caret can take inside laid out text, in logical pixels
+/// relative to the top left corner of the text node.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CaretStop {
+    /// Byte index into the displayed string.
+    pub index: usize,
+    pub x: f32,
+    pub top: f32,
+    pub height: f32,
+    /// Visual line (after soft wrapping).
+    pub line: usize,
+}
+
+/// Collects all caret stops of a laid out text block.
+///
+/// `text` must be the string the block was laid out from; `scale` converts
+/// the physical layout coordinates to logical pixels
+/// (`ComputedNode::inverse_scale_factor`).
+pub fn caret_stops(text: &str, block: &ComputedTextBlock, scale: f32) -> Vec<CaretStop> {
+    // Byte offset of every hard line, cosmic-text indexes glyphs per line
+    let mut line_starts = vec![0];
+    line_starts.extend(text.match_indices('\n').map(|(i, _)| i + 1));
+
+    let mut stops = Vec::new();
+    for (line, run) in block.buffer().layout_runs().enumerate() {
+        let offset = line_starts.get(run.line_i).copied().unwrap_or(text.len());
+        let top = run.line_top * scale;
+        let height = run.line_height * scale;
+        let mut stop = |index: usize, x: f32| {
+            stops.push(CaretStop {
+                index: offset + index,
+                x: x * scale,
+                top,
+                height,
+                line,
+            })
+        };
+        match (run.glyphs.first(), run.glyphs.last()) {
+            (Some(_), Some(last)) => {
+                for glyph in run.glyphs.iter() {
+                    stop(glyph.start, glyph.x);
+                }
+                stop(last.end, last.x + last.w);
+            }
+            _ => stop(0, 0.0),
+        }
+    }
+    stops
+}
+
+/// Stop for the caret at `index`. At a soft wrap the stop on the following
+/// line is preferred.
+pub fn caret_stop(stops: &[CaretStop], index: usize) -> Option<CaretStop> {
+    stops
+        .iter()
+        .rev()
+        .find(|s| s.index == index)
+        .or_else(|| stops.iter().rev().find(|s| s.index <= index))
+        .copied()
+}
+
+/// Nearest caret index for a point relative to the text node.
+pub fn index_at(stops: &[CaretStop], point: Vec2) -> usize {
+    let Some(line) = stops
+        .iter()
+        .min_by(|a, b| {
+            line_distance(a, point.y)
+                .partial_cmp(&line_distance(b, point.y))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .map(|s| s.line)
+    else {
+        return 0;
+    };
+    index_on_line(stops, line, point.x).unwrap_or(0)
+}
+
+/// Nearest caret index on visual line `line` for the horizontal position `x`.
+pub fn index_on_line(stops: &[CaretStop], line: usize, x: f32) -> Option<usize> {
+    stops
+        .iter()
+        .filter(|s| s.line == line)
+        .min_by(|a, b| {
+            (a.x - x)
+                .abs()
+                .partial_cmp(&(b.x - x).abs())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .map(|s| s.index)
+}
+
+fn line_distance(stop: &CaretStop, y: f32) -> f32 {
+    if y < stop.top {
+        stop.top - y
+    } else if y > stop.top + stop.height {
+        y - stop.top - stop.height
+    } else {
+        0.0
+    }
+}
+
+/// One rectangle per visual line covering the byte `range`, relative to
+/// the text node. Lines without selected glyphs are left out.
+pub fn selection_rects(stops: &[CaretStop], range: std::ops::Range<usize>) -> Vec<Rect> {
+    let mut lines: Vec<Rect> = Vec::new();
+    for stop in stops
+        .iter()
+        .filter(|s| range.contains(&s.index) || s.index == range.end)
+    {
+        match lines.get_mut(stop.line) {
+            Some(rect) if rect.height() > 0.0 => {
+                rect.min.x = rect.min.x.min(stop.x);
+                rect.max.x = rect.max.x.max(stop.x);
+            }
+            _ => {
+                while lines.len() <= stop.line {
+                    lines.push(Rect::default());
+                }
+                lines[stop.line] = Rect::new(stop.x, stop.top, stop.x, stop.top + stop.height);
+            }
+        }
+    }
+    lines.retain(|r| r.width() > 0.0);
+    lines
+}
@@ -0,0 +1,15 @@
+mod builder;
+mod components;
+mod events;
+mod layout;
+mod plugin;
+mod style;
+mod systems;
+
+pub use builder::*;
+pub use components::*;
+pub use events::*;
+pub use layout::*;
+pub use plugin::*;
+pub use style::*;
+pub use systems::*;
@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+
+use super::{systems::*, TextChangedEvent, TextSubmittedEvent};
+use crate::plugin::UiState;
+
+/// Plugin for [`TextFieldBuilder`](super::TextFieldBuilder) and all other
+/// inputs built on [`TextFieldState`](super::TextFieldState).
+pub struct TextFieldPlugin;
+
+impl Plugin for TextFieldPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<TextChangedEvent>()
+            .add_event::<TextSubmittedEvent>()
+            .add_systems(
+                Update,
+                (
+                    handle_text_field_pointer,
+                    handle_text_field_keyboard,
+                    update_text_field_visuals,
+                    update_text_field_ime,
+                )
+                    .chain()
+                    .run_if(in_state(UiState::Ready)),
+            );
+    }
+}
@@ -0,0 +1,94 @@
+use bevy::prelude::*;
+
+use crate::theme::UiTheme;
+
+/// Style bundle for the root of a text field.
+#[derive(Bundle, Clone, Debug)]
+pub struct TextFieldStyle {
+    pub node: Node,
+    pub background_color: BackgroundColor,
+    pub border_color: BorderColor,
+    pub border_radius: BorderRadius,
+}
+
+impl TextFieldStyle {
+    pub fn new(theme: &UiTheme) -> Self {
+        Self {
+            node: Node {
+                display: Display::Flex,
+                align_items: AlignItems::Center,
+                min_width: Val::Px(160.0),
+                padding: UiRect::axes(
+                    Val::Px(theme.layout.padding.sm),
+                    Val::Px(theme.layout.padding.xs),
+                ),
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            background_color: BackgroundColor(theme.color.gray.step01),
+            border_color: BorderColor(theme.color.gray.step07),
+            border_radius: BorderRadius::all(Val::Px(theme.layout.radius.sm)),
+        }
+    }
+
+    /// Border color for the current focus state.
+    pub fn border(theme: &UiTheme, focused: bool) -> BorderColor {
+        if focused {
+            BorderColor(theme.accent.step08)
+        } else {
+            BorderColor(theme.color.gray.step07)
+        }
+    }
+
+    /// Border color for a field marked with
+    /// [`TextFieldInvalid`](super::TextFieldInvalid).
+    pub fn invalid_border(theme: &UiTheme) -> BorderColor {
+        BorderColor(theme.color.tomato.step08)
+    }
+}
+
+/// Clipping node around text, caret and selection.
+pub fn text_field_viewport_node(line_height: f32) -> Node {
+    Node {
+        position_type: PositionType::Relative,
+        flex_grow: 1.0,
+        min_height: Val::Px(line_height),
+        overflow: Overflow::clip(),
+        ..default()
+    }
+}
+
+/// Caret bar, positioned by the text field systems.
+pub fn text_field_caret(theme: &UiTheme, line_height: f32) -> (Node, BackgroundColor) {
+    (
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Px(1.0),
+            height: Val::Px(line_height),
+            ..default()
+        },
+        BackgroundColor(theme.color.gray.step12),
+    )
+}
+
+/// Container for the selection highlight. The systems add one rectangle per
+/// selected line, colored with `theme.accent.step05`.
+pub fn text_field_selection() -> Node {
+    Node {
+        position_type: PositionType::Absolute,
+        left: Val::Px(0.0),
+        top: Val::Px(0.0),
+        ..default()
+    }
+}
+
+/// Container for the underline of IME composition text. The systems add one
+/// line per row of the composition, drawn above the text.
+pub fn text_field_preedit() -> Node {
+    Node {
+        position_type: PositionType::Absolute,
+        left: Val::Px(0.0),
+        top: Val::Px(0.0),
+        ..default()
+    }
+}
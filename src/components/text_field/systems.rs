@@ -0,0 +1,553 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::mouse::MouseButton;
+use bevy::input::ButtonState;
+use bevy::input_focus::InputFocus;
+use bevy::prelude::*;
+use bevy::text::ComputedTextBlock;
+use bevy::ui::RelativeCursorPosition;
+use bevy::window::{Ime, PrimaryWindow};
+
+use super::*;
+use crate::components::clipboard::Clipboard;
+use crate::components::direction_provider::RuntimePosition;
+use crate::components::disabled::InheritedDisabled;
+use crate::components::shortcut::{editing_command, is_printable, Modifiers};
+use crate::theme::UiTheme;
+
+/// Marker for one highlight rectangle below a [`TextFieldSelectionMarker`].
+#[derive(Component, Debug, Clone, Copy, Default)]
+#[require(RuntimePosition)]
+pub struct TextFieldSelectionRect;
+
+/// Marker for one underline segment below a [`TextFieldPreeditMarker`].
+#[derive(Component, Debug, Clone, Copy, Default)]
+#[require(RuntimePosition)]
+pub struct TextFieldPreeditRect;
+
+/// First descendant of `root` matching `query`.
+pub(crate) fn find_part<D: bevy::ecs::query::QueryData, F: bevy::ecs::query::QueryFilter>(
+    root: Entity,
+    children: &Query<&Children>,
+    query: &Query<D, F>,
+) -> Option<Entity> {
+    children
+        .iter_descendants(root)
+        .find(|entity| query.contains(*entity))
+}
+
+/// Caret stops of the text node, empty while the placeholder is shown.
+/// Indices refer to `state.value`, also for masked fields.
+pub(crate) fn text_stops(
+    state: &TextFieldState,
+    text: &Text,
+    block: Option<&ComputedTextBlock>,
+    node: &ComputedNode,
+) -> Vec<CaretStop> {
+    match block {
+        Some(block) if !state.value.is_empty() || state.is_composing() => {
+            let mut stops = caret_stops(&text.0, block, node.inverse_scale_factor());
+            if state.mask.is_some() || state.is_composing() {
+                for stop in stops.iter_mut() {
+                    stop.index = state.value_index(stop.index);
+                }
+            }
+            stops
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Places the caret on click and extends the selection while dragging.
+pub fn handle_text_field_pointer(
+    time: Res<Time>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut fields: Query<(Entity, &Interaction, &mut TextFieldState), Without<InheritedDisabled>>,
+    children: Query<&Children>,
+    viewports: Query<(&RelativeCursorPosition, &ComputedNode), With<TextFieldViewportMarker>>,
+    texts: Query<(&Text, Option<&ComputedTextBlock>, &ComputedNode), With<TextFieldTextMarker>>,
+) {
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    for (entity, interaction, mut state) in fields.iter_mut() {
+        let pressed = mouse.just_pressed(MouseButton::Left) && *interaction == Interaction::Pressed;
+        if !mouse.pressed(MouseButton::Left) {
+            if state.dragging {
+                state.dragging = false;
+            }
+            continue;
+        }
+        if !pressed && !state.dragging {
+            continue;
+        }
+
+        let Some((cursor, viewport_node)) =
+            find_part(entity, &children, &viewports).and_then(|e| viewports.get(e).ok())
+        else {
+            continue;
+        };
+        let Some(normalized) = cursor.normalized else {
+            continue;
+        };
+        let Some((text, block, text_node)) =
+            find_part(entity, &children, &texts).and_then(|e| texts.get(e).ok())
+        else {
+            continue;
+        };
+
+        let size = viewport_node.size() * viewport_node.inverse_scale_factor();
+        let point = normalized * size + state.scroll;
+        let stops = text_stops(&state, text, block, text_node);
+        let index = index_at(&stops, point);
+
+        let extend = state.dragging || shift;
+        state.move_to(index, extend);
+        state.dragging = true;
+        state.goal_x = None;
+        state.blink_reset = time.elapsed_secs();
+    }
+}
+
+/// Applies keyboard and IME input to the focused text field.
+pub fn handle_text_field_keyboard(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    focus: Res<InputFocus>,
+    mut clipboard: ResMut<Clipboard>,
+    mut key_events: EventReader<KeyboardInput>,
+    mut ime_events: EventReader<Ime>,
+    mut fields: Query<&mut TextFieldState, Without<InheritedDisabled>>,
+    mut changed: EventWriter<TextChangedEvent>,
+    mut submitted: EventWriter<TextSubmittedEvent>,
+) {
+    let Some(entity) = focus.get() else {
+        key_events.clear();
+        ime_events.clear();
+        return;
+    };
+    let Ok(mut state) = fields.get_mut(entity) else {
+        key_events.clear();
+        ime_events.clear();
+        return;
+    };
+
+    // Während einer Komposition gehören die Tasten dem IME
+    if state.is_composing() {
+        key_events.clear();
+    }
+
+    let modifiers = Modifiers::pressed(&keys);
+    // Word-wise movement: Alt on macOS, Ctrl everywhere else
+    let word = if cfg!(target_os = "macos") {
+        modifiers.alt
+    } else {
+        modifiers.ctrl
+    };
+    let extend = modifiers.shift;
+    let mut edited = false;
+    let mut moved = false;
+
+    for event in key_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        if let Some(command) = editing_command(&event.logical_key, modifiers) {
+            match command {
+                'a' => {
+                    state.select_all();
+                    moved = true;
+                }
+                'c' if state.has_selection() && !state.secret => {
+                    clipboard.set_text(state.selected_text());
+                }
+                'x' if state.has_selection() && !state.secret => {
+                    clipboard.set_text(state.selected_text());
+                    edited |= state.delete_selection();
+                }
+                'v' => {
+                    let text = clipboard.get_text().unwrap_or_default();
+                    edited |= state.insert(&text);
+                }
+                _ => {}
+            }
+            continue;
+        }
+        match &event.logical_key {
+            Key::Backspace => edited |= state.backspace(word),
+            Key::Delete => edited |= state.delete_forward(word),
+            Key::ArrowLeft => {
+                state.move_left(word, extend);
+                moved = true;
+            }
+            Key::ArrowRight => {
+                state.move_right(word, extend);
+                moved = true;
+            }
+            Key::Home if !state.multiline => {
+                state.move_to(0, extend);
+                moved = true;
+            }
+            Key::End if !state.multiline => {
+                let end = state.value.len();
+                state.move_to(end, extend);
+                moved = true;
+            }
+            Key::Enter if state.multiline => edited |= state.insert("\n"),
+            Key::Enter => {
+                submitted.write(TextSubmittedEvent {
+                    entity,
+                    value: public_value(&state),
+                });
+            }
+            // Anders als AltGr/Option wählt Super keine Zeichenebene, Cmd+S tippt kein "s"
+            _ if modifiers.super_key => {}
+            _ => {
+                if let Some(text) = event.text.as_deref().filter(|t| is_printable(t)) {
+                    edited |= state.insert(text);
+                }
+            }
+        }
+    }
+
+    for event in ime_events.read() {
+        match event {
+            Ime::Preedit { value, cursor, .. } if state.accepts_ime() => {
+                edited |= state.set_preedit(value, *cursor);
+                moved = true;
+            }
+            Ime::Commit { value, .. } => {
+                state.clear_preedit();
+                edited |= state.insert(value);
+            }
+            Ime::Disabled { .. } => {
+                if state.is_composing() {
+                    state.clear_preedit();
+                    moved = true;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if edited || moved {
+        state.goal_x = None;
+        state.blink_reset = time.elapsed_secs();
+    }
+    if edited {
+        changed.write(TextChangedEvent {
+            entity,
+            value: public_value(&state),
+        });
+    }
+}
+
+/// Value for the text events; secret fields never leave their state.
+fn public_value(state: &TextFieldState) -> String {
+    if state.secret {
+        String::new()
+    } else {
+        state.value.clone()
+    }
+}
+
+/// Thickness of the line below IME composition text, in logical pixels.
+const PREEDIT_UNDERLINE: f32 = 1.0;
+
+/// Renders value or placeholder and positions caret and selection.
+///
+/// Keeps the caret inside the viewport by scrolling the text node.
+pub fn update_text_field_visuals(
+    mut commands: Commands,
+    time: Res<Time>,
+    theme: Option<Res<UiTheme>>,
+    focus: Res<InputFocus>,
+    mut fields: Query<(
+        Entity,
+        &mut TextFieldState,
+        Option<&mut BorderColor>,
+        Has<TextFieldInvalid>,
+    )>,
+    children: Query<&Children>,
+    viewports: Query<&ComputedNode, With<TextFieldViewportMarker>>,
+    mut texts: Query<
+        (
+            &mut Text,
+            &mut TextColor,
+            &mut Node,
+            Option<&ComputedTextBlock>,
+            &ComputedNode,
+        ),
+        With<TextFieldTextMarker>,
+    >,
+    mut carets: Query<
+        (&mut Node, &mut Visibility),
+        (With<TextFieldCaretMarker>, Without<TextFieldTextMarker>),
+    >,
+    selections: Query<Option<&Children>, With<TextFieldSelectionMarker>>,
+    mut rects: Query<
+        &mut Node,
+        (
+            With<TextFieldSelectionRect>,
+            Without<TextFieldTextMarker>,
+            Without<TextFieldCaretMarker>,
+        ),
+    >,
+    preedits: Query<Option<&Children>, With<TextFieldPreeditMarker>>,
+    mut underlines: Query<
+        &mut Node,
+        (
+            With<TextFieldPreeditRect>,
+            Without<TextFieldSelectionRect>,
+            Without<TextFieldTextMarker>,
+            Without<TextFieldCaretMarker>,
+        ),
+    >,
+) {
+    let Some(theme) = theme else {
+        return;
+    };
+
+    for (entity, mut state, border, invalid) in fields.iter_mut() {
+        let focused = focus.get() == Some(entity);
+        if let Some(mut border) = border {
+            let wanted = if invalid {
+                TextFieldStyle::invalid_border(&theme)
+            } else {
+                TextFieldStyle::border(&theme, focused)
+            };
+            if border.0 != wanted.0 {
+                *border = wanted;
+            }
+        }
+
+        let Some(text_entity) = find_part(entity, &children, &texts) else {
+            continue;
+        };
+        let Ok((mut text, mut color, mut text_node, block, computed)) = texts.get_mut(text_entity)
+        else {
+            continue;
+        };
+
+        // 1) Inhalt: Wert (ggf. maskiert) oder Platzhalter
+        let (display, display_color) = if state.value.is_empty() && !state.is_composing() {
+            (state.placeholder.as_str().into(), theme.color.gray.step09)
+        } else {
+            (state.display_text(), theme.color.gray.step12)
+        };
+        if text.0 != display {
+            text.0 = display.into_owned();
+        }
+        if color.0 != display_color {
+            color.0 = display_color;
+        }
+
+        // 2) Caret-Position aus dem Layout des letzten Frames. Während einer
+        //    IME-Komposition zählen die Indizes im angezeigten Text.
+        let composing = state.is_composing();
+        let stops = match block {
+            Some(block) if composing && !text.0.is_empty() => {
+                caret_stops(&text.0, block, computed.inverse_scale_factor())
+            }
+            _ => text_stops(&state, &text, block, computed),
+        };
+        let line_height = theme.font.size.base * 1.2;
+        let caret = caret_stop(&stops, state.display_caret()).unwrap_or(CaretStop {
+            index: 0,
+            x: 0.0,
+            top: 0.0,
+            height: line_height,
+            line: 0,
+        });
+
+        // 3) Scrollen, damit der Caret sichtbar bleibt
+        let viewport = find_part(entity, &children, &viewports)
+            .and_then(|e| viewports.get(e).ok())
+            .map(|n| n.size() * n.inverse_scale_factor())
+            .unwrap_or(Vec2::ZERO);
+        let mut scroll = state.scroll;
+        if caret.x < scroll.x {
+            scroll.x = caret.x;
+        } else if caret.x + 1.0 > scroll.x + viewport.x {
+            scroll.x = caret.x + 1.0 - viewport.x;
+        }
+        if caret.top < scroll.y {
+            scroll.y = caret.top;
+        } else if caret.top + caret.height > scroll.y + viewport.y {
+            scroll.y = caret.top + caret.height - viewport.y;
+        }
+        if state.multiline {
+            // Umbrochener Text passt immer in die Breite
+            scroll.x = 0.0;
+        }
+        let content = computed.size() * computed.inverse_scale_factor();
+        scroll = scroll
+            .min((content - viewport).max(Vec2::ZERO) + Vec2::X)
+            .max(Vec2::ZERO);
+        if state.scroll != scroll {
+            state.scroll = scroll;
+        }
+        let (left, top) = (Val::Px(-scroll.x), Val::Px(-scroll.y));
+        if text_node.left != left || text_node.top != top {
+            text_node.left = left;
+            text_node.top = top;
+        }
+
+        // 4) Caret blinkt nur im fokussierten Feld
+        if let Some((mut caret_node, mut caret_vis)) =
+            find_part(entity, &children, &carets).and_then(|e| carets.get_mut(e).ok())
+        {
+            let phase = (time.elapsed_secs() - state.blink_reset).rem_euclid(1.0);
+            // Der IME kann den Cursor in der Komposition ausblenden
+            let hidden_by_ime = composing && state.preedit_cursor.is_none();
+            let visible = focused && phase < 0.5 && !hidden_by_ime;
+            let wanted = if visible {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            };
+            if *caret_vis != wanted {
+                *caret_vis = wanted;
+            }
+            let (left, top) = (Val::Px(caret.x - scroll.x), Val::Px(caret.top - scroll.y));
+            let height = Val::Px(caret.height);
+            if caret_node.left != left || caret_node.top != top || caret_node.height != height {
+                caret_node.left = left;
+                caret_node.top = top;
+                caret_node.height = height;
+            }
+        }
+
+        // 5) Unterstreichung der IME-Komposition, eine Linie pro Zeile
+        if let Some(preedit_entity) = find_part(entity, &children, &preedits) {
+            let lines = if composing {
+                let start = state.caret;
+                selection_rects(&stops, start..start + state.preedit().len())
+            } else {
+                Vec::new()
+            };
+            let existing: Vec<Entity> = preedits
+                .get(preedit_entity)
+                .ok()
+                .flatten()
+                .map(|c| c.iter().collect())
+                .unwrap_or_default();
+            for (i, rect) in lines.iter().enumerate() {
+                let node = Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(rect.min.x - scroll.x),
+                    top: Val::Px(rect.max.y - PREEDIT_UNDERLINE - scroll.y),
+                    width: Val::Px(rect.width()),
+                    height: Val::Px(PREEDIT_UNDERLINE),
+                    ..default()
+                };
+                match existing.get(i).and_then(|e| underlines.get_mut(*e).ok()) {
+                    Some(mut current) => {
+                        if *current != node {
+                            *current = node;
+                        }
+                    }
+                    None => {
+                        commands.entity(preedit_entity).with_child((
+                            TextFieldPreeditRect,
+                            node,
+                            BackgroundColor(theme.color.gray.step12),
+                        ));
+                    }
+                }
+            }
+            for extra in existing.iter().skip(lines.len()) {
+                commands.entity(*extra).despawn();
+            }
+        }
+
+        // 6) Auswahl: ein Rechteck pro sichtbarer Zeile
+        let Some(selection_entity) = find_part(entity, &children, &selections) else {
+            continue;
+        };
+        let lines = if focused && state.has_selection() && !composing {
+            selection_rects(&stops, state.selection())
+        } else {
+            Vec::new()
+        };
+
+        let existing: Vec<Entity> = selections
+            .get(selection_entity)
+            .ok()
+            .flatten()
+            .map(|c| c.iter().collect())
+            .unwrap_or_default();
+        for (i, rect) in lines.iter().enumerate() {
+            let node = Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(rect.min.x - scroll.x),
+                top: Val::Px(rect.min.y - scroll.y),
+                width: Val::Px(rect.width()),
+                height: Val::Px(rect.height()),
+                ..default()
+            };
+            match existing.get(i).and_then(|e| rects.get_mut(*e).ok()) {
+                Some(mut current) => {
+                    if *current != node {
+                        *current = node;
+                    }
+                }
+                None => {
+                    commands.entity(selection_entity).with_child((
+                        TextFieldSelectionRect,
+                        node,
+                        BackgroundColor(theme.accent.step05),
+                    ));
+                }
+            }
+        }
+        for extra in existing.iter().skip(lines.len()) {
+            commands.entity(*extra).despawn();
+        }
+    }
+}
+
+/// Turns IME on while a text input that accepts it is focused and moves the
+/// candidate window to the caret.
+///
+/// IME is only switched when the focused field changes, so other widgets
+/// can still enable it themselves. Compositions of fields that lost focus
+/// are dropped.
+pub fn update_text_field_ime(
+    mut last_active: Local<Option<Entity>>,
+    focus: Res<InputFocus>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    mut fields: Query<(Entity, &mut TextFieldState, Has<InheritedDisabled>)>,
+    children: Query<&Children>,
+    carets: Query<(&ComputedNode, &GlobalTransform), With<TextFieldCaretMarker>>,
+) {
+    let mut active = None;
+    for (entity, mut state, disabled) in fields.iter_mut() {
+        if focus.get() == Some(entity) && !disabled && state.accepts_ime() {
+            active = Some(entity);
+        } else if state.is_composing() {
+            state.clear_preedit();
+        }
+    }
+
+    let Ok(mut window) = windows.single_mut() else {
+        return;
+    };
+    if *last_active != active {
+        *last_active = active;
+        window.ime_enabled = active.is_some();
+    }
+
+    let Some((caret_node, transform)) = active
+        .and_then(|entity| find_part(entity, &children, &carets))
+        .and_then(|caret| carets.get(caret).ok())
+    else {
+        return;
+    };
+    // Unterkante des Carets, in logischen Fensterkoordinaten
+    let size = caret_node.size();
+    let center = transform.translation().truncate();
+    let position =
+        Vec2::new(center.x - size.x / 2.0, center.y + size.y / 2.0) / window.scale_factor();
+    if window.ime_position != position {
+        window.ime_position = position;
+    }
+}
@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 
 use crate::components::helper::UiBuilder;
+use crate::components::layer::{Layer, UiLayer};
+use crate::components::transition::UiTransition;
 use crate::theme::UiTheme;
 
 use super::{ToastMarker, ToastStyle, ToastTimer, ToastVariant};
@@ -37,7 +39,11 @@ impl<'w, 's> UiBuilder<'w, 's> for ToastBuilder {
     fn spawn(self, parent: &'s mut ChildSpawnerCommands<'w>, theme: &UiTheme, font: &Handle<Font>) -> Self::Output {
         let style = ToastStyle::new(self.variant, theme, font);
         let timer = ToastTimer(Timer::from_seconds(self.duration, TimerMode::Once));
-        let mut cmd = parent.spawn((ToastMarker, style, timer, Name::new("Toast")));
+        let transition = UiTransition::default()
+            .fade()
+            .slide(Vec2::new(0.0, theme.layout.gap.base * 2.0))
+            .appear();
+        let mut cmd = parent.spawn((ToastMarker, style, timer, transition, Layer(UiLayer::Toast), Name::new("Toast")));
         cmd.with_children(|cb| {
             cb.spawn((
                 Text::new(self.text),
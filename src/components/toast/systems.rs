@@ -1,17 +1,30 @@
 use bevy::prelude::*;
 
 use super::{ToastMarker, ToastTimer};
+use crate::components::transition::UiTransition;
 
-/// Despawns toasts after their timer has finished.
+/// Hides toasts after their timer has finished and despawns them once the
+/// closing transition is done.
 pub fn update_toast_timers(
     mut commands: Commands,
     time: Res<Time>,
-    mut query: Query<(Entity, &mut ToastTimer), With<ToastMarker>>,
+    mut query: Query<
+        (Entity, &mut ToastTimer, &mut Visibility, Option<&UiTransition>),
+        With<ToastMarker>,
+    >,
 ) {
-    for (entity, mut timer) in query.iter_mut() {
+    for (entity, mut timer, mut vis, transition) in query.iter_mut() {
         timer.0.tick(time.delta());
-        if timer.0.finished() {
-            commands.entity(entity).despawn();
+        if !timer.0.finished() {
+            continue;
+        }
+        match transition {
+            Some(transition) if transition.is_open() || transition.is_running() => {
+                if *vis != Visibility::Hidden {
+                    *vis = Visibility::Hidden;
+                }
+            }
+            _ => commands.entity(entity).despawn(),
         }
     }
 }
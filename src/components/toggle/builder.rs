@@ -2,6 +2,7 @@ use bevy::prelude::*;
 use bevy::ui::FocusPolicy;
 
 use super::*;
+use crate::components::disabled::Disabled;
 use crate::components::helper::NoAction;
 ///
 /// # Toggle Module
@@ -102,12 +103,12 @@ impl<A: Component + Clone + Send + Sync + 'static> ToggleBuilder<A> {
         self
     }
 
-    /// Spawnt den Toggle als Child‑Entity
+    /// Spawnt den Toggle als Child‑Entity und gibt dessen Entity zurück.
     pub fn spawn_into<'w>(
         self,
         parent: &mut ChildSpawnerCommands<'w>,
         theme: &crate::theme::UiTheme,
-    ) {
+    ) -> Entity {
         let style_def = get_toggle_style_def(theme, self.variant, self.size);
 
         // Haupt‑Button‑Node
@@ -122,6 +123,7 @@ impl<A: Component + Clone + Send + Sync + 'static> ToggleBuilder<A> {
             BackgroundColor(theme.gray_accent.step10),
             BorderRadius::all(Val::Px(style_def.border_radius)),
             FocusPolicy::Pass,
+            Interaction::default(),
         ));
 
         cmd.insert((
@@ -149,25 +151,10 @@ impl<A: Component + Clone + Send + Sync + 'static> ToggleBuilder<A> {
             });
         }
 
-        // Disabled‑Overlay
+        // Disabled‑Overlay kommt aus der gemeinsamen `Disabled`-Komponente
         if self.disabled {
-            spawn_disabled_overlay(&mut cmd, theme);
+            cmd.insert(Disabled);
         }
+        cmd.id()
     }
 }
-
-/// Hilfsfunktion zum Spawnen eines Disabled‑Overlays (siehe template.md §2.2)
-fn spawn_disabled_overlay<'w>(cmd: &mut EntityCommands<'w>, theme: &crate::theme::UiTheme) {
-    cmd.with_children(|parent| {
-        parent.spawn((
-            Node {
-                position_type: PositionType::Absolute,
-                width: Val::Percent(100.0),
-                height: Val::Percent(100.0),
-                ..default()
-            },
-            BackgroundColor(theme.color.black.step05),
-            FocusPolicy::Pass,
-        ));
-    });
-}
@@ -1,4 +1,5 @@
 use crate::components::toggle::enums::{ToggleSize, ToggleVariant};
+use crate::components::disabled::DisabledFlag;
 use bevy::prelude::*;
 /// Marker für die Haupt‑Entität eines Toggles.
 #[derive(Component, Debug, Clone, Copy)]
@@ -23,3 +24,13 @@ impl Default for ToggleState {
         }
     }
 }
+
+impl DisabledFlag for ToggleState {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+}
@@ -1,7 +1,9 @@
 use super::{
     events::ToggleChangedEvent,
-    systems::{handle_toggle_interaction, update_toggle_visuals},
+    systems::{handle_toggle_interaction, handle_toggle_keyboard, update_toggle_visuals},
 };
+use super::ToggleState;
+use crate::components::disabled::sync_disabled_flag;
 use crate::prelude::UiState;
 use bevy::prelude::*;
 use std::marker::PhantomData;
@@ -17,9 +19,26 @@ impl<A: Component + Clone + Send + Sync + 'static> Default for TogglePlugin<A> {
 
 impl<A: Component + Clone + Send + Sync + 'static> Plugin for TogglePlugin<A> {
     fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<ToggleStatePlugin>() {
+            app.add_plugins(ToggleStatePlugin);
+        }
         app.add_event::<ToggleChangedEvent<A>>().add_systems(
             Update,
-            (handle_toggle_interaction::<A>, update_toggle_visuals)
+            (handle_toggle_interaction::<A>, handle_toggle_keyboard::<A>)
+                .run_if(in_state(UiState::Ready)),
+        );
+    }
+}
+
+/// Systeme, die nicht vom Aktionstyp abhängen. Wird vom ersten
+/// [`TogglePlugin`] hinzugefügt, damit sie nur einmal laufen.
+pub struct ToggleStatePlugin;
+
+impl Plugin for ToggleStatePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (update_toggle_visuals, sync_disabled_flag::<ToggleState>)
                 .run_if(in_state(UiState::Ready)),
         );
     }
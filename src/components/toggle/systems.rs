@@ -1,6 +1,10 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::input_focus::InputFocus;
 use bevy::prelude::*;
 
 use super::*;
+use crate::components::disabled::InheritedDisabled;
 
 /// Behandelt `Interaction::Pressed` und toggelt den Zustand.
 pub fn handle_toggle_interaction<A: Component + Clone + Send + Sync + 'static>(
@@ -22,6 +26,39 @@ pub fn handle_toggle_interaction<A: Component + Clone + Send + Sync + 'static>(
     }
 }
 
+/// Space und Enter schalten den fokussierten Toggle.
+pub fn handle_toggle_keyboard<A: Component + Clone + Send + Sync + 'static>(
+    focus: Res<InputFocus>,
+    mut key_events: EventReader<KeyboardInput>,
+    mut toggles: Query<
+        (&mut ToggleState, Option<&A>),
+        (With<ToggleMarker>, Without<InheritedDisabled>),
+    >,
+    mut toggle_events: EventWriter<ToggleChangedEvent<A>>,
+) {
+    let Some(entity) = focus.get() else {
+        key_events.clear();
+        return;
+    };
+    let Ok((mut state, action_opt)) = toggles.get_mut(entity) else {
+        key_events.clear();
+        return;
+    };
+    for event in key_events.read() {
+        if event.state != ButtonState::Pressed || event.repeat {
+            continue;
+        }
+        if matches!(event.logical_key, Key::Enter | Key::Space) && !state.disabled {
+            state.pressed = !state.pressed;
+            toggle_events.write(ToggleChangedEvent {
+                source_entity: entity,
+                pressed: state.pressed,
+                action_id: action_opt.cloned(),
+            });
+        }
+    }
+}
+
 /// Aktualisiert Farben auf Basis von Zustand & Interaction.
 pub fn update_toggle_visuals(
     mut toggles: Query<
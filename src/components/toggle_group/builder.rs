@@ -1,5 +1,6 @@
 use super::*;
 use crate::components::helper::NoAction;
+use crate::components::roving_focus::{RovingFocusGroup, RovingFocusItem, RovingFocusOrientation};
 use crate::components::toggle::{ToggleBuilder, ToggleSize, ToggleVariant};
 use bevy::prelude::*;
 use std::collections::HashSet;
@@ -196,39 +197,54 @@ impl<A: Component + Clone + Send + Sync + 'static> ToggleGroupBuilder<A> {
             cmd.insert(action);
         }
 
+        // Arrow-key navigation between the items
+        if self.roving_focus {
+            cmd.insert(RovingFocusGroup::new(
+                match self.orientation {
+                    ToggleGroupOrientation::Horizontal => RovingFocusOrientation::Horizontal,
+                    ToggleGroupOrientation::Vertical => RovingFocusOrientation::Vertical,
+                },
+                self.loop_navigation,
+            ));
+        }
+
         // Add toggle items
+        let roving_focus = self.roving_focus;
         cmd.with_children(|parent| {
             for item in self.items {
-                parent
-                    .spawn((
-                        Node {
-                            display: Display::Flex,
-                            align_items: AlignItems::Center,
-                            justify_content: JustifyContent::Center,
-                            ..Default::default()
-                        },
-                        ToggleGroupItemMarker,
-                        ToggleGroupItemState {
-                            value: item.value.clone(),
-                            pressed: self.default_values.contains(&item.value),
-                            disabled: item.disabled,
-                        },
-                        Name::new(format!("ToggleGroupItem-{}", item.value)),
-                    ))
-                    .with_children(|parent| {
-                        // Create the actual toggle button inside each item
-                        let _ = ToggleBuilder::<A>::new()
-                            .pressed(self.default_values.contains(&item.value))
-                            .disabled(self.disabled || item.disabled)
-                            .variant(toggle_variant)
-                            .size(toggle_size)
-                            .icon(item.icon.unwrap_or_else(|| {
-                                // Create an empty/transparent image handle if none provided
-                                // This is a placeholder - you'd want to have a proper empty handle
-                                Handle::default()
-                            }))
-                            .spawn_into(parent, theme);
-                    });
+                let mut item_cmd = parent.spawn((
+                    Node {
+                        display: Display::Flex,
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::Center,
+                        ..Default::default()
+                    },
+                    ToggleGroupItemMarker,
+                    ToggleGroupItemState {
+                        value: item.value.clone(),
+                        pressed: self.default_values.contains(&item.value),
+                        disabled: item.disabled,
+                    },
+                    Name::new(format!("ToggleGroupItem-{}", item.value)),
+                ));
+                item_cmd.with_children(|parent| {
+                    // Create the actual toggle button inside each item
+                    let toggle = ToggleBuilder::<A>::new()
+                        .pressed(self.default_values.contains(&item.value))
+                        .disabled(self.disabled || item.disabled)
+                        .variant(toggle_variant)
+                        .size(toggle_size)
+                        .icon(item.icon.unwrap_or_else(|| {
+                            // Create an empty/transparent image handle if none provided
+                            // This is a placeholder - you'd want to have a proper empty handle
+                            Handle::default()
+                        }))
+                        .spawn_into(parent, theme);
+                    // Fokus gehört dem Toggle selbst, damit Disabled greift und Space/Enter schalten
+                    if roving_focus {
+                        parent.commands().entity(toggle).insert(RovingFocusItem);
+                    }
+                });
             }
         });
 
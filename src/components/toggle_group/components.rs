@@ -1,9 +1,6 @@
 use super::enums::{ToggleGroupOrientation, ToggleGroupSize, ToggleGroupType, ToggleGroupVariant};
 use bevy::prelude::*;
 use std::collections::HashSet;
-// Simple focus component and event for keyboard navigation
-#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default)]
-pub struct FocusState;
 
 /// Marker for the main entity of a ToggleGroup
 #[derive(Component, Debug, Clone, Copy)]
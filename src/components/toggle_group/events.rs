@@ -20,9 +20,3 @@ impl<A: Component + Clone + Send + Sync + 'static> std::fmt::Debug for ToggleGro
             .finish_non_exhaustive()
     }
 }
-
-#[derive(Event, Debug, Clone)]
-pub enum FocusEvent {
-    Focus { entity: Entity },
-    Blur { entity: Entity },
-}
@@ -16,7 +16,6 @@ impl<A: Component + Clone + Send + Sync + 'static> Default for ToggleGroupPlugin
 impl<A: Component + Clone + Send + Sync + 'static> Plugin for ToggleGroupPlugin<A> {
     fn build(&self, app: &mut App) {
         app.add_event::<ToggleGroupChangedEvent<A>>();
-        app.add_event::<FocusEvent>();
 
         // Register systems individually rather than as a tuple
         app.add_systems(
@@ -27,10 +26,6 @@ impl<A: Component + Clone + Send + Sync + 'static> Plugin for ToggleGroupPlugin<
             Update,
             update_toggle_group_visuals.run_if(in_state(UiState::Ready)),
         )
-        .add_systems(
-            Update,
-            handle_toggle_group_keyboard_navigation.run_if(in_state(UiState::Ready)),
-        )
         .add_systems(
             Startup,
             sync_toggle_group_on_startup.run_if(in_state(UiState::Ready)),
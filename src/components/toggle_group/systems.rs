@@ -1,4 +1,5 @@
 use super::*;
+use crate::components::direction_provider::{ReadingDirection, ResolvedDirection};
 use crate::components::toggle::{ToggleChangedEvent, ToggleMarker, ToggleState};
 
 use bevy::prelude::*;
@@ -8,13 +9,18 @@ pub fn handle_toggle_group_item_changes<A: Component + Clone + Send + Sync + 'st
     mut toggle_events: EventReader<ToggleChangedEvent<A>>,
     mut toggle_groups: Query<(Entity, &mut ToggleGroupState, Option<&A>), With<ToggleGroupMarker>>,
     toggle_items: Query<(&ChildOf, &ToggleGroupItemState), With<ToggleGroupItemMarker>>,
+    toggle_parents: Query<&ChildOf, With<ToggleMarker>>,
     mut toggle_states: Query<&mut ToggleState, With<ToggleMarker>>,
     children_query: Query<&Children>,
     mut toggle_group_events: EventWriter<ToggleGroupChangedEvent<A>>,
 ) {
     for event in toggle_events.read() {
-        // Find the parent toggle group for this toggle
-        if let Ok((child_of, item_state)) = toggle_items.get(event.source_entity) {
+        // The toggle sits inside an item wrapper, the wrapper inside the group
+        let Ok(toggle_parent) = toggle_parents.get(event.source_entity) else {
+            continue;
+        };
+        let item_entity = toggle_parent.parent();
+        if let Ok((child_of, item_state)) = toggle_items.get(item_entity) {
             let parent_entity = child_of.parent();
 
             // Get the toggle group state
@@ -37,7 +43,7 @@ pub fn handle_toggle_group_item_changes<A: Component + Clone + Send + Sync + 'st
                             // Update visual state of other toggles
                             if let Ok(children) = children_query.get(parent_entity) {
                                 for child in children.iter() {
-                                    if child != event.source_entity {
+                                    if child != item_entity {
                                         if let Ok(child_children) = children_query.get(child) {
                                             for toggle_entity in child_children.iter() {
                                                 if let Ok(mut toggle_state) =
@@ -93,91 +99,6 @@ pub fn handle_toggle_group_item_changes<A: Component + Clone + Send + Sync + 'st
     }
 }
 
-/// Handles keyboard navigation for toggle groups
-pub fn handle_toggle_group_keyboard_navigation(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
-    toggle_groups: Query<(&ToggleGroupState, &Children), With<ToggleGroupMarker>>,
-    toggle_items: Query<&ToggleGroupItemState, With<ToggleGroupItemMarker>>,
-    focused: Query<Entity, With<FocusState>>,
-    mut focus_events: EventWriter<FocusEvent>,
-) {
-    // Get currently focused entity
-    let focused_entity = if let Some(entity) = focused.iter().next() {
-        entity
-    } else {
-        return;
-    };
-
-    for (group_state, children) in toggle_groups.iter() {
-        if group_state.disabled || !group_state.roving_focus {
-            continue;
-        }
-
-        // Find if focused entity is part of this group
-        let mut found_index = None;
-        let mut active_items = Vec::new();
-
-        for (idx, child) in children.iter().enumerate() {
-            if let Ok(item_state) = toggle_items.get(child) {
-                if !item_state.disabled {
-                    active_items.push((idx, child));
-                    if child == focused_entity {
-                        found_index = Some(active_items.len() - 1);
-                    }
-                }
-            }
-        }
-
-        // If focused item is in this group, handle navigation
-        if let Some(current_idx) = found_index {
-            let nav_keys = match group_state.orientation {
-                ToggleGroupOrientation::Horizontal => [
-                    KeyCode::ArrowLeft,
-                    KeyCode::ArrowRight,
-                    KeyCode::Home,
-                    KeyCode::End,
-                ],
-                ToggleGroupOrientation::Vertical => [
-                    KeyCode::ArrowUp,
-                    KeyCode::ArrowDown,
-                    KeyCode::Home,
-                    KeyCode::End,
-                ],
-            };
-
-            let mut new_idx = current_idx;
-
-            if keyboard_input.just_pressed(nav_keys[0]) {
-                // Previous
-                if current_idx > 0 {
-                    new_idx = current_idx - 1;
-                } else if group_state.loop_navigation {
-                    new_idx = active_items.len() - 1;
-                }
-            } else if keyboard_input.just_pressed(nav_keys[1]) {
-                // Next
-                if current_idx < active_items.len() - 1 {
-                    new_idx = current_idx + 1;
-                } else if group_state.loop_navigation {
-                    new_idx = 0;
-                }
-            } else if keyboard_input.just_pressed(nav_keys[2]) {
-                // Home
-                new_idx = 0;
-            } else if keyboard_input.just_pressed(nav_keys[3]) {
-                // End
-                new_idx = active_items.len() - 1;
-            }
-
-            // If index changed, focus the new item
-            if new_idx != current_idx {
-                let (_, entity) = active_items[new_idx];
-                focus_events.write(FocusEvent::Focus { entity });
-            }
-        }
-    }
-}
-
 /// Updates the visual representation of toggle groups
 pub fn update_toggle_group_visuals(
     mut toggle_groups: Query<
@@ -186,16 +107,19 @@ pub fn update_toggle_group_visuals(
             &mut Node,
             &mut BorderColor,
             &mut BackgroundColor,
+            Option<&ResolvedDirection>,
         ),
         With<ToggleGroupMarker>,
     >,
     theme: Res<crate::theme::UiTheme>,
 ) {
-    for (state, mut style, mut border_color, mut bg_color) in toggle_groups.iter_mut() {
+    for (state, mut style, mut border_color, mut bg_color, dir) in toggle_groups.iter_mut() {
         let style_def = get_toggle_group_style_def(&theme, state.variant, state.size);
 
-        // Set direction based on orientation
+        // Set direction based on orientation, keeping the RTL mirror of the layout pass
+        let rtl = matches!(dir, Some(ResolvedDirection(ReadingDirection::Rtl)));
         style.flex_direction = match state.orientation {
+            ToggleGroupOrientation::Horizontal if rtl => FlexDirection::RowReverse,
             ToggleGroupOrientation::Horizontal => FlexDirection::Row,
             ToggleGroupOrientation::Vertical => FlexDirection::Column,
         };
@@ -243,27 +167,3 @@ pub fn sync_toggle_group_on_startup(
         }
     }
 }
-
-/// System to apply focus events
-pub fn apply_focus_events(
-    mut focus_events: EventReader<FocusEvent>,
-    mut commands: Commands,
-    focused_entities: Query<Entity, With<FocusState>>,
-) {
-    for event in focus_events.read() {
-        match event {
-            FocusEvent::Focus { entity } => {
-                // Remove focus from currently focused entities
-                for focused in focused_entities.iter() {
-                    commands.entity(focused).remove::<FocusState>();
-                }
-
-                // Apply focus to new entity
-                commands.entity(*entity).insert(FocusState);
-            }
-            FocusEvent::Blur { entity } => {
-                commands.entity(*entity).remove::<FocusState>();
-            }
-        }
-    }
-}
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 
 use crate::components::helper::UiBuilder;
+use crate::components::roving_focus::{RovingFocusGroup, RovingFocusOrientation};
 use crate::theme::UiTheme;
 
 use super::{ToolbarMarker, ToolbarOrientation, ToolbarState, style::ToolbarStyle};
@@ -8,12 +9,13 @@ use super::{ToolbarMarker, ToolbarOrientation, ToolbarState, style::ToolbarStyle
 /// Builder for a simple toolbar container.
 pub struct ToolbarBuilder {
     orientation: ToolbarOrientation,
+    loop_navigation: bool,
     items: Vec<Box<dyn FnOnce(&mut ChildSpawnerCommands, &UiTheme, &Handle<Font>) + Send + Sync>>, 
 }
 
 impl ToolbarBuilder {
     pub fn new() -> Self {
-        Self { orientation: ToolbarOrientation::Horizontal, items: Vec::new() }
+        Self { orientation: ToolbarOrientation::Horizontal, loop_navigation: true, items: Vec::new() }
     }
 
     /// Set the toolbar orientation.
@@ -22,6 +24,12 @@ impl ToolbarBuilder {
         self
     }
 
+    /// Whether arrow-key navigation wraps around at the ends (default: `true`).
+    pub fn loop_navigation(mut self, flag: bool) -> Self {
+        self.loop_navigation = flag;
+        self
+    }
+
     /// Add a custom child item using a closure.
     pub fn item<F>(mut self, f: F) -> Self
     where
@@ -40,6 +48,13 @@ impl<'w, 's> UiBuilder<'w, 's> for ToolbarBuilder {
             ToolbarMarker,
             ToolbarState { orientation: self.orientation },
             ToolbarStyle::new(self.orientation, theme),
+            RovingFocusGroup::new(
+                match self.orientation {
+                    ToolbarOrientation::Horizontal => RovingFocusOrientation::Horizontal,
+                    ToolbarOrientation::Vertical => RovingFocusOrientation::Vertical,
+                },
+                self.loop_navigation,
+            ),
             Name::new("Toolbar"),
         ));
 
@@ -2,8 +2,10 @@ mod builder;
 mod components;
 mod plugin;
 mod style;
+mod systems;
 
 pub use builder::*;
 pub use components::*;
 pub use plugin::*;
 pub use style::*;
+pub use systems::*;
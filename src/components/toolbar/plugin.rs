@@ -1,8 +1,19 @@
 use bevy::prelude::*;
 
-/// Plugin for the toolbar component. Currently no runtime systems are required.
+use super::systems::register_toolbar_items;
+use crate::components::roving_focus::sync_roving_tab_indices;
+use crate::plugin::UiState;
+
+/// Plugin for the toolbar component.
 pub struct ToolbarPlugin;
 
 impl Plugin for ToolbarPlugin {
-    fn build(&self, _app: &mut App) {}
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            register_toolbar_items
+                .before(sync_roving_tab_indices)
+                .run_if(in_state(UiState::Ready)),
+        );
+    }
 }
@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+
+use crate::components::roving_focus::{RovingFocusGroup, RovingFocusItem};
+
+use super::ToolbarMarker;
+
+/// Toolbar items are arbitrary closures, so the toolbar registers every
+/// interactive descendant as a [`RovingFocusItem`] once it is spawned.
+///
+/// Nested roving groups (e.g. a toggle group inside the toolbar) keep their
+/// own items.
+pub fn register_toolbar_items(
+    mut commands: Commands,
+    toolbars: Query<Entity, Added<ToolbarMarker>>,
+    children_q: Query<&Children>,
+    interactive: Query<(), (With<Interaction>, Without<RovingFocusItem>)>,
+    groups: Query<(), With<RovingFocusGroup>>,
+) {
+    fn walk(
+        entity: Entity,
+        commands: &mut Commands,
+        children_q: &Query<&Children>,
+        interactive: &Query<(), (With<Interaction>, Without<RovingFocusItem>)>,
+        groups: &Query<(), With<RovingFocusGroup>>,
+    ) {
+        let Ok(children) = children_q.get(entity) else {
+            return;
+        };
+        for child in children.iter() {
+            if groups.contains(child) {
+                continue;
+            }
+            if interactive.contains(child) {
+                commands.entity(child).insert(RovingFocusItem);
+                // The interactive node is the item; its content is not.
+                continue;
+            }
+            walk(child, commands, children_q, interactive, groups);
+        }
+    }
+
+    for toolbar in toolbars.iter() {
+        walk(toolbar, &mut commands, &children_q, &interactive, &groups);
+    }
+}
@@ -1,8 +1,13 @@
 use bevy::prelude::*;
 use bevy::ui::FocusPolicy;
 
+use crate::components::floating::{Floating, FloatingAlign, FloatingSide};
 use crate::components::helper::UiBuilder;
+use crate::components::hover_intent::HoverIntent;
 use crate::components::label::LabelBuilder;
+use crate::components::layer::{Layer, UiLayer};
+use crate::components::portal::Portal;
+use crate::components::transition::UiTransition;
 use crate::theme::UiTheme;
 
 use super::{
@@ -10,16 +15,60 @@ use super::{
     TooltipTriggerMarker,
 };
 
+/// Tooltips share one hover group, so moving between them skips the delay.
+const TOOLTIP_GROUP: &str = "tooltip";
+/// Seconds after a tooltip closed in which the next one opens instantly.
+const TOOLTIP_SKIP_DELAY: f32 = 0.3;
+
 /// Builder for a simple tooltip with text content.
+///
+/// The content floats above the trigger and flips below it when there is no
+/// room (see [`Floating`]). It opens after resting on the trigger for a moment;
+/// moving on to a neighbouring tooltip shows that one right away (see
+/// [`HoverIntent`]).
 pub struct TooltipBuilder {
     trigger: String,
     text: String,
+    side: FloatingSide,
+    align: FloatingAlign,
+    open_delay: f32,
+    close_delay: f32,
 }
 
 impl TooltipBuilder {
     /// Create a new tooltip with a trigger label and text content.
     pub fn new(trigger: impl Into<String>, text: impl Into<String>) -> Self {
-        Self { trigger: trigger.into(), text: text.into() }
+        Self {
+            trigger: trigger.into(),
+            text: text.into(),
+            side: FloatingSide::Top,
+            align: FloatingAlign::Center,
+            open_delay: 0.7,
+            close_delay: 0.0,
+        }
+    }
+
+    /// Preferred side of the trigger.
+    pub fn side(mut self, side: FloatingSide) -> Self {
+        self.side = side;
+        self
+    }
+
+    pub fn align(mut self, align: FloatingAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Seconds the pointer has to rest on the trigger (default `0.7`).
+    pub fn open_delay(mut self, secs: f32) -> Self {
+        self.open_delay = secs;
+        self
+    }
+
+    /// Seconds before closing once the pointer left (default `0.0`).
+    pub fn close_delay(mut self, secs: f32) -> Self {
+        self.close_delay = secs;
+        self
     }
 }
 
@@ -38,9 +87,10 @@ impl<'w, 's> UiBuilder<'w, 's> for TooltipBuilder {
             TooltipState { open: false },
             Name::new("Tooltip"),
         ));
+        let root = cmd.id();
 
         cmd.with_children(|cb| {
-            cb.spawn((
+            let trigger = cb.spawn((
                 TooltipTriggerMarker,
                 Node {
                     display: Display::Flex,
@@ -52,17 +102,29 @@ impl<'w, 's> UiBuilder<'w, 's> for TooltipBuilder {
             ))
             .with_children(|tc| {
                 let _ = LabelBuilder::new(self.trigger.clone()).spawn(tc, theme, font);
-            });
+            })
+            .id();
 
             let mut content = cb.spawn((
                 TooltipContentMarker,
                 TooltipContentStyle::new(theme),
+                Floating::new(trigger).side(self.side).align(self.align),
+                Portal::global(),
+                Layer(UiLayer::Tooltip),
                 Visibility::Hidden,
+                UiTransition::default().fade().scale(0.96),
             ));
 
             content.with_children(|cc| {
                 let _ = LabelBuilder::new(tooltip_text).spawn(cc, theme, font);
             });
+            let content = content.id();
+
+            let intent = HoverIntent::new(trigger, content)
+                .open_delay(self.open_delay)
+                .close_delay(self.close_delay)
+                .group(TOOLTIP_GROUP, TOOLTIP_SKIP_DELAY);
+            cb.commands_mut().entity(root).insert(intent);
         });
 
         cmd.id()
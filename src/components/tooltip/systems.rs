@@ -1,23 +1,21 @@
 use bevy::prelude::*;
 
-use super::{TooltipContentMarker, TooltipMarker, TooltipState, TooltipTriggerMarker};
+use super::{TooltipContentMarker, TooltipMarker, TooltipState};
+use crate::components::hover_intent::HoverIntent;
 
-/// Shows or hides tooltip content when hovering the trigger.
+/// Shows or hides tooltip content once its [`HoverIntent`] opens or closes.
 pub fn handle_tooltip_interaction(
-    mut root_q: Query<(&mut TooltipState, &Children), With<TooltipMarker>>,
-    trigger_q: Query<&Interaction, With<TooltipTriggerMarker>>,
+    mut root_q: Query<(&mut TooltipState, &HoverIntent), With<TooltipMarker>>,
     mut content_q: Query<&mut Visibility, With<TooltipContentMarker>>,
 ) {
-    for (mut state, children) in root_q.iter_mut() {
-        let mut open = state.open;
-        for child in children.iter() {
-            if let Ok(interaction) = trigger_q.get(child) {
-                open = *interaction == Interaction::Hovered;
-            }
-            if let Ok(mut vis) = content_q.get_mut(child) {
-                *vis = if open { Visibility::Inherited } else { Visibility::Hidden };
-            }
+    for (mut state, intent) in root_q.iter_mut() {
+        let open = intent.is_open();
+        if state.open == open {
+            continue;
         }
         state.open = open;
+        if let Ok(mut vis) = content_q.get_mut(intent.content) {
+            *vis = if open { Visibility::Inherited } else { Visibility::Hidden };
+        }
     }
 }
@@ -0,0 +1,153 @@
+use bevy::ecs::component::Tick;
+use bevy::prelude::*;
+
+/// Default duration of a UI transition in seconds.
+pub const DEFAULT_TRANSITION_SECS: f32 = 0.15;
+
+/// Visual effect applied while a [`UiTransition`] runs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransitionEffect {
+    /// Fades the element and its children in and out (background, border,
+    /// text and image colors).
+    Fade,
+    /// Scales the node uniformly from `from` to `1.0`.
+    Scale { from: f32 },
+    /// Expands the node vertically (e.g. an accordion body).
+    ScaleY,
+    /// Moves the node by `offset` px towards its resting position. The
+    /// offset is added to `left`/`top` and removed again at the end, so
+    /// positions set by the layout or other systems are kept. Axes with
+    /// other units than `Px` or `Auto` are not moved.
+    Slide { offset: Vec2 },
+}
+
+/// Animates showing and hiding an element.
+///
+/// Widgets keep toggling `Visibility` as before; the transition plays the
+/// configured effects and only hides the node once the close animation has
+/// finished. With `UiConfig::reduced_motion` set every transition completes
+/// instantly, so widgets never need their own check.
+///
+/// Elements with [`driven_by`](Self::driven_by) do not watch their own
+/// visibility but follow the open state of another transition (e.g. the
+/// overlay and content of a dialog follow the dialog root).
+#[derive(Component, Debug, Clone)]
+pub struct UiTransition {
+    pub duration: f32,
+    pub effects: Vec<TransitionEffect>,
+    pub easing: EaseFunction,
+    /// Plays the open animation when the element is spawned visible.
+    pub appear: bool,
+    pub(crate) driver: Option<Entity>,
+    pub(crate) progress: f32,
+    pub(crate) target: bool,
+    pub(crate) initialized: bool,
+    pub(crate) written_at: Option<Tick>,
+    /// Ursprüngliche Alpha-Werte der ausgeblendeten Entities, solange die
+    /// Transition nicht ganz offen ist.
+    pub(crate) fade_bases: Vec<(Entity, FadeBase)>,
+    /// Aktuell auf `left`/`top` addierter Versatz.
+    pub(crate) slide_applied: Vec2,
+    /// Achsen, die vor dem Verschieben `Val::Auto` waren.
+    pub(crate) slide_auto: BVec2,
+}
+
+/// Alpha values of one faded entity before the fade started.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct FadeBase {
+    pub background: Option<f32>,
+    pub border: Option<f32>,
+    pub text: Option<f32>,
+    pub image: Option<f32>,
+}
+
+impl Default for UiTransition {
+    fn default() -> Self {
+        Self {
+            duration: DEFAULT_TRANSITION_SECS,
+            effects: Vec::new(),
+            easing: EaseFunction::CubicOut,
+            appear: false,
+            driver: None,
+            progress: 0.0,
+            target: false,
+            initialized: false,
+            written_at: None,
+            fade_bases: Vec::new(),
+            slide_applied: Vec2::ZERO,
+            slide_auto: BVec2::FALSE,
+        }
+    }
+}
+
+impl UiTransition {
+    pub fn new(duration: f32) -> Self {
+        Self {
+            duration,
+            ..default()
+        }
+    }
+
+    pub fn with_effect(mut self, effect: TransitionEffect) -> Self {
+        self.effects.push(effect);
+        self
+    }
+
+    pub fn fade(self) -> Self {
+        self.with_effect(TransitionEffect::Fade)
+    }
+
+    pub fn scale(self, from: f32) -> Self {
+        self.with_effect(TransitionEffect::Scale { from })
+    }
+
+    pub fn expand(self) -> Self {
+        self.with_effect(TransitionEffect::ScaleY)
+    }
+
+    pub fn slide(self, offset: Vec2) -> Self {
+        self.with_effect(TransitionEffect::Slide { offset })
+    }
+
+    pub fn easing(mut self, easing: EaseFunction) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Plays the open animation once the element appears.
+    pub fn appear(mut self) -> Self {
+        self.appear = true;
+        self
+    }
+
+    /// Follows the open state of `driver` instead of the own `Visibility`.
+    pub fn driven_by(mut self, driver: Entity) -> Self {
+        self.driver = Some(driver);
+        self
+    }
+
+    /// Whether the element is currently shown or opening.
+    pub fn is_open(&self) -> bool {
+        self.target
+    }
+
+    /// Whether the transition is still running.
+    pub fn is_running(&self) -> bool {
+        self.progress != if self.target { 1.0 } else { 0.0 }
+    }
+
+    /// Restarts the open animation from the beginning.
+    pub fn replay(&mut self) {
+        self.target = true;
+        self.progress = 0.0;
+    }
+
+    /// Replaces the offset of all slide effects.
+    pub fn set_slide_offset(&mut self, offset: Vec2) {
+        for effect in self.effects.iter_mut() {
+            if let TransitionEffect::Slide { offset: o } = effect {
+                *o = offset;
+            }
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod components;
+mod plugin;
+mod systems;
+
+pub use components::*;
+pub use plugin::*;
+pub use systems::*;
@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+use bevy::render::view::VisibilitySystems;
+use bevy::ui::UiSystem;
+
+use super::systems::*;
+use crate::plugin::UiState;
+
+/// Plugin for the shared [`UiTransition`](super::UiTransition) animations.
+///
+/// Runs after the widget systems so that visibility changes made in `Update`
+/// are picked up in the same frame.
+pub struct TransitionPlugin;
+
+impl Plugin for TransitionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            advance_ui_transitions
+                .before(VisibilitySystems::VisibilityPropagate)
+                .before(UiSystem::Layout)
+                .run_if(in_state(UiState::Ready)),
+        );
+    }
+}
@@ -0,0 +1,257 @@
+use std::collections::{HashMap, HashSet};
+
+use bevy::ecs::system::SystemChangeTick;
+use bevy::math::curve::Curve;
+use bevy::prelude::*;
+
+use super::{FadeBase, TransitionEffect, UiTransition};
+use crate::components::direction_provider::{ReadingDirection, ResolvedDirection};
+use crate::plugin::UiConfig;
+
+type FadeColors<'a> = (
+    Option<&'a mut BackgroundColor>,
+    Option<&'a mut BorderColor>,
+    Option<&'a mut TextColor>,
+    Option<&'a mut ImageNode>,
+);
+
+/// Advances all [`UiTransition`]s and keeps closing elements visible until
+/// their animation has finished.
+pub fn advance_ui_transitions(
+    time: Res<Time>,
+    config: Res<UiConfig>,
+    ticks: SystemChangeTick,
+    mut query: Query<(
+        Entity,
+        &mut UiTransition,
+        &mut Visibility,
+        &mut Node,
+        &mut Transform,
+        Option<&ResolvedDirection>,
+    )>,
+    children_q: Query<&Children>,
+    mut colors: Query<FadeColors>,
+) {
+    let step = if config.reduced_motion {
+        None
+    } else {
+        Some(time.delta_secs())
+    };
+
+    // 1) Transitions, die der eigenen Sichtbarkeit folgen
+    let mut open_states: HashMap<Entity, bool> = HashMap::default();
+    let mut fading: Vec<Entity> = Vec::new();
+    for (entity, mut transition, mut vis, mut node, mut transform, dir) in query.iter_mut() {
+        if transition.driver.is_some() {
+            continue;
+        }
+
+        // Änderungen anderer Systeme von den eigenen Schreibzugriffen unterscheiden
+        let external = vis.is_changed() && transition.written_at != Some(vis.last_changed());
+        if !transition.initialized || external {
+            transition.target = *vis != Visibility::Hidden;
+        }
+
+        let changed = initialize(&mut transition);
+        if advance(&mut transition, step) || changed {
+            apply(&mut transition, &mut node, &mut transform, dir);
+            fading.push(entity);
+        }
+
+        let wanted = if transition.target || transition.progress > 0.0 {
+            match *vis {
+                Visibility::Hidden => Visibility::Inherited,
+                other => other,
+            }
+        } else {
+            Visibility::Hidden
+        };
+        if *vis != wanted {
+            *vis = wanted;
+            transition.written_at = Some(ticks.this_run());
+        }
+
+        open_states.insert(entity, transition.target);
+    }
+
+    // 2) Transitions, die einem anderen Element folgen
+    for (entity, mut transition, _, mut node, mut transform, dir) in query.iter_mut() {
+        let Some(driver) = transition.driver else {
+            continue;
+        };
+        if let Some(open) = open_states.get(&driver) {
+            transition.target = *open;
+        }
+
+        let changed = initialize(&mut transition);
+        if advance(&mut transition, step) || changed {
+            apply(&mut transition, &mut node, &mut transform, dir);
+            fading.push(entity);
+        }
+    }
+
+    // 3) Ausblenden inklusive Kinder; verschachtelte Fades regeln ihren Teilbaum selbst
+    let faders: HashSet<Entity> = query
+        .iter()
+        .filter(|(_, t, ..)| t.effects.contains(&TransitionEffect::Fade))
+        .map(|(entity, ..)| entity)
+        .collect();
+    for entity in fading {
+        if !faders.contains(&entity) {
+            continue;
+        }
+        let Ok((_, mut transition, ..)) = query.get_mut(entity) else {
+            continue;
+        };
+        fade_subtree(entity, &mut transition, &faders, &children_q, &mut colors);
+    }
+}
+
+/// Marks the transition as initialized on first sight. Returns `true` if
+/// this happened in this call.
+fn initialize(transition: &mut UiTransition) -> bool {
+    if transition.initialized {
+        return false;
+    }
+    transition.initialized = true;
+    transition.progress = if transition.target && !transition.appear {
+        1.0
+    } else {
+        0.0
+    };
+    true
+}
+
+/// Moves the progress towards the target. `None` completes immediately.
+fn advance(transition: &mut UiTransition, step: Option<f32>) -> bool {
+    let target = if transition.target { 1.0 } else { 0.0 };
+    if transition.progress == target {
+        return false;
+    }
+    transition.progress = match step {
+        Some(dt) if transition.duration > 0.0 => {
+            let delta = dt / transition.duration;
+            if target > transition.progress {
+                (transition.progress + delta).min(target)
+            } else {
+                (transition.progress - delta).max(target)
+            }
+        }
+        _ => target,
+    };
+    true
+}
+
+fn apply(
+    transition: &mut UiTransition,
+    node: &mut Node,
+    transform: &mut Transform,
+    dir: Option<&ResolvedDirection>,
+) {
+    let eased = transition.easing.sample_clamped(transition.progress);
+    let mut slide = Vec2::ZERO;
+    for effect in transition.effects.iter() {
+        match *effect {
+            TransitionEffect::Fade => {}
+            TransitionEffect::Scale { from } => {
+                let scale = from + (1.0 - from) * eased;
+                transform.scale = Vec3::new(scale, scale, 1.0);
+            }
+            TransitionEffect::ScaleY => {
+                transform.scale.y = eased;
+            }
+            TransitionEffect::Slide { offset } => {
+                slide += offset * (1.0 - eased);
+            }
+        }
+    }
+
+    // Nur den eigenen Versatz austauschen, die übrige Position bleibt erhalten.
+    // Unter RTL hat der Spiegel-Pass `left` nach `right` getauscht, der
+    // Versatz wandert mit und wird dort gespiegelt weitergeführt.
+    let applied = transition.slide_applied;
+    if slide != applied {
+        let rtl = matches!(dir, Some(ResolvedDirection(ReadingDirection::Rtl)));
+        let start = if rtl { &mut node.right } else { &mut node.left };
+        let (x, auto_x) = shift(*start, applied.x, slide.x, transition.slide_auto.x);
+        *start = x;
+        let (top, auto_y) = shift(node.top, applied.y, slide.y, transition.slide_auto.y);
+        node.top = top;
+        transition.slide_auto = BVec2::new(auto_x, auto_y);
+        transition.slide_applied = slide;
+    }
+}
+
+/// Replaces the offset `applied` in `val` by `wanted`. Returns the new value
+/// and whether it was `Auto` before the slide.
+fn shift(val: Val, applied: f32, wanted: f32, was_auto: bool) -> (Val, bool) {
+    match val {
+        Val::Px(_) if was_auto && wanted == 0.0 => (Val::Auto, false),
+        Val::Px(v) => (Val::Px(v - applied + wanted), was_auto),
+        Val::Auto if wanted != 0.0 => (Val::Px(wanted), true),
+        other => (other, was_auto),
+    }
+}
+
+/// Scales the alpha of `root` and its descendants with the eased progress.
+/// The original values are captured when the fade starts and restored once
+/// the element is fully shown.
+fn fade_subtree(
+    root: Entity,
+    transition: &mut UiTransition,
+    faders: &HashSet<Entity>,
+    children_q: &Query<&Children>,
+    colors: &mut Query<FadeColors>,
+) {
+    let shown = transition.progress >= 1.0;
+    let factor = if shown {
+        1.0
+    } else {
+        transition.easing.sample_clamped(transition.progress)
+    };
+    if shown && transition.fade_bases.is_empty() {
+        return;
+    }
+
+    if transition.fade_bases.is_empty() {
+        let mut stack = vec![root];
+        while let Some(entity) = stack.pop() {
+            if let Ok((bg, border, text, image)) = colors.get(entity) {
+                transition.fade_bases.push((
+                    entity,
+                    FadeBase {
+                        background: bg.map(|c| c.0.alpha()),
+                        border: border.map(|c| c.0.alpha()),
+                        text: text.map(|c| c.0.alpha()),
+                        image: image.map(|i| i.color.alpha()),
+                    },
+                ));
+            }
+            if let Ok(children) = children_q.get(entity) {
+                stack.extend(children.iter().filter(|c| !faders.contains(c)));
+            }
+        }
+    }
+
+    for (entity, base) in transition.fade_bases.iter() {
+        let Ok((bg, border, text, image)) = colors.get_mut(*entity) else {
+            continue;
+        };
+        if let (Some(mut c), Some(a)) = (bg, base.background) {
+            c.0.set_alpha(a * factor);
+        }
+        if let (Some(mut c), Some(a)) = (border, base.border) {
+            c.0.set_alpha(a * factor);
+        }
+        if let (Some(mut c), Some(a)) = (text, base.text) {
+            c.0.set_alpha(a * factor);
+        }
+        if let (Some(mut i), Some(a)) = (image, base.image) {
+            i.color.set_alpha(a * factor);
+        }
+    }
+
+    if shown {
+        transition.fade_bases.clear();
+    }
+}
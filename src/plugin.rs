@@ -7,11 +7,12 @@ use crate::assets::{FontAssets, IconAssets};
 use crate::camera::CameraPlugin;
 use crate::components::helper::*;
 use crate::components::{
-    accordion::*, alert_dialog::*, avatar::*, button::*, checkbox::*, checkbox_cards::*,
-    checkbox_group::*, dialog::*, hover_card::*, menubar::*, navigation_menu::*,
-    one_time_password_field::*, password_toggle_field::*, popover::*, portal::*,
-    radio::*, radio_cards::*, radio_group::*, select::*, switch::*, toast::*,
-    toggle::*, toggle_group::*, toolbar::*, tooltip::*,
+    accordion::*, alert_dialog::*, avatar::*, base_menu::*, button::*, checkbox::*, checkbox_cards::*,
+    checkbox_group::*, clipboard::*, code::*, combobox::*, context_menu::*, dialog::*, direction_provider::*, disabled::*, dismissable_layer::*, dropdown_menu::*, field::*, floating::*, form::*, hover_card::*, hover_intent::*, layer::*, menubar::*,
+    navigation_menu::*, number_field::*, one_time_password_field::*, password_toggle_field::*, popover::*, portal::*,
+    radio::*, radio_cards::*, radio_group::*, roving_focus::*, scroll_area::*, select::*,
+    shortcut::*, switch::*, text_area::*, text_field::*, toast::*, toggle::*, toggle_group::*,
+    toolbar::*, tooltip::*, transition::*,
 };
 use crate::theme::{
     check_theme_asset_readiness, hot_reload_theme_system, load_theme_asset, save_theme_system,
@@ -34,6 +35,8 @@ pub struct UiConfig {
     pub high_contrast: bool,
     pub scaling: f32,
     pub spacing_factor: f32,
+    /// Disables all UI transitions (dialogs, accordions, toasts, ...).
+    pub reduced_motion: bool,
 }
 
 impl Default for UiConfig {
@@ -44,6 +47,7 @@ impl Default for UiConfig {
             appearance: Appearance::Light,
             high_contrast: false,
             scaling: 1.0,
+            reduced_motion: false,
         }
     }
 }
@@ -63,6 +67,11 @@ impl ForgeUiPlugin {
         self.config.font_size_base = font_size_base;
         self
     }
+
+    pub fn with_reduced_motion(mut self, reduced_motion: bool) -> Self {
+        self.config.reduced_motion = reduced_motion;
+        self
+    }
 }
 
 impl Plugin for ForgeUiPlugin {
@@ -103,17 +112,30 @@ impl Plugin for ForgeUiPlugin {
                 .run_if(in_state(UiState::LoadingTheme)),
             )
             // endregion
+            .add_plugins(RovingFocusPlugin)
+            .add_plugins(DirectionProviderPlugin)
+            .add_plugins(ShortcutPlugin)
+            .add_plugins(DisabledPlugin)
+            .add_plugins(TransitionPlugin)
             .add_plugins(ButtonPlugin::<NoAction>::default())
             .add_plugins(DialogPlugin)
             .add_plugins(AlertDialogPlugin)
             .add_plugins(AccordionPlugin)
             .add_plugins(AvatarPlugin)
+            .add_plugins(FloatingPlugin)
             .add_plugins(HoverCardPlugin)
-            .add_plugins(MenubarPlugin)
-            .add_plugins(NavigationMenuPlugin)
             .add_plugins(PopoverPlugin)
             .add_plugins(SelectPlugin)
+            .add_plugins(ComboboxPlugin)
+            .add_plugins(ClipboardPlugin)
             .add_plugins(OneTimePasswordFieldPlugin)
+            .add_plugins(TextFieldPlugin)
+            .add_plugins(TextAreaPlugin)
+            .add_plugins(NumberFieldPlugin)
+            .add_plugins(FieldPlugin)
+            .add_plugins(FormPlugin)
+            .add_plugins(CodePlugin)
+            .add_plugins(ScrollAreaPlugin)
             .add_plugins(PasswordToggleFieldPlugin)
             .add_plugins(ToastPlugin)
             .add_plugins(ToolbarPlugin)
@@ -133,6 +155,7 @@ impl Plugin for ForgeUiPlugin {
                 (
                     handle_toggle_switch_clicks.run_if(in_state(UiState::Ready)),
                     update_toggle_switch_visuals.after(handle_toggle_switch_clicks),
+                    sync_disabled_flag::<SwitchState>.run_if(in_state(UiState::Ready)),
                 ),
             )
             // endregion --- Toggle Switches ---
@@ -144,19 +167,37 @@ impl Plugin for ForgeUiPlugin {
                     update_radio_visuals,
                     handle_radio_click,
                     update_radio_indicator,
+                    sync_disabled_flag::<RadioState>,
                 )
                     .run_if(in_state(UiState::Ready)), // Stelle sicher, dass dies korrekt ist für deine State-Logik
             )
             // endregion --- Radio Buttons ---
             // region: --- Portale ---
             .add_plugins(PortalPlugin)
-            // endregion --- UI-Systeme in Ready ---
-            // Debug: Save theme on S key
+            .add_plugins(LayerPlugin)
+            .add_plugins(DismissableLayerPlugin)
+            .add_plugins(HoverIntentPlugin)
+            // endregion --- Portale ---
+            // region: --- Menüs ---
+            .add_plugins(BaseMenuPlugin)
+            .add_plugins(ContextMenuPlugin)
+            .add_plugins(ContextMenuActionPlugin::<NoAction>::default())
+            .add_plugins(DropdownMenuPlugin)
+            .add_plugins(DropdownMenuActionPlugin::<NoAction>::default())
+            .add_plugins(MenubarPlugin)
+            .add_plugins(MenubarActionPlugin::<NoAction>::default())
+            .add_plugins(NavigationMenuPlugin)
+            .add_plugins(NavigationMenuActionPlugin::<NoAction>::default())
+            // endregion --- Menüs ---
+            // Debug: Save theme on S key (nicht beim Tippen in Textfelder)
             .add_systems(
                 Update,
-                save_theme_system.run_if(in_state(UiState::Ready)).run_if(
-                    bevy::input::common_conditions::input_just_pressed(KeyCode::KeyS),
-                ),
+                save_theme_system
+                    .run_if(in_state(UiState::Ready))
+                    .run_if(bevy::input::common_conditions::input_just_pressed(
+                        KeyCode::KeyS,
+                    ))
+                    .run_if(not_typing),
             )
             // 6) HotReload cycle: detect & trigger in Ready, process in HotReload
             .add_systems(
@@ -1,11 +1,11 @@
 pub use crate::assets::*;
 pub use crate::components::helper::*;
 pub use crate::components::{
-    badge::*, button::*, accordion::*, checkbox::*, checkbox_cards::*, checkbox_group::*, radio_cards::*, dialog::*, label::*, portal::*, radio::*,
-    radio_group::*, switch::*, toggle::*, toggle_group::*, alert_dialog::*, avatar::*, blockquote::*, card::*, ui_box::*, callout::*, code::*, collapsible::*,
-    collection::*, container::*, context::*, context_menu::*, dropdown_menu::*, hover_card::*, menubar::*, navigation_menu::*, popover::*, progress::*, scroll_area::*, select::*, separator::*, slider::*, one_time_password_field::*, password_toggle_field::*, visually_hidden::*, accessible_icon::*, base_button::*, base_card::*,
-    direction_provider::*, slot::*,
-    base_checkbox::*, base_dialog::*, base_menu::*, base_radio::*, base_tab_list::*, aspect_ratio::*, tabs::*, toast::*, toolbar::*, tooltip::*,
+    badge::*, button::*, accordion::*, checkbox::*, checkbox_cards::*, checkbox_group::*, radio_cards::*, dialog::*, label::*, layer::*, portal::*, radio::*,
+    radio_group::*, roving_focus::*, switch::*, toggle::*, toggle_group::*, alert_dialog::*, avatar::*, blockquote::*, card::*, ui_box::*, callout::*, clipboard::*, code::*, collapsible::*,
+    collection::*, combobox::*, container::*, context::*, context_menu::*, dropdown_menu::*, hover_card::*, hover_intent::*, menubar::*, navigation_menu::*, popover::*, progress::*, scroll_area::*, select::*, separator::*, shortcut::*, slider::*, one_time_password_field::*, password_toggle_field::*, visually_hidden::*, accessible_icon::*, base_button::*, base_card::*,
+    direction_provider::*, disabled::*, dismissable_layer::*, slot::*,
+    base_checkbox::*, base_dialog::*, base_menu::*, base_radio::*, base_tab_list::*, aspect_ratio::*, tabs::*, text_area::*, text_field::*, number_field::*, field::*, floating::*, form::*, toast::*, toolbar::*, tooltip::*, transition::*,
 };
 pub use crate::layout::*;
 pub use crate::plugin::{ForgeUiPlugin, UiState};
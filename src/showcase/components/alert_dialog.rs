@@ -16,6 +16,6 @@ pub fn show_alert_dialog_example(
         let _ = builder.spawn_trigger(vc, theme, font, "Open Alert");
 
         let mut cmds = vc.commands_mut();
-        let _ = builder.spawn(&mut cmds, theme, font, None);
+        let _ = builder.spawn(&mut cmds, theme, font);
     });
 }
@@ -38,6 +38,16 @@ pub fn show_button_variants(
                 .variant(ButtonVariant::Ghost)
                 .build(&theme, font),
         );
+
+        // Button mit Tastenkürzel (Alt+F)
+        vc.spawn((
+            ButtonBuilder::<NoAction>::new("Shortcut")
+                .text("Find (Alt+F)")
+                .variant(ButtonVariant::Outline)
+                .action(NoAction)
+                .build(&theme, font),
+            Shortcut(Accelerator::alt(KeyCode::KeyF)),
+        ));
     });
 
     // Button Sizes
@@ -0,0 +1,32 @@
+use super::super::helpers::*;
+use crate::prelude::*;
+use bevy::prelude::*;
+
+pub fn show_clipboard_example(
+    parent: &mut ChildSpawnerCommands,
+    theme: &UiTheme,
+    font: &Handle<Font>,
+) {
+    let mut section = create_variant_section(parent, "Selectable Label", theme, font);
+    section.with_children(|vc| {
+        let _ = LabelBuilder::new("Markieren mit der Maus, kopieren mit Strg+C.")
+            .selectable(true)
+            .spawn(vc, theme, font);
+    });
+
+    let mut section = create_variant_section(parent, "Code with Copy Button", theme, font);
+    section.with_children(|vc| {
+        let _ = CodeBuilder::new("cargo add forge_ui")
+            .selectable(true)
+            .copy_button(true)
+            .spawn(vc, theme, font);
+    });
+
+    let mut section = create_variant_section(parent, "Paste Target", theme, font);
+    section.with_children(|vc| {
+        let _ = TextFieldBuilder::new()
+            .placeholder("Strg+V fügt hier ein")
+            .width(Val::Px(240.0))
+            .spawn(vc, theme, font);
+    });
+}
@@ -0,0 +1,35 @@
+use super::super::helpers::*;
+use crate::prelude::*;
+use bevy::prelude::*;
+
+pub fn show_combobox_example(
+    parent: &mut ChildSpawnerCommands,
+    theme: &UiTheme,
+    font: &Handle<Font>,
+) {
+    let mut section = create_variant_section(parent, "Fuzzy", theme, font);
+    section.with_children(|vc| {
+        let _ = ComboboxBuilder::new()
+            .option("rust", "Rust")
+            .option("go", "Go")
+            .option("typescript", "TypeScript")
+            .option("javascript", "JavaScript")
+            .option("python", "Python")
+            .placeholder("Sprache suchen...")
+            .width(Val::Px(240.0))
+            .spawn(vc, theme, font);
+    });
+
+    let mut section = create_variant_section(parent, "Prefix with Create", theme, font);
+    section.with_children(|vc| {
+        let _ = ComboboxBuilder::new()
+            .option("bug", "Bug")
+            .option("feature", "Feature")
+            .option("docs", "Documentation")
+            .selected("feature")
+            .filter(ComboboxFilter::Prefix)
+            .allow_create(true)
+            .width(Val::Px(240.0))
+            .spawn(vc, theme, font);
+    });
+}
@@ -5,10 +5,49 @@ use bevy::prelude::*;
 pub fn show_context_menu_example(parent: &mut ChildSpawnerCommands, theme: &UiTheme, font: &Handle<Font>) {
     let mut section = create_variant_section(parent, "Context Menu", theme, font);
     section.with_children(|vc| {
-        let _ = ContextMenuBuilder::new()
-            .item("First")
-            .item("Second")
-            .item("Third")
+        let area = vc
+            .spawn((
+                Node {
+                    width: Val::Px(320.0),
+                    height: Val::Px(160.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(1.0)),
+                    ..default()
+                },
+                BorderColor(theme.color.gray.step07),
+                BorderRadius::all(Val::Px(theme.layout.radius.sm)),
+                Interaction::default(),
+            ))
+            .with_children(|ac| {
+                let _ = LabelBuilder::new("Right-click or long-press here")
+                    .color(theme.color.gray.step10)
+                    .spawn(ac, theme, font);
+            })
+            .id();
+
+        let _ = ContextMenuBuilder::<NoAction>::new()
+            .label("Edit")
+            .item("Back")
+            .item("Forward")
+            .disabled()
+            .item("Reload")
+            .submenu(
+                "More Tools",
+                MenuItems::new()
+                    .item("Save Page As…")
+                    .item("Create Shortcut…")
+                    .separator()
+                    .item("Developer Tools"),
+            )
+            .separator()
+            .checkbox("Show Bookmarks Bar", true)
+            .checkbox("Show Full URLs", false)
+            .separator()
+            .label("People")
+            .radio("person", "Pedro Duarte", true)
+            .radio("person", "Colm Tuite", false)
+            .trigger(area)
             .spawn(vc, theme, font);
     });
 }
@@ -12,6 +12,7 @@ pub fn show_dialog_variants(
 
     dialog_section.with_children(|vc| {
         let dialog_id = DialogId::new_unique();
+        let nested_id = DialogId::new_unique();
 
         let _ = DialogTriggerBuilder::new(dialog_id)
             .text("Open Dialog")
@@ -33,6 +34,14 @@ pub fn show_dialog_variants(
             }))
             .footer(
                 DialogFooterBuilder::new().add_custom_content(move |p, theme, font| {
+                    // Öffnet einen zweiten Dialog über diesem
+                    p.spawn(
+                        ButtonBuilder::<DialogAction>::new("Nested Dialog Button")
+                            .text("Open Nested")
+                            .variant(ButtonVariant::Outline)
+                            .action(DialogAction::Open(nested_id))
+                            .build(theme, font),
+                    );
                     p.spawn(
                         ButtonBuilder::<DialogAction>::new("Close Button")
                             .text("Close")
@@ -43,12 +52,81 @@ pub fn show_dialog_variants(
                 }),
             );
 
+        let nested_content = DialogContentBuilder::new()
+            .header(DialogHeaderBuilder::new().title("Nested Dialog"))
+            .body(DialogBodyBuilder::new().add_content(|p, theme, font| {
+                p.spawn((
+                    Text::new("Escape closes only this dialog."),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: theme.font.size.base,
+                        ..default()
+                    },
+                    TextColor(theme.color.slate.step12),
+                ));
+            }))
+            .footer(
+                DialogFooterBuilder::new().add_custom_content(move |p, theme, font| {
+                    p.spawn(
+                        ButtonBuilder::<DialogAction>::new("Nested Close Button")
+                            .text("Close")
+                            .variant(ButtonVariant::Soft)
+                            .action(DialogAction::Close(nested_id))
+                            .build(theme, font),
+                    );
+                }),
+            );
+
         let mut cmds = vc.commands_mut();
         let _ = DialogBuilder::new(dialog_id)
             .content(content)
-            .spawn(&mut cmds, theme, font, None);
+            .spawn(&mut cmds, theme, font);
+        let _ = DialogBuilder::new(nested_id)
+            .width(Val::Px(380.0))
+            .content(nested_content)
+            .spawn(&mut cmds, theme, font);
     });
 
-    // Optional: weitere Trigger-Beispiele
-    let _triggers_section = create_variant_section(parent, "Dialog Triggers", theme, font);
+    let mut triggers_section = create_variant_section(parent, "Dialog Triggers", theme, font);
+
+    triggers_section.with_children(|vc| {
+        let panel_id = DialogId::new_unique();
+
+        let _ = DialogTriggerBuilder::new(panel_id)
+            .text("Open Non-Modal Dialog")
+            .variant(ButtonVariant::Outline)
+            .spawn(vc, theme, font);
+
+        let content = DialogContentBuilder::new()
+            .header(DialogHeaderBuilder::new().title("Non-Modal Dialog"))
+            .body(DialogBodyBuilder::new().add_content(|p, theme, font| {
+                p.spawn((
+                    Text::new("The page behind stays interactive."),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: theme.font.size.base,
+                        ..default()
+                    },
+                    TextColor(theme.color.slate.step12),
+                ));
+            }))
+            .footer(
+                DialogFooterBuilder::new().add_custom_content(move |p, theme, font| {
+                    p.spawn(
+                        ButtonBuilder::<DialogAction>::new("Non-Modal Close Button")
+                            .text("Close")
+                            .variant(ButtonVariant::Soft)
+                            .action(DialogAction::Close(panel_id))
+                            .build(theme, font),
+                    );
+                }),
+            );
+
+        let mut cmds = vc.commands_mut();
+        let _ = DialogBuilder::new(panel_id)
+            .modal(false)
+            .width(Val::Px(320.0))
+            .content(content)
+            .spawn(&mut cmds, theme, font);
+    });
 }
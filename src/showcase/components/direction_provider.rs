@@ -5,14 +5,29 @@ use bevy::prelude::*;
 pub fn show_direction_provider_example(parent: &mut ChildSpawnerCommands, theme: &UiTheme, font: &Handle<Font>) {
     let mut section = create_variant_section(parent, "Direction Provider", theme, font);
     section.with_children(|vc| {
-        let _ = DirectionProviderBuilder::new(ReadingDirection::Rtl)
-            .content(|p, dir, t, f| {
-                p.spawn((
-                    Text::new(format!("Direction: {:?}", dir)),
-                    TextFont { font: f.clone(), font_size: t.font.size.base, ..default() },
-                    TextColor(t.color.slate.step12),
-                ));
-            })
-            .spawn(vc, theme, font);
+        for dir in [ReadingDirection::Ltr, ReadingDirection::Rtl] {
+            let _ = DirectionProviderBuilder::new(dir)
+                .content(|p, dir, t, f| {
+                    let label_font = f.clone();
+                    let label_size = t.font.size.base;
+                    let label_color = t.color.slate.step12;
+                    let _ = HorizontalStackBuilder::new()
+                        .gap(Val::Px(t.layout.gap.base))
+                        .padding_rect(UiRect::left(Val::Px(t.layout.padding.lg)))
+                        .add_fn(move |hc| {
+                            for text in [format!("Direction: {:?}", dir), "1".into(), "2".into(), "3".into()] {
+                                hc.spawn((
+                                    Text::new(text),
+                                    TextFont { font: label_font.clone(), font_size: label_size, ..default() },
+                                    TextColor(label_color),
+                                ));
+                            }
+                        })
+                        .spawn(p);
+                    let _ = SliderBuilder::new().value(0.3).spawn(p, t, f);
+                    let _ = SwitchBuilder::new().checked(true).spawn(p, t);
+                })
+                .spawn(vc, theme, font);
+        }
     });
 }
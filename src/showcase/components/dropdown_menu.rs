@@ -5,10 +5,35 @@ use bevy::prelude::*;
 pub fn show_dropdown_menu_example(parent: &mut ChildSpawnerCommands, theme: &UiTheme, font: &Handle<Font>) {
     let mut section = create_variant_section(parent, "Dropdown Menu", theme, font);
     section.with_children(|vc| {
-        let _ = DropdownMenuBuilder::new("Options")
-            .item("First")
-            .item("Second")
-            .item("Third")
+        let _ = DropdownMenuBuilder::<NoAction>::new("Options")
+            .item("New Tab")
+            .item("New Window")
+            .item("New Private Window")
+            .disabled()
+            .submenu(
+                "More Tools",
+                MenuItems::new()
+                    .item("Save Page As…")
+                    .item("Create Shortcut…")
+                    .item("Name Window…")
+                    .separator()
+                    .item("Developer Tools"),
+            )
+            .separator()
+            .checkbox("Show Bookmarks", true)
+            .checkbox("Show Full URLs", false)
+            .separator()
+            .label("People")
+            .radio("person", "Pedro Duarte", true)
+            .radio("person", "Colm Tuite", false)
+            .spawn(vc, theme, font);
+
+        let _ = DropdownMenuBuilder::<NoAction>::new("Columns (stays open)")
+            .close_on_select(false)
+            .checkbox("Name", true)
+            .checkbox("Size", true)
+            .checkbox("Modified", false)
+            .checkbox("Kind", false)
             .spawn(vc, theme, font);
     });
 }
@@ -0,0 +1,31 @@
+use super::super::helpers::*;
+use crate::prelude::*;
+use bevy::prelude::*;
+
+pub fn show_field_example(parent: &mut ChildSpawnerCommands, theme: &UiTheme, font: &Handle<Font>) {
+    let mut section = create_variant_section(parent, "Field", theme, font);
+    section.with_children(|vc| {
+        let _ = FieldBuilder::new("E-Mail", |p, theme, font| {
+            TextFieldBuilder::new()
+                .placeholder("name@example.com")
+                .width(Val::Px(240.0))
+                .spawn(p, theme, font)
+        })
+        .description("Wir geben deine Adresse nicht weiter.")
+        .required(true)
+        .spawn(vc, theme, font);
+
+        let _ = FieldBuilder::new("Benachrichtigungen", |p, theme, _font| {
+            SwitchBuilder::new().spawn(p, theme).id()
+        })
+        .description("Klick auf das Label schaltet den Switch um.")
+        .inline()
+        .spawn(vc, theme, font);
+
+        let _ = FieldBuilder::new("Anzahl", |p, theme, font| {
+            NumberFieldBuilder::new().value(0.0).spawn(p, theme, font)
+        })
+        .error("Mindestens 1 Exemplar auswählen.")
+        .spawn(vc, theme, font);
+    });
+}
@@ -0,0 +1,52 @@
+use super::super::helpers::*;
+use crate::prelude::*;
+use bevy::prelude::*;
+
+pub fn show_form_example(parent: &mut ChildSpawnerCommands, theme: &UiTheme, font: &Handle<Font>) {
+    let mut section = create_variant_section(parent, "Form", theme, font);
+    section.with_children(|vc| {
+        let _ = FormBuilder::new()
+            .width(Val::Px(320.0))
+            .field(
+                FormFieldDef::new("name", |p, theme, font| {
+                    TextFieldBuilder::new()
+                        .placeholder("Ada Lovelace")
+                        .width(Val::Percent(100.0))
+                        .spawn(p, theme, font)
+                })
+                .label("Name")
+                .required()
+                .validator(FormValidator::min_length(2)),
+            )
+            .field(
+                FormFieldDef::new("age", |p, theme, font| {
+                    NumberFieldBuilder::new()
+                        .value(30.0)
+                        .min(0.0)
+                        .max(130.0)
+                        .spawn(p, theme, font)
+                })
+                .label("Alter")
+                .validator(FormValidator::range(18.0, 130.0)),
+            )
+            .field(
+                FormFieldDef::new("plan", |p, theme, font| {
+                    SelectBuilder::new()
+                        .option("free", "Free")
+                        .option("pro", "Pro")
+                        .spawn(p, theme, font)
+                })
+                .label("Tarif")
+                .required(),
+            )
+            .field(
+                FormFieldDef::new("newsletter", |p, theme, _font| {
+                    SwitchBuilder::new().spawn(p, theme).id()
+                })
+                .label("Newsletter")
+                .inline(),
+            )
+            .submit_label("Speichern")
+            .spawn(vc, theme, font);
+    });
+}
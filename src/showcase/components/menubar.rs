@@ -9,14 +9,41 @@ pub fn show_menubar_example(
 ) {
     let mut section = create_variant_section(parent, "Menubar", theme, font);
     section.with_children(|vc| {
-        MenubarBuilder::new()
+        MenubarBuilder::<NoAction>::new()
             .menu(
                 MenubarMenuBuilder::new("File")
-                    .item("New")
-                    .item("Open")
-                    .item("Save"),
+                    .item_with_shortcut("New", Accelerator::primary(KeyCode::KeyN))
+                    .item_with_shortcut("Open", Accelerator::primary(KeyCode::KeyO))
+                    .submenu(
+                        "Open Recent",
+                        MenuItems::new()
+                            .item("level_01.ron")
+                            .item("level_02.ron")
+                            .item("sandbox.ron"),
+                    )
+                    .separator()
+                    .item_with_shortcut("Save", Accelerator::primary(KeyCode::KeyS))
+                    .item("Export")
+                    .disabled(),
+            )
+            .menu(
+                MenubarMenuBuilder::new("Edit")
+                    .item_with_shortcut("Undo", Accelerator::primary(KeyCode::KeyZ))
+                    .item_with_shortcut("Redo", Accelerator::primary(KeyCode::KeyZ).with_shift())
+                    .separator()
+                    .item("Cut")
+                    .item("Copy")
+                    .item("Paste"),
+            )
+            .menu(
+                MenubarMenuBuilder::new("View")
+                    .checkbox("Show Grid", true)
+                    .checkbox("Show Gizmos", false)
+                    .separator()
+                    .label("Camera")
+                    .radio("camera", "Perspective", true)
+                    .radio("camera", "Orthographic", false),
             )
-            .menu(MenubarMenuBuilder::new("Edit").item("Undo").item("Redo"))
             .spawn(vc, theme, font);
     });
 }
@@ -45,6 +45,13 @@ mod separator;
 mod slider;
 mod one_time_password_field;
 mod password_toggle_field;
+mod text_area;
+mod number_field;
+mod form;
+mod field;
+mod clipboard;
+mod combobox;
+mod text_field;
 mod visually_hidden;
 mod accessible_icon;
 mod aspect_ratio;
@@ -99,6 +106,13 @@ pub use separator::*;
 pub use slider::*;
 pub use one_time_password_field::*;
 pub use password_toggle_field::*;
+pub use text_area::*;
+pub use number_field::*;
+pub use form::*;
+pub use field::*;
+pub use clipboard::*;
+pub use combobox::*;
+pub use text_field::*;
 pub use visually_hidden::*;
 pub use accessible_icon::*;
 pub use aspect_ratio::*;
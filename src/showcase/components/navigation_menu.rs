@@ -9,17 +9,27 @@ pub fn show_navigation_menu_example(
 ) {
     let mut section = create_variant_section(parent, "NavigationMenu", theme, font);
     section.with_children(|vc| {
-        NavigationMenuBuilder::new()
+        NavigationMenuBuilder::<NoAction>::new()
             .item(
-                NavigationMenuItemBuilder::new("Products")
-                    .link("Item 1")
-                    .link("Item 2"),
+                NavigationMenuItemBuilder::new("Campaign")
+                    .link("Continue")
+                    .link("New Game")
+                    .link("Load Game"),
             )
             .item(
-                NavigationMenuItemBuilder::new("About")
-                    .link("Team")
-                    .link("Contact"),
+                NavigationMenuItemBuilder::new("Stories")
+                    .link("The Lighthouse Keeper")
+                    .link("A Winter in the Northern Provinces")
+                    .link("Short Tales")
+                    .link("Community Stories")
+                    .link("Workshop"),
             )
+            .item(
+                NavigationMenuItemBuilder::new("Settings")
+                    .link("Graphics")
+                    .link("Audio"),
+            )
+            .item(NavigationMenuItemBuilder::new("Credits"))
             .spawn(vc, theme, font);
     });
 }
@@ -0,0 +1,32 @@
+use super::super::helpers::*;
+use crate::prelude::*;
+use bevy::prelude::*;
+
+pub fn show_number_field_example(
+    parent: &mut ChildSpawnerCommands,
+    theme: &UiTheme,
+    font: &Handle<Font>,
+) {
+    let mut section = create_variant_section(parent, "Number Field", theme, font);
+    section.with_children(|vc| {
+        let _ = NumberFieldBuilder::new()
+            .label("Menge")
+            .value(3.0)
+            .min(0.0)
+            .max(99.0)
+            .spawn(vc, theme, font);
+        let _ = NumberFieldBuilder::new()
+            .label("Deckkraft")
+            .value(0.5)
+            .min(0.0)
+            .max(1.0)
+            .step(0.05)
+            .precision(2)
+            .width(Val::Px(96.0))
+            .spawn(vc, theme, font);
+        let _ = NumberFieldBuilder::new()
+            .value(42.0)
+            .disabled(true)
+            .spawn(vc, theme, font);
+    });
+}
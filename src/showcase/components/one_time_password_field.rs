@@ -6,5 +6,9 @@ pub fn show_one_time_password_field_example(parent: &mut ChildSpawnerCommands, t
     let mut section = create_variant_section(parent, "One Time Password Field", theme, font);
     section.with_children(|vc| {
         let _ = OneTimePasswordFieldBuilder::new().spawn(vc, theme, font);
+        let _ = OneTimePasswordFieldBuilder::new()
+            .length(4)
+            .mode(OtpMode::Alphanumeric)
+            .spawn(vc, theme, font);
     });
 }
@@ -2,10 +2,32 @@ use super::super::helpers::*;
 use crate::prelude::*;
 use bevy::prelude::*;
 
-pub fn show_password_toggle_field_example(parent: &mut ChildSpawnerCommands, theme: &UiTheme, font: &Handle<Font>) {
+pub fn show_password_toggle_field_example(
+    parent: &mut ChildSpawnerCommands,
+    theme: &UiTheme,
+    font: &Handle<Font>,
+    icons: &Res<IconAssets>,
+) {
     let mut section = create_variant_section(parent, "Password Toggle Field", theme, font);
+    let eye_open = icons
+        .0
+        .get("eye-open")
+        .expect("missing 'eye-open' icon")
+        .clone();
+    let eye_closed = icons
+        .0
+        .get("eye-closed")
+        .expect("missing 'eye-closed' icon")
+        .clone();
+
     section.with_children(|vc| {
-        let _ = PasswordToggleFieldBuilder::new().spawn(vc, theme, font);
+        let _ = PasswordToggleFieldBuilder::new()
+            .placeholder("Passwort")
+            .icons(eye_open, eye_closed)
+            .spawn(vc, theme, font);
+        let _ = PasswordToggleFieldBuilder::new()
+            .password("hunter2")
+            .visible(true)
+            .spawn(vc, theme, font);
     });
 }
-
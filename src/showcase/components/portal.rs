@@ -6,7 +6,6 @@ pub fn show_portal_example(
     parent: &mut ChildSpawnerCommands,
     theme: &UiTheme,
     font: &Handle<Font>,
-    portal_root: Res<ForgeUiPortalRoot>,
 ) {
     let mut section = create_variant_section(parent, "Portal Example", theme, font);
 
@@ -20,31 +19,74 @@ pub fn show_portal_example(
             },
             TextColor(theme.color.slate.step12),
         ));
-    });
 
-    PortalContentBuilder::spawn_in_global_root(parent, portal_root, theme, font, |p, t, f| {
-        p.spawn((
+        // Der Container schneidet ab, der portierte Inhalt bleibt trotzdem sichtbar
+        vc.spawn((
             Node {
-                position_type: PositionType::Absolute,
-                top: Val::Px(20.0),
-                left: Val::Px(20.0),
-                padding: UiRect::all(Val::Px(12.0)),
+                width: Val::Px(120.0),
+                height: Val::Px(40.0),
+                overflow: Overflow::clip(),
                 ..default()
             },
-            BackgroundColor(t.color.tomato.step06),
-            Name::new("Portal Overlay"),
+            BackgroundColor(theme.color.slate.step03),
+            Name::new("Clipping Container"),
         ))
-        .with_children(|c| {
-            c.spawn((
-                Text::new("I'm in the portal"),
-                TextFont {
-                    font: f.clone(),
-                    font_size: t.font.size.base,
-                    ..default()
-                },
-                TextColor(t.color.slate.step12),
-            ));
+        .with_children(|clip| {
+            PortalContentBuilder::spawn_in_global_root(clip, theme, font, |p, t, f| {
+                p.spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        top: Val::Px(20.0),
+                        left: Val::Px(20.0),
+                        padding: UiRect::all(Val::Px(12.0)),
+                        ..default()
+                    },
+                    BackgroundColor(t.color.tomato.step06),
+                    Name::new("Portal Overlay"),
+                ))
+                .with_children(|c| {
+                    c.spawn((
+                        Text::new("I'm in the portal"),
+                        TextFont {
+                            font: f.clone(),
+                            font_size: t.font.size.base,
+                            ..default()
+                        },
+                        TextColor(t.color.slate.step12),
+                    ));
+                });
+            });
         });
     });
-}
 
+    let mut section = create_variant_section(parent, "Named Target", theme, font);
+    section.with_children(|vc| {
+        vc.spawn((
+            Node {
+                padding: UiRect::all(Val::Px(theme.layout.padding.base)),
+                ..default()
+            },
+            BackgroundColor(theme.color.slate.step03),
+            PortalTarget("showcase-portal-target".into()),
+            Name::new("Portal Target"),
+        ));
+
+        PortalContentBuilder::spawn(
+            vc,
+            Portal::to("showcase-portal-target"),
+            theme,
+            font,
+            |p, t, f| {
+                p.spawn((
+                    Text::new("Rendered into the named target above."),
+                    TextFont {
+                        font: f.clone(),
+                        font_size: t.font.size.base,
+                        ..default()
+                    },
+                    TextColor(t.color.slate.step12),
+                ));
+            },
+        );
+    });
+}
@@ -0,0 +1,22 @@
+use super::super::helpers::*;
+use crate::prelude::*;
+use bevy::prelude::*;
+
+pub fn show_text_area_example(
+    parent: &mut ChildSpawnerCommands,
+    theme: &UiTheme,
+    font: &Handle<Font>,
+) {
+    let mut section = create_variant_section(parent, "Text Area", theme, font);
+    section.with_children(|vc| {
+        let _ = TextAreaBuilder::new()
+            .placeholder("Tagebucheintrag…")
+            .rows(5)
+            .spawn(vc, theme, font);
+        let _ = TextAreaBuilder::new()
+            .placeholder("Wächst bis 160 px mit")
+            .rows(2)
+            .auto_grow(160.0)
+            .spawn(vc, theme, font);
+    });
+}
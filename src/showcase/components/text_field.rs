@@ -0,0 +1,27 @@
+use super::super::helpers::*;
+use crate::prelude::*;
+use bevy::prelude::*;
+
+pub fn show_text_field_example(
+    parent: &mut ChildSpawnerCommands,
+    theme: &UiTheme,
+    font: &Handle<Font>,
+) {
+    let mut section = create_variant_section(parent, "Text Field", theme, font);
+    section.with_children(|vc| {
+        let _ = TextFieldBuilder::new()
+            .placeholder("Name eingeben…")
+            .width(Val::Px(240.0))
+            .spawn(vc, theme, font);
+        let _ = TextFieldBuilder::new()
+            .value("Max. 8 Zeichen")
+            .max_length(8)
+            .width(Val::Px(240.0))
+            .spawn(vc, theme, font);
+        let _ = TextFieldBuilder::new()
+            .value("Deaktiviert")
+            .disabled(true)
+            .width(Val::Px(240.0))
+            .spawn(vc, theme, font);
+    });
+}
@@ -7,4 +7,18 @@ pub fn show_tooltip_example(parent: &mut ChildSpawnerCommands, theme: &UiTheme,
     section.with_children(|vc| {
         let _ = TooltipBuilder::new("Hover me", "Tooltip text").spawn(vc, theme, font);
     });
+
+    let mut section = create_variant_section(parent, "Sides", theme, font);
+    section.with_children(|vc| {
+        for (label, side) in [
+            ("Top", FloatingSide::Top),
+            ("Right", FloatingSide::Right),
+            ("Bottom", FloatingSide::Bottom),
+            ("Left", FloatingSide::Left),
+        ] {
+            let _ = TooltipBuilder::new(label, format!("Placed {}", label.to_lowercase()))
+                .side(side)
+                .spawn(vc, theme, font);
+        }
+    });
 }
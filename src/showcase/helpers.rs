@@ -62,6 +62,8 @@ pub enum ShowcaseElement {
     Collection,
     Container,
     Context,
+    ContextMenu,
+    DropdownMenu,
     VisuallyHidden,
     AccessibleIcon,
     HoverCard,
@@ -79,6 +81,13 @@ pub enum ShowcaseElement {
     Tooltip,
     OneTimePasswordField,
     PasswordToggleField,
+    TextField,
+    TextArea,
+    NumberField,
+    Form,
+    Field,
+    Clipboard,
+    Combobox,
 }
 
 // Hilfsfunktion zur Erstellung von beschrifteten Varianten
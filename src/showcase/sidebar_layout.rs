@@ -276,6 +276,22 @@ pub fn build_sidebar(
                     .build(theme, font),
             ),
         )
+        .add_entity(
+            parent.spawn(
+                ButtonBuilder::new("context_menu")
+                    .text("Context Menu")
+                    .action(ShowcaseAction::ShowElement(ShowcaseElement::ContextMenu))
+                    .build(theme, font),
+            ),
+        )
+        .add_entity(
+            parent.spawn(
+                ButtonBuilder::new("dropdown_menu")
+                    .text("Dropdown Menu")
+                    .action(ShowcaseAction::ShowElement(ShowcaseElement::DropdownMenu))
+                    .build(theme, font),
+            ),
+        )
         .add_entity(
             parent.spawn(
                 ButtonBuilder::new("hover_card")
@@ -332,6 +348,14 @@ pub fn build_sidebar(
                     .build(theme, font),
             ),
         )
+        .add_entity(
+            parent.spawn(
+                ButtonBuilder::new("combobox")
+                    .text("Combobox")
+                    .action(ShowcaseAction::ShowElement(ShowcaseElement::Combobox))
+                    .build(theme, font),
+            ),
+        )
         .add_entity(
             parent.spawn(
                 ButtonBuilder::new("separator")
@@ -400,6 +424,54 @@ pub fn build_sidebar(
                     .build(theme, font),
             ),
         )
+        .add_entity(
+            parent.spawn(
+                ButtonBuilder::new("text_field")
+                    .text("Text Field")
+                    .action(ShowcaseAction::ShowElement(ShowcaseElement::TextField))
+                    .build(theme, font),
+            ),
+        )
+        .add_entity(
+            parent.spawn(
+                ButtonBuilder::new("text_area")
+                    .text("Text Area")
+                    .action(ShowcaseAction::ShowElement(ShowcaseElement::TextArea))
+                    .build(theme, font),
+            ),
+        )
+        .add_entity(
+            parent.spawn(
+                ButtonBuilder::new("number_field")
+                    .text("Number Field")
+                    .action(ShowcaseAction::ShowElement(ShowcaseElement::NumberField))
+                    .build(theme, font),
+            ),
+        )
+        .add_entity(
+            parent.spawn(
+                ButtonBuilder::new("form")
+                    .text("Form")
+                    .action(ShowcaseAction::ShowElement(ShowcaseElement::Form))
+                    .build(theme, font),
+            ),
+        )
+        .add_entity(
+            parent.spawn(
+                ButtonBuilder::new("field")
+                    .text("Field")
+                    .action(ShowcaseAction::ShowElement(ShowcaseElement::Field))
+                    .build(theme, font),
+            ),
+        )
+        .add_entity(
+            parent.spawn(
+                ButtonBuilder::new("clipboard")
+                    .text("Clipboard")
+                    .action(ShowcaseAction::ShowElement(ShowcaseElement::Clipboard))
+                    .build(theme, font),
+            ),
+        )
         .spawn(parent)
         .id()
 }
@@ -431,7 +503,6 @@ pub fn handle_select_element(
     font: Res<FontAssets>,
     icons: Res<IconAssets>,
     assets: Res<AssetServer>,
-    global_portal_root: Res<ForgeUiPortalRoot>,
 ) {
     let container = match container_q.single() {
         Ok(c) => c,
@@ -544,12 +615,9 @@ pub fn handle_select_element(
                         ShowcaseElement::AspectRatio => {
                             show_aspect_ratio_example(vc, &theme, &font.default);
                         }
-                        ShowcaseElement::Portal => show_portal_example(
-                            vc,
-                            &theme,
-                            &font.default,
-                            Res::clone(&global_portal_root),
-                        ),
+                        ShowcaseElement::Portal => {
+                            show_portal_example(vc, &theme, &font.default);
+                        }
                         ShowcaseElement::Slot => {
                             show_slot_example(vc, &theme, &font.default);
                         }
@@ -583,6 +651,12 @@ pub fn handle_select_element(
                         ShowcaseElement::AccessibleIcon => {
                             show_accessible_icon_example(vc, &theme, &font.default, &icons)
                         }
+                        ShowcaseElement::ContextMenu => {
+                            show_context_menu_example(vc, &theme, &font.default)
+                        }
+                        ShowcaseElement::DropdownMenu => {
+                            show_dropdown_menu_example(vc, &theme, &font.default)
+                        }
                         ShowcaseElement::HoverCard => {
                             show_hover_card_example(vc, &theme, &font.default)
                         }
@@ -598,6 +672,9 @@ pub fn handle_select_element(
                             show_scroll_area_example(vc, &theme, &font.default)
                         }
                         ShowcaseElement::Select => show_select_example(vc, &theme, &font.default),
+                        ShowcaseElement::Combobox => {
+                            show_combobox_example(vc, &theme, &font.default)
+                        }
                         ShowcaseElement::Separator => {
                             show_separator_example(vc, &theme, &font.default)
                         }
@@ -610,7 +687,21 @@ pub fn handle_select_element(
                             show_one_time_password_field_example(vc, &theme, &font.default)
                         }
                         ShowcaseElement::PasswordToggleField => {
-                            show_password_toggle_field_example(vc, &theme, &font.default)
+                            show_password_toggle_field_example(vc, &theme, &font.default, &icons)
+                        }
+                        ShowcaseElement::TextField => {
+                            show_text_field_example(vc, &theme, &font.default)
+                        }
+                        ShowcaseElement::TextArea => {
+                            show_text_area_example(vc, &theme, &font.default)
+                        }
+                        ShowcaseElement::NumberField => {
+                            show_number_field_example(vc, &theme, &font.default)
+                        }
+                        ShowcaseElement::Form => show_form_example(vc, &theme, &font.default),
+                        ShowcaseElement::Field => show_field_example(vc, &theme, &font.default),
+                        ShowcaseElement::Clipboard => {
+                            show_clipboard_example(vc, &theme, &font.default)
                         }
                     });
             });
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use bevy::input_focus::InputFocus;
 use bevy::prelude::*;
 
 use super::events::*;
@@ -10,10 +11,14 @@ use crate::assets::FontAssets;
 /// ⌨️  F12 / Esc → Toggle-Event erzeugen
 pub fn handle_toggle_system(
     keys: Res<ButtonInput<KeyCode>>,
+    focus: Option<Res<InputFocus>>,
+    text_inputs: Query<(), TextInputFilter>,
     mut ev_toggle: EventWriter<ToggleShowcaseEvent>,
     mut bt_events: EventReader<ButtonClickedEvent<ShowcaseAction>>,
 ) {
-    if keys.just_pressed(KeyCode::F12) || keys.just_pressed(KeyCode::Escape) {
+    // Esc im Textfeld gehört dem Feld
+    let escape = keys.just_pressed(KeyCode::Escape) && !is_typing(focus.as_deref(), &text_inputs);
+    if keys.just_pressed(KeyCode::F12) || escape {
         ev_toggle.write(ToggleShowcaseEvent);
     }
     for ev in bt_events.read() {
@@ -11,9 +11,32 @@ pub struct UiLayout {
     pub z_index: UiZIndex,
 }
 
+/// Basis-`GlobalZIndex` der benannten Overlay-Ebenen (siehe `UiLayer`).
 #[derive(Debug, Clone)]
 pub struct UiZIndex {
-    pub modal_base: i32,
+    pub base: i32,
+    pub dropdown: i32,
+    pub sticky: i32,
+    pub overlay: i32,
+    pub modal: i32,
+    pub popover: i32,
+    pub toast: i32,
+    pub tooltip: i32,
+}
+
+impl Default for UiZIndex {
+    fn default() -> Self {
+        Self {
+            base: 0,
+            dropdown: 1000,
+            sticky: 1100,
+            overlay: 1200,
+            modal: 1300,
+            popover: 1400,
+            toast: 1500,
+            tooltip: 1600,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -49,7 +72,7 @@ pub fn build(data: &UiLayoutData, config: &UiConfig) -> UiLayout {
     let rem = |v: f32| v * base_rem;
 
     UiLayout {
-        z_index: UiZIndex { modal_base: 10 },
+        z_index: UiZIndex::default(),
         padding: UiSpacing {
             xs: s(data.padding.xs),
             sm: s(data.padding.sm),